@@ -1,8 +1,8 @@
 // mod utils;
 
 use js_sys::Promise;
-use robohash::RoboHashBuilder;
-use robonames::generate_short_nickname;
+use robohash::{AlgorithmVersion, RoboHashBuilder};
+use robonames::{generate_short_nickname_with_version, NicknameVersion};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
@@ -13,7 +13,9 @@ async fn string_to_js_result(string: String) -> Result<JsValue, JsValue> {
 #[wasm_bindgen]
 pub fn generate_robohash(initial_string: &str, size: i32) -> String {
     // Generate Robot Avatar synchronously. Returns a base64 avatar string.
+    // Pins the V1 trait selection only, the art and defaults may still change.
     let robohash = RoboHashBuilder::new(initial_string)
+        .with_algorithm(AlgorithmVersion::V1)
         .with_background(&true)
         .with_size(size as u32, size as u32)
         .build();
@@ -27,6 +29,22 @@ pub fn generate_robohash(initial_string: &str, size: i32) -> String {
     }
 }
 
+#[wasm_bindgen]
+pub fn generate_robohash_from_trait_code(trait_code: &str, size: i32) -> String {
+    // Generate the Robot Avatar of a trait code (RH1-...). Returns a base64 avatar string.
+    let robohash = RoboHashBuilder::from_trait_code(trait_code)
+        .with_size(size as u32, size as u32)
+        .build();
+
+    match robohash {
+        Ok(robo) => match robo.assemble_base64() {
+            Ok(base64_string) => base64_string,
+            Err(text) => text.to_string(),
+        },
+        Err(text) => text.to_string(),
+    }
+}
+
 #[wasm_bindgen]
 pub fn async_generate_robohash(initial_string: &str, size: i32) -> Promise {
     // Generate Robot Avatar asynchronously. Returns a base64 avatar string promise.
@@ -37,7 +55,7 @@ pub fn async_generate_robohash(initial_string: &str, size: i32) -> Promise {
 #[wasm_bindgen]
 pub fn generate_roboname(initial_string: &str) -> String {
     // Generate Robot Nickname synchronousl. Returns a nickname string.
-    let nickname = generate_short_nickname(initial_string);
+    let nickname = generate_short_nickname_with_version(initial_string, NicknameVersion::V1);
     match nickname {
         Ok(nick) => nick,
         Err(_) => "Error".to_string(),
@@ -51,6 +69,48 @@ pub fn async_generate_roboname(initial_string: &str) -> Promise {
     future_to_promise(future)
 }
 
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose, Engine as _};
+    use robohash::golden::{Corpus, GoldenCase, CORPUS_DIRECTORY};
+    use robohash::{RoboSet, SizeMode};
+
+    use super::*;
+
+    /// Cases with the options `generate_robohash` renders with.
+    fn is_avatar(case: &GoldenCase) -> bool {
+        case.algorithm == AlgorithmVersion::V1
+            && case.width == case.height
+            && case.size_mode == SizeMode::Stretch
+            && case.scale == 1.0
+            && case.set == RoboSet::Set1
+            && case.background
+            && !case.hue_rotation
+            && case.mask.is_none()
+    }
+
+    #[test]
+    fn generate_robohash_renders_like_the_golden_images() {
+        // arrange
+        let corpus = Corpus::load(CORPUS_DIRECTORY).unwrap();
+        let corpus = Corpus {
+            cases: corpus.cases.into_iter().filter(is_avatar).collect(),
+            ..corpus
+        };
+        // act
+        let mismatches = corpus
+            .check(|case| {
+                let base64_string = generate_robohash(&case.input, case.width as i32);
+                let png = general_purpose::STANDARD.decode(base64_string).unwrap();
+                Ok(image::load_from_memory(&png)?.to_rgba8())
+            })
+            .unwrap();
+        // assert
+        assert!(!corpus.cases.is_empty());
+        assert_eq!(mismatches, Vec::new());
+    }
+}
+
 // Print browser alerts, useful for testing.
 // #[wasm_bindgen]
 // extern "C" {
@@ -1,5 +1,8 @@
+use robohash::cache::CachedParts;
+use robohash::provider::{EmbeddedParts, PartsProvider};
 use robohash::*;
 use std::error::Error;
+use std::sync::Arc;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -49,6 +52,27 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("Build tiny size Robohash", |b| {
         b.iter(|| build_robohash(initial_string, use_background, size))
     });
+
+    let parts: Arc<dyn PartsProvider> = Arc::new(CachedParts::new(EmbeddedParts::new()));
+    let size = black_box(256);
+    c.bench_function("Build medium size Robohash from cached parts", |b| {
+        b.iter(|| build_cached_robohash(initial_string, &parts, size))
+    });
+}
+
+fn build_cached_robohash(
+    initial_string: &str,
+    parts: &Arc<dyn PartsProvider>,
+    size: u32,
+) -> Result<(), Box<dyn Error>> {
+    let robo_hash: RoboHash = RoboHashBuilder::new(initial_string)
+        .with_parts_provider(parts.clone())
+        .with_size(size, size)
+        .build()?;
+
+    let _base64_robohash = robo_hash.assemble_base64()?;
+
+    Ok(())
 }
 
 criterion_group!(benches, criterion_benchmark);
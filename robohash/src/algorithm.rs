@@ -0,0 +1,205 @@
+use std::fmt;
+
+use ring::digest::{digest, SHA512};
+use serde::{Deserialize, Serialize};
+
+/// How the traits are picked from the digest. Versions are frozen: the traits a
+/// version picks for a digest never change across releases, which the golden
+/// vectors of each version in `test_resources/golden` hold it to. New selection
+/// rules, part tables or hue handling come as new versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlgorithmVersion {
+    /// Remainders of the 44 bit chunks of the hex digest. Counts that do not divide
+    /// 2^44 make the first indices slightly more likely. The selection of RoboSats
+    /// robots until 0.3.
+    V1,
+    /// Exactly uniform: 64 bit words drawn from SHA-512 of the digest, a stream per
+    /// trait, reduced by multiplication and rejecting the words that would bias them.
+    V2,
+}
+
+impl AlgorithmVersion {
+    /// Latest frozen version, the default.
+    pub const LATEST: AlgorithmVersion = AlgorithmVersion::V2;
+    /// Every frozen version, oldest first.
+    pub const ALL: [AlgorithmVersion; 2] = [AlgorithmVersion::V1, AlgorithmVersion::V2];
+}
+
+impl Default for AlgorithmVersion {
+    fn default() -> Self {
+        AlgorithmVersion::LATEST
+    }
+}
+
+impl fmt::Display for AlgorithmVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlgorithmVersion::V1 => f.write_str("v1"),
+            AlgorithmVersion::V2 => f.write_str("v2"),
+        }
+    }
+}
+
+/// Picks the index of each trait from its slot of the hash array, like upstream
+/// Robohash, or from the stream of the slot with `V2`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Picker<'a> {
+    version: AlgorithmVersion,
+    hash_array: &'a [i64],
+    digest: &'a [u8],
+}
+
+impl<'a> Picker<'a> {
+    pub(crate) fn new(version: AlgorithmVersion, hash_array: &'a [i64], digest: &'a [u8]) -> Self {
+        Self {
+            version,
+            hash_array,
+            digest,
+        }
+    }
+
+    /// Index below `count`, never 0 as `SetCatalog::validate` runs before the picks.
+    pub(crate) fn pick(&self, slot: usize, count: usize) -> usize {
+        match self.version {
+            AlgorithmVersion::V1 => (self.hash_array[slot] % count as i64) as usize,
+            AlgorithmVersion::V2 => words(self.digest, slot)
+                .find_map(|word| reduce(word, count as u64, 64))
+                .map_or(0, |index| index as usize),
+        }
+    }
+}
+
+/// Words of SHA-512(digest, slot, block) for blocks 0, 1, 2...
+fn words(digest_bytes: &[u8], slot: usize) -> impl Iterator<Item = u64> + '_ {
+    (0u32..).flat_map(move |block| {
+        let mut input = digest_bytes.to_vec();
+        input.push(slot as u8);
+        input.extend(block.to_be_bytes());
+        digest(&SHA512, &input)
+            .as_ref()
+            .chunks_exact(8)
+            .map(|word| u64::from_be_bytes(word.try_into().unwrap_or_default()))
+            .collect::<Vec<u64>>()
+    })
+}
+
+/// Lemire's reduction of a `bits` wide word to an index below `count`: the high
+/// bits of `word * count`, or `None` when the low bits fall in the `2^bits % count`
+/// values that would make some indices more likely.
+fn reduce(word: u64, count: u64, bits: u32) -> Option<u64> {
+    let space = 1u128 << bits;
+    let product = word as u128 * count as u128;
+    let low = product % space;
+    match low < space % count as u128 {
+        true => None,
+        false => Some((product >> bits) as u64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn reduce_maps_the_accepted_words_evenly_to_every_index() {
+        // Every 8 bit word for every count fitting in 8 bits
+        for count in 1..=255u64 {
+            let mut hits = vec![0; count as usize];
+            (0..=255u64)
+                .filter_map(|word| reduce(word, count, 8))
+                .for_each(|index| hits[index as usize] += 1);
+            assert!(
+                hits.iter().all(|hit| *hit == hits[0]),
+                "uneven for {}: {:?}",
+                count,
+                hits
+            );
+            assert_eq!(hits[0], 256 / count);
+        }
+    }
+
+    #[test]
+    fn v1_keeps_the_remainders_of_the_hash_array() {
+        // arrange
+        let hash_array = [17, 4_398_046_511_103];
+        let picker = Picker::new(AlgorithmVersion::V1, &hash_array, &[]);
+        // act & assert
+        assert_eq!(picker.pick(0, 5), 2);
+        assert_eq!(picker.pick(1, 360), 4_398_046_511_103 % 360);
+    }
+
+    #[test]
+    fn v2_gives_each_slot_its_own_stream() {
+        // arrange
+        let digest = [7; 64];
+        let picker = Picker::new(AlgorithmVersion::V2, &[], &digest);
+        // act
+        let picks = (0..11)
+            .map(|slot| picker.pick(slot, 1 << 30))
+            .collect::<Vec<_>>();
+        // assert
+        assert_eq!(
+            picks,
+            (0..11)
+                .map(|slot| picker.pick(slot, 1 << 30))
+                .collect::<Vec<_>>()
+        );
+        assert!(picks
+            .iter()
+            .enumerate()
+            .all(|(i, pick)| !picks[..i].contains(pick)));
+    }
+
+    /// Chi-square of `samples` digests picking below `count` from `slot` against the
+    /// critical value at p = 0.001, from the Wilson-Hilferty approximation.
+    fn assert_uniform(slot: usize, count: usize) {
+        let samples = count * 200;
+        let mut hits = vec![0usize; count];
+        for sample in 0..samples {
+            let digest = digest(&SHA512, &(sample as u64).to_be_bytes());
+            hits[Picker::new(AlgorithmVersion::V2, &[], digest.as_ref()).pick(slot, count)] += 1;
+        }
+        let expected = samples as f64 / count as f64;
+        let chi_square = hits
+            .iter()
+            .map(|hit| (*hit as f64 - expected).powi(2) / expected)
+            .sum::<f64>();
+        let freedom = (count - 1) as f64;
+        let spread = 2.0 / (9.0 * freedom);
+        let critical = freedom * (1.0 - spread + 3.09 * spread.sqrt()).powi(3);
+        assert!(
+            chi_square < critical,
+            "slot {} count {}: chi-square {} above {}",
+            slot,
+            count,
+            chi_square,
+            critical
+        );
+    }
+
+    #[test]
+    fn v2_picks_every_part_of_every_layer_uniformly() {
+        for set_catalog in catalog::embedded() {
+            for (layer, _) in set_catalog.layers.iter().enumerate() {
+                let mut counts = (0..set_catalog.colors.len())
+                    .map(|color| set_catalog.layer_count(layer, color))
+                    .filter(|count| *count > 1)
+                    .collect::<Vec<usize>>();
+                counts.sort();
+                counts.dedup();
+                counts
+                    .into_iter()
+                    .for_each(|count| assert_uniform(layer, count));
+            }
+        }
+    }
+
+    #[test]
+    fn v2_picks_hues_and_colors_uniformly() {
+        assert_uniform(7, 360);
+        assert_uniform(8, 10);
+    }
+}
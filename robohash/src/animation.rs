@@ -0,0 +1,378 @@
+use std::f64::consts::PI;
+use std::io::Write;
+use std::sync::Arc;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops, Delay, Frame, RgbaImage};
+
+use crate::effects::Effect;
+use crate::error::Error;
+use crate::image::{apply_effects, build_robo_hash_image};
+use crate::layout::Layout;
+
+/// Movements of an animated robot, they can be combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// Rotates the hue of the parts a full turn over the loop.
+    HueCycle,
+    /// Swaps the eyes for another pair on the last frames of the loop.
+    Blink,
+    /// Moves the parts up and down over the background, by up to `amplitude` pixels.
+    Bob { amplitude: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub motions: Vec<Motion>,
+    /// Number of frames of the loop.
+    pub frames: u32,
+    /// Duration of each frame in milliseconds.
+    pub frame_duration: u16,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            motions: vec![Motion::Blink, Motion::Bob { amplitude: 4 }],
+            frames: 16,
+            frame_duration: 80,
+        }
+    }
+}
+
+/// Encoding of an animated robot. The animation loops forever.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationFormat {
+    #[default]
+    Apng,
+    /// Lossless animated WebP. Needs the `webp` feature.
+    Webp,
+    /// GIF has a 256 colors palette and no partial transparency.
+    Gif,
+}
+
+impl AnimationFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            AnimationFormat::Apng => "image/apng",
+            AnimationFormat::Webp => "image/webp",
+            AnimationFormat::Gif => "image/gif",
+        }
+    }
+}
+
+/// The eyes layer and the part it is swapped with while blinking.
+pub(crate) struct BlinkEyes {
+    pub(crate) layer: usize,
+    pub(crate) part: Arc<RgbaImage>,
+}
+
+/// Composes the frames from layers already resized to their placement in `layout`.
+pub(crate) fn build_robo_hash_frames(
+    robo_parts: &[Arc<RgbaImage>],
+    background: &Option<Arc<RgbaImage>>,
+    layout: &Layout,
+    hue_rotation: &Option<i32>,
+    blink_eyes: &Option<BlinkEyes>,
+    effects: &[Arc<dyn Effect>],
+    animation: &Animation,
+) -> Result<Vec<RgbaImage>, Error> {
+    if animation.frames == 0 || animation.frame_duration == 0 {
+        return Err(Error::InvalidAnimation(String::from(
+            "frames and frame duration should be greater than 0",
+        )));
+    }
+
+    let base_image = build_robo_hash_image(&[], background, layout, &None, &[])?;
+    let blink_eyes = blink_eyes
+        .as_ref()
+        .filter(|_| animation.motions.contains(&Motion::Blink));
+
+    let frames = animation.frames;
+    let blink_frames = (frames / 8).max(1);
+    let hue = hue_rotation.unwrap_or(0);
+    let mut images = Vec::with_capacity(frames as usize);
+    for frame in 0..frames {
+        let phase = frame as f64 / frames as f64;
+        let mut hue_rotation = hue;
+        let mut offset = 0;
+        for motion in &animation.motions {
+            match motion {
+                Motion::HueCycle => hue_rotation += (360.0 * phase) as i32,
+                Motion::Blink => {}
+                Motion::Bob { amplitude } => {
+                    offset = -(*amplitude as f64 * (2.0 * PI * phase).sin()).round() as i64
+                }
+            }
+        }
+        let blinking = frame >= frames - blink_frames;
+
+        let mut image = base_image.clone();
+        // With effects, the parts are composed apart to run them over the robot only
+        let mut robot = match effects.is_empty() {
+            true => None,
+            false => Some(RgbaImage::new(layout.width, layout.height)),
+        };
+        for (layer, part) in robo_parts.iter().enumerate() {
+            let mut part = match blink_eyes {
+                Some(eyes) if blinking && eyes.layer == layer => eyes.part.as_ref().clone(),
+                _ => part.as_ref().clone(),
+            };
+            imageops::colorops::huerotate_in_place(&mut part, hue_rotation);
+            let target = robot.as_mut().unwrap_or(&mut image);
+            imageops::overlay(target, &part, layout.parts.x, layout.parts.y + offset);
+        }
+        if let Some(mut robot) = robot {
+            apply_effects(&mut robot, effects);
+            imageops::overlay(&mut image, &robot, 0, 0);
+        }
+        images.push(image);
+    }
+    Ok(images)
+}
+
+pub(crate) fn encode<W: Write>(
+    frames: &[RgbaImage],
+    frame_duration: u16,
+    format: &AnimationFormat,
+    writer: W,
+) -> Result<(), Error> {
+    match format {
+        AnimationFormat::Apng => encode_apng(frames, frame_duration, writer),
+        AnimationFormat::Webp => encode_webp(frames, frame_duration, writer),
+        AnimationFormat::Gif => {
+            let mut encoder = GifEncoder::new_with_speed(writer, 10);
+            encoder.set_repeat(Repeat::Infinite)?;
+            let delay = Delay::from_numer_denom_ms(frame_duration as u32, 1);
+            encoder.encode_frames(
+                frames
+                    .iter()
+                    .map(|frame| Frame::from_parts(frame.clone(), 0, 0, delay)),
+            )?;
+            Ok(())
+        }
+    }
+}
+
+fn encode_apng<W: Write>(
+    frames: &[RgbaImage],
+    frame_duration: u16,
+    writer: W,
+) -> Result<(), Error> {
+    let apng_error =
+        |err: png::EncodingError| Error::EncodingFailed(String::from("apng"), err.to_string());
+    let (width, height) = frames
+        .first()
+        .map(|frame| frame.dimensions())
+        .unwrap_or_default();
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    // 0 plays means looping forever
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(apng_error)?;
+    encoder
+        .set_frame_delay(frame_duration, 1000)
+        .map_err(apng_error)?;
+    let mut writer = encoder.write_header().map_err(apng_error)?;
+    for frame in frames {
+        writer
+            .write_image_data(frame.as_raw())
+            .map_err(apng_error)?;
+    }
+    writer.finish().map_err(apng_error)
+}
+
+#[cfg(feature = "webp")]
+fn encode_webp<W: Write>(
+    frames: &[RgbaImage],
+    frame_duration: u16,
+    mut writer: W,
+) -> Result<(), Error> {
+    let (width, height) = frames
+        .first()
+        .map(|frame| frame.dimensions())
+        .unwrap_or_default();
+    let mut config = webp::WebPConfig::new().map_err(|_| {
+        Error::EncodingFailed(String::from("webp"), String::from("invalid configuration"))
+    })?;
+    config.lossless = 1;
+    let mut encoder = webp::AnimEncoder::new(width, height, &config);
+    encoder.set_loop_count(0);
+    for (i, frame) in frames.iter().enumerate() {
+        let timestamp = i as i32 * frame_duration as i32;
+        encoder.add_frame(webp::AnimFrame::from_rgba(
+            frame.as_raw(),
+            width,
+            height,
+            timestamp,
+        ));
+    }
+    let webp = encoder
+        .try_encode()
+        .map_err(|err| Error::EncodingFailed(String::from("webp"), format!("{:?}", err)))?;
+    writer.write_all(&webp)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "webp"))]
+fn encode_webp<W: Write>(
+    _frames: &[RgbaImage],
+    _frame_duration: u16,
+    _writer: W,
+) -> Result<(), Error> {
+    Err(Error::FeatureDisabled(
+        String::from("animated webp output"),
+        String::from("webp"),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    use image::codecs::gif::GifDecoder;
+    use image::codecs::png::PngDecoder;
+    use image::{AnimationDecoder, Rgba};
+
+    use crate::layout::SizeMode;
+
+    use super::*;
+
+    fn layout() -> Layout {
+        Layout::new(8, 8, SizeMode::Stretch, 1.0)
+    }
+
+    fn part(color: [u8; 4]) -> Arc<RgbaImage> {
+        Arc::new(RgbaImage::from_pixel(8, 8, Rgba(color)))
+    }
+
+    fn frames(motions: Vec<Motion>) -> Vec<RgbaImage> {
+        let parts = vec![part([255, 0, 0, 255]), part([0, 0, 255, 255])];
+        let blink_eyes = Some(BlinkEyes {
+            layer: 1,
+            part: part([0, 255, 0, 255]),
+        });
+        let animation = Animation {
+            motions,
+            frames: 8,
+            frame_duration: 100,
+        };
+        build_robo_hash_frames(
+            &parts,
+            &None,
+            &layout(),
+            &None,
+            &blink_eyes,
+            &[],
+            &animation,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn blink_swaps_the_eyes_on_the_last_frame_only() {
+        // act
+        let frames = frames(vec![Motion::Blink]);
+        // assert
+        assert_eq!(frames.len(), 8);
+        assert!(frames[..7]
+            .iter()
+            .all(|frame| frame.get_pixel(4, 4) == &Rgba([0, 0, 255, 255])));
+        assert_eq!(frames[7].get_pixel(4, 4), &Rgba([0, 255, 0, 255]));
+    }
+
+    #[test]
+    fn bob_moves_the_parts_up_then_down() {
+        // act
+        let frames = frames(vec![Motion::Bob { amplitude: 2 }]);
+        // assert
+        assert_eq!(frames[0].get_pixel(4, 7)[3], 255);
+        assert_eq!(frames[2].get_pixel(4, 7)[3], 0);
+        assert_eq!(frames[6].get_pixel(4, 0)[3], 0);
+    }
+
+    #[test]
+    fn hue_cycle_changes_the_colors_of_every_frame() {
+        // act
+        let frames = frames(vec![Motion::HueCycle]);
+        // assert
+        assert_ne!(frames[0].get_pixel(4, 4), frames[4].get_pixel(4, 4));
+    }
+
+    #[test]
+    fn build_robo_hash_frames_rejects_empty_animations() {
+        // arrange
+        let animation = Animation {
+            frames: 0,
+            ..Animation::default()
+        };
+        // act
+        let frames = build_robo_hash_frames(
+            &[part([0, 0, 0, 255])],
+            &None,
+            &layout(),
+            &None,
+            &None,
+            &[],
+            &animation,
+        );
+        // assert
+        assert!(frames.is_err());
+    }
+
+    #[test]
+    fn encode_apng_and_gif_keep_every_frame() {
+        // arrange
+        let frames = frames(vec![Motion::Blink, Motion::HueCycle]);
+        let mut apng = Vec::new();
+        let mut gif = Vec::new();
+        // act
+        encode(&frames, 100, &AnimationFormat::Apng, &mut apng).unwrap();
+        encode(&frames, 100, &AnimationFormat::Gif, &mut gif).unwrap();
+        // assert
+        let apng_frames = PngDecoder::new(Cursor::new(apng))
+            .unwrap()
+            .apng()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(apng_frames.len(), 8);
+        assert_eq!(apng_frames[7].buffer(), &frames[7]);
+        assert_eq!(
+            Duration::from(apng_frames[0].delay()),
+            Duration::from_millis(100)
+        );
+        let gif_frames = GifDecoder::new(Cursor::new(gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(gif_frames.len(), 8);
+    }
+
+    #[cfg(feature = "webp")]
+    #[test]
+    fn encode_webp_keeps_every_frame() {
+        // arrange
+        let frames = frames(vec![Motion::Blink]);
+        let mut webp = Vec::new();
+        // act
+        encode(&frames, 100, &AnimationFormat::Webp, &mut webp).unwrap();
+        // assert
+        let decoded = image::codecs::webp::WebPDecoder::new(Cursor::new(webp))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        // libwebp merges the identical frames before the blink
+        let duration = decoded
+            .iter()
+            .map(|frame| Duration::from(frame.delay()))
+            .sum::<Duration>();
+        assert_eq!(duration, Duration::from_millis(800));
+        assert_eq!(decoded.last().unwrap().buffer(), &frames[7]);
+    }
+}
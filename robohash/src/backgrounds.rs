@@ -1,26 +1,27 @@
-pub static BACKGROUNDS: [&str;21] =
-[
-    "UklGRsINAABXRUJQVlA4ILYNAABQwgCdASosASwBPxF+tVWsJ6SjJVPcgYAiCWVqmmeyO19/XVMFpZOfrjNT4wA6OGmn9/zn5l6A/XLRqwg3K/1/6cr3/85it6oH9FT//+2JrknsxSXyGCORPw4Reyuej1oeN7kbZxlI2UGSE/DXjlhIZotywe2Rp7U5HwlLLkLeJoaMQ/8yJnNExPl/tkzRw3MKNVyMmxWvf1vNt0oM8OFOnxvb4Jo4dSFueRWwCfxJxViszhmJ2p8hwovrC9QOytVa8hfS2PTJ6tI49ZbS8x+XsKks5hHc6x3Or7K8ItkpV5CNVzW002DLh1LQR/GF8QSZuy77TWXw5TvdYwxuVLZ+zA3usY5F2HPSXvbyW7niHMRuo1zWJpkuW4fL1Ym2CgofeHzQmvIVhju5CpG977lapidIyU+Ydp9tw6KEWZNqIVeH+vZek+k0d3m3BOA8LQTGJ9Kj+IxvMlKYgB4jtnvSviFXE+uDHG2M/ygA0ic7UJtUif0mqNkyyOas9fvPLJOJx80OvQ5VTjjmh39kEqdYQHs4zsgA5W/NpQBZBY/ix9Jed7mxXlQgUafVEIGp/7p51HPpmLJYoraeuVZcuUaX0B3EfeDaPM4ITi6GHe6/ZeECqCXwMmaIcOP0fpjGc45FHsBkPI6EOUXB+KKlYlQfOOzuTcRCKll/HcSmeF8Pad0uua7wPe8CN1YBnKRVnhRYZxGzwI7CXkJO+Mu0c5qD4HUgFx7ZlIkC9v8qLPNQVKbTUs5qZRVaNJqcC3jdHEKDLqPZq0c5fL6j2UTuhraR1qE07Lq//9sG5jKRRFRfAekLh+Wu9L+/RDuu/+CviB2FfJkFltUBTiq6V1rs6FTWvi56N21lXdCVM1mYsqG5eNSPgWcA8jQgQNNQ8mbKmANzTiE7UOtw0mN1F57DFZeiy5xbNXbXr4/eHmp+VLSLBWkq8Yybm7PzwnqVPWM+N7MvRK7xTf+6gLsvULusT+6mWHCexPCg2K8oq88zrXhoHBjFXBGVQzSjrYkCaCmI3zOtbMGShfovk5s5My08MztKcuCKWzYjO9IaQ8SQOboBuzEIPVZ2c42vjP+M//xG+//+w6/ddfVPpH5F8zS8dM0GvZjCL69NDpYDEm7o66IQK3n31O2KA1ToTmANmSP91eHhcCPDhTZoN5v/BNy70j0l2w7ojMrKIfe1wINB7zPMjq8IAPBt3FFN1uv691UIrU1Ep2rn1G835Pl9tTSIQ+FYGwezoTewmWvQQqN6WC1G1UBubSY7xMwUFgHoLDXHh/7tkF+M3AR/9w1H4E7//soT/FqzodQaq79Qm7QYPQzmOsTVPdmpJkXTANfAMsC3aEN19ffKFIRCc6WZfZixYr6AwSpdejsBuxV/YEkPiz5Elu0gN15MX+R/tc+bHfYLrwsZWo61CTSvqlhaV5Sg14SdtfUt7ednnRs24MlcKW7n0Jcfnzcq8qZRm8UEA4NWDiynqRsFalpFZJF4HvN60G2QSVxwIT9NTtjjTpFI91jI/cRxMXtV5wEK7j/joNn1/9v9su5QgMtQsHWG6Krz9hwX0m9XRV02grhXX3+686sl9pkoftIimGmgdHsQrreKEEj7rM72u8UxPx0+VF4Y3N5v1/F8O/9FX+vy+AawIAwYqi0b6oFnkDTbXP69gfROuqqEE9cpwa/uDbN4PGAIj1lOgxA3b9KUnIOmsMwjwDYATS0r9U12fd/Y2v/lJ1y/Lb+QBf/0Vuiv4cpJLjP/953TfFHyerQv63EQBkR080Pixvnj657Go6JvIO/iCJ3yQcAxnA/xPRD0apvVR1vTvjbFflvtNyhSFQePIGEaHB6TBMtROv6FeCXeT/0WaE7fvijeGvNv8myyglB7UrauWq8dFrV1ze0x/IJqkpUvf7wGaZTwHYB7x3jVpFprbzt/MJhMUDjlKtRAmL6qu5aSaR6mRoOW2C0XxIQT0hGhtH1wVRERv2CKd8kdEYqQRt/WoYlfMZguuyrdXeUA4UcV11z7CmWnIRIyBrM19xQ7pIZT4tYTldlKqEAz5ej4rw7nOBldpFVGyl+c1piwafUZLRpWZsurQpAXA6LaUsAA/ncE34Mtzu0DrSNacSTA5KbuMkfwMMx6WiqiBt8kDP+0hOy78/RcHoxB5aV21oDld1evMbmGXNuHs1GFqM2QCEL0rKSMFGRnlmn466CeKOmee59HNyqkUG2Rn42/DLfFYPe13KsAGo7QI8nccb1+yiBDRgXuxb3jKKRsDCWDrzxlVHrs4LP6TGs2PhahQ7JeUbtl65Y/Fi3GAz1IOH69bFeIZcRl2k3H4pklWwSsIkm2QM9ZYgaEEBjxfTzEpz0K7A7zUAUl9dQ/p57ALS2JlyML5QkN3e6r7o8DII/dAiiYkeIuUOTC4cIwQGg6edX1GxgLYt5zE1BHLhtEB9tbv5t5qEnlQfMXpsG3eIeMiprADUFY/9ybbELImLefXleIsGWXGbGhamf506IHzP9a4Qb8kVOy0pBN07K+b/xfA6BhbRpPI8VoSB7RLgt9+x38mfmCiMqAd9xGRqOYZCes5KgQ0rnA0TopWYt7LP4um5VJNk3hn/tGk2LshcnJe5R5GbaOy3K1uCQZh7ze7uUWcA3vYepSyCFoRAAB2wue2SMBy2ckcVf127RXgUUkbyGkBSczKOB2XFXVujHY9hxfaeyiEIQe/nKVmzfMMRjn1WG1hXVV2InHH2q+nlb4BcE+fomrWP3mE2UiZK0xTfh05Kvg+kNkZKBIQfaneHOAePXn4Xhb25uaF+52qlclZXyIPZkP0YJjpxPQCudUg3G7K/kvE/PkZsp88pOgZqEADwMXBEGyAZOO5j8Kd1WzzahfI+o15KfeaDZvshY92SsksQqHvtHC4+ToHBIAKop+Dxv5qOIRhrIduT8PxCspp1ciwaRYs25T2o4A9yAOhPawQFgXZvoOZbcjwZapJwMAVx/XITalE3u5bcLz+3iRbzGDjDPNJii4xoUJGvPJm2FvCac7kk1eWz3I4Sd6XkVTfKzT+zeG3vq2JsjZAAFEMslBK4FIgrwttvbtHZEmpbZB23zeEF0rC+5OwcExq7zVEZ9H5ty44w4zUrx/Yd6tiuKisjbXgIZJvwqp1R+I40i6g5+R27WODn8qj5gxJfpM2GfLRPKIIJ4ljhBfL5zQAfbychFhhSRYNHIe1I5Ky0LiGXQfSWlyHXG+bga7fZ2b0c/VZfvODBgJbvBKr1XTSmXgkyq4wsjIt1Uwp6dMfOvW7S/IZBirr930joEpzvMFx22COlcSOBZQal71+ppM2L6kdgz3nf+O2Qas3yYUczBAlX0TEjdhPyMfm0v0qIaUi+ktBchbFmmx1p82/YQLD4Y+tteSu+ZTmak700/uZgRP2QjPNSrET+5C03OSGDY7/tMmAdNEkv6PaVxnzgqJxCEjsGMVoytJ+VvN4MVneGidfXuMUqXlWZrFQ0/j7elTeSRA6kVhhYrho4ACyXUJYq/PlU44SCCiiBpoq42Af9XkNIzwVgEu4wRn5zB7Gwih0VlPhVYbdeQcjjQLw5yYjUY5EokQWV3E4PEwJKs5Ujcho/J80NiOmaETbpMEfo5d5PRZmNCcd3PuOGYmmmHv+kzAPQAc+77R0K8JBSwaAM2ze3m8UcxLHxTqG4sxu0Q5A8uTCwnjwIHtsfqx4UmDkOwxAAjR0CRaZ061Gp3+CaLYWmm9lPkfPRaMSvjsJ1YCh9rhcTZdCOYNRwMOUFpMnHH7cWBu/EnmM9QZ5HRYJEmDGltkA/f06xx3j4BKB+6ILqqpBOiUpGQQTWgaEte6vcpZJgi4aT+TN1b87I0TxbM9wr+1d95l8CnN//7A2IHDOpg6oJzCu5/ZLmlFLTH+dSdIRWW6FykkDOoDUkr4TW/Mi+LsTlxcpP5VYxvuASXEpdGXhgOHtJGe1IYj2SJ5IQvMEKkVDOJ+UHrMtPW6XrixvCL9/nPhhfch+OAMihqtrFNH9X3pFmTjkPZL9Remjb+Aiy5/6UWHYkFasg+xaQ1ReBtxDJRic6PVupwnm9iaVW57ChbgtzN5plSlY2Bw0ttw/EsN6NqVSkFqMoPPVlxrO+B0qQWZh8xayFFao2xtrf0kZNmfGDBXcbYQbtSl7Xx2sHHvIy5vA6E76Ui/8+mLwwfuL2t/oWz7dd0wepG0YYGxNCe6UBzx7bCu8CcowHkUPSxPZnJinXSiJok1d8lI/1BfDzTkjBj5vffMZnKSZWPm66FPtkgPdMGqTbm/YhlnZGRaTKOHO2LODGABWGxYcsPGoT3/34UsoFyc70WNyu3KsxrM3uv9GOFydBFpq6Xlr49oPumA+E6WabklpnkAED/ZX/5tVLyGgxBkmvT4GML+NkgILC4GxoyPMT13RbeS+S8a7JVkBaiIW3hvjRH4uxS13ErlvSEd3+IpkuTBFTwbHz/PcmxvN0yWFelF3V8apHMRXopQyiNjQDO/TWnKCmO13E4jnYhvODRBQ0FG+AXsNGNk5BJotZ/EzNvBKCrGt8tOdevj76NmDu0+RMq0gIv2hrGh+vQGKFx99gEAkC68YQoyX2VaY39/DzGErA81ERQ0EhSX7YkCnpRcVP5TXyxCVFxw0biuONU4OIdIot9ElnYYbBXEgvDp0qAH+VrJLkAq10SYE4PwxlmtjLTaPBWluvrxGkU2AAA=",
-    "UklGRvAFAABXRUJQVlA4IOQFAACwNACdASosASwBPxF6uFesLawupFBbCdAiCWVucAuQxT9P3sZpNLP4Tz/sPtN84zJTwprH3ZLqP81ZwjqpOLeW2GcX0Wte+/XB7/7GX90AUZNbJ8UfV/ji3xLviQfC1hsoNGolc34Ql/83HvuuXQC6Cd8+td+LvZLLthu2mnfBto6axZ5OexhfJquHkuPVZm08VDQ15p+vtwbh150h0whkM/Tw2YHZP8IMvS7LNlQM/kfiWcVUqEkKa894ZAPNX0P/eSFTJeMxOlVAE3P5gPBbKLXdm1KjJAMHvo2+kx+j7v166fCz4P9LDpy4lsCLGqNYLAQGXVW5qfRGqYxppdRJ5aIrW9GMBQ9ugyMesuFrTDebLk9fiM8wYLNofs8XsG6i5nDOzNCr38i2G5EDapiApCssTzBFTiNzDAoJ6wiDUoHvBMlC2G3AYlee3c7osLCThf8+pRLDYdXIMwpoRdzJCplWNaY2DZNH8dujByVoRu+lJm8LqbVeBA0xC+R/035IqIHeKNWUVmDj/LLMOdNA9TZuboFX0K2YI+eY4d4xxRgSwRURqK0V0rQNO+IAAP7uxcaX9cecPm+uGeWLz8rrNzeSkO2SD+vV5qEIz5EuFUYnL7eze/xB34ZiVpI7yRIHshMGBMHzZY+6+CTgI8AAeqQhqxNbMWbQwEdFpbwFUIzai1mATvMdQeGs0E4/kyHDlfa8QJoPKKc2zVFn/8SoWYACoLk/t73s6qBSi8GoKiwkwk/CkT3p0uaf8C5u1ISDWGoYTI71WwaS28dD5kjA7bkPVcKssuKxhp6yPvfUkTMDGYO+TLVADzlPE0KGFwCXmqKyTMCDOFX1p9maUNfvZyqM5TSgXi/PzNhaBQ6A2OwIXHVFS49wi1y2lIhGHc1gXPnmS8ohw2UaWr3k2YciIVXT5PKwej755Ej5cK16sMCK9Y8ra5Vod4yX2YBWZK5+EgvTAiuT3CL93w8crXtG6oPz9xL+gzEb7zdI9liW04BuTEU6Y5l8f5kPIyppR2aLG1Ti/KDyubJ/zLqV2f7Gmme/lavYFaiREdvShNaX2H1Uwxqc9SCE1toAFspAbOJiVJO8FsgoGSFeq5Rabc5ZPipFImrlGBQhrGfqoiMg6g3jLIaLs/UeYFOt5tJBj+qdVDChuTil5o9CC+BHchHlq/2rAkGXhFF2gWmJDDhy+gdBj0c+iedrVednn69rTIPptwB/4qyp3ESlnORJ0ZvorR1tY5kK7y84a4SmnKuI801oFUZjwdNLUeigfwf6eVq/fFzNFxm9nnocag9FGON1umgpNU0V2UGrhPastQs0QWPgoD8XO6vi2NDPzH1AOZTPG8BToLmGeDJboWljV8YaaT6RJQ/8SWiCtIZ/r+QXWvW+MKaFCcOBq0r7Zh+MOlhSwHF3ZO06TxWVsIoXiN8T0OfDusqzbL8UJIWpC9nm+yrQWQinWRBWLAXStEUA5SUAAc/CikMSWwXA3N50tL3hzxFoDTMAdMZAFPJn9e2cJwlZ6ZJsrAABYg/Uoh5+VXz2ar38l32dM2HDOwzUM3x3R2b/CY9+o7RIHfBjjRn3ii1zX1iaPN8X5UL0YPK8X3bs1OBP2bUwulc22fYY4Ptv9pkFWPXYbRO5sK/n1z0r9sEnSzC7mvbOqUJyaQTeG08SUkWl/DAAivx8uCLviy1sWuF496JW9FN42OVX4oloRtvxrXDkM1M1z6oEGhQhQLtxLVvqaCtU01E5d8Qv7sX7VTJcmJVS6OoniwieqROsGWPowFrdBnl1Q20mWF/QUcMG4y24rul8tPmyCsU7enFYCJVy/PP05211M3iFzcJ7h9QTXn3xTht79gi6Ntv4pYgc3+Bv/0SKntwrR+uOaOA2+c/arK5hFC7YcJ8c/KT/oXuPUFq+nsvUdCg5hgUFCoLOeJNBOdbk6dQZr6nyM0ZgS1L7LsIjBeoFy3WrnMuNgN6rSbgb22b/oZSoelPxncM+5xrCHwjFXj0jx5ozTXGmJQAAAA==",
-    "UklGRmACAABXRUJQVlA4IFQCAACwLgCdASosASwBPxGIv1esKacjpDYoqYAiCWlu62AzMM7Ou3zXBOZG5/gdxjuMdxjFPiXTLrTPaZ7TPaZsyZgO4x2+sW3Llp2JfsmYDuMZEcnht6bHxEDr3NIUfDuCva47jHcWlE4vzaA2lPiPTasNTS1Qvuzk1OX8DuMYM5uozahYBPgZIlpmnBmfdzkSiERPOELQ+0z2mfIqf6i0kp/QmESmtBEtrd5rDiHY0nuLoHb7Bes/dppGpQkQhy+m65FiMb3Xx+pQ8iVSs8U4qFW7CE78yehyb85zb3SwbztTchdsh+8Hv1WIL/pQjbkxthrua7VUrjot/Mejyo/k3SjofVqQJohf6ZtMfAe0z5w7k9w5M4GJWWV5adSpeCh9x1I+uDuMdxjug09phsCUzAdxjuMdxlQkQRiCMQRiCNYJbg1aRth7TPaZ7TSy1AucRs+gdxjuMdxjumFJXrs7Q+xTMB3GO4x2/gnVmwkJ4DsIxBGIIxBGIIxBGIIxBGGgAPj3+rSaRef6D4s12AeDHqxecpAYT8mywBtm5k8kxNF9l0z+gdzI073NOBTsaMNpPTA18rYhn0PxuHfS9baoDPjb55MeGRhcNlR/KeQuCvg2i5wpKhwTlWIn1LWuBLG9/swGLUEC/qbtrMudPP0u+FMjUopl486hVIeXTuMr7tQD6ZTauMGWMluplvT4A12rs0I9VJ/q6l1WyHqj0xtu/y172ZLQC4yBI7dJm+GlTCKAiQ9ycG+qIPyaEHBs0gydt715o5fqONcAVuNBXJu6UTgpAAAAAA==",
-    "UklGRuwBAABXRUJQVlA4IOABAACwIQCdASosASwBPxGIvliwsLUjopPo+qAiCWlu4W3BG/Npo39/08oBrZIAva5d/3FnfUj8/cu/7izvz7KIFnfn2uMwLymHyuuuNpdagpnqMv2FaWMlxwS8C9ri+A4fZu2+ga5t2gAs78+xyR3lqa4BXChVoH0Qb7l3/a/c5C4vsd4SJ8MqKIac7QR59zRP/HghPajOy2QZhW0Q4ebu+wKwVdmxAuoWCYiUMzJAF7V6UGiJspChLzFb/w2DwxXgXsZ+CE5wY3OoZn9iyKIcWd+fa5emFL3Fnfn2uXgsf9xZ359rl4AHSF7XLv+4s782d8Isy/uLO/PtcvOZ7l3/cWd+fawNIhetQU1YXtcu/7izvz7XLv+0wAD+68/+g+Ll//1E9hHffa5VonhBcno2ShJiexzzlJYYjvB3c/5DUUk2Vw5Y57/TAdP3D0J1hm9MrcLtIoplL1EvoJdmTlAzEdTLXqXsuoxC/mt1MtmGsy14Zz8spVOZ5DZxN1A9X2xffmsetZqIu4eeC00VAO/ebhD83dU/fA7znRxLrsbVaOSQwgxh+RgJWzsLu5mIxvBBjJRL2BxHRdTS5zsV7uT/HFuNCfPLvutgapbpEFj5XWfkGboiaDvkG6jBHxbgk6UAAAA=",
-    "UklGRggNAABXRUJQVlA4IPwMAABQvQCdASosASwBPxGCtlasKCSjo7JcIYAiCWdsGtr19/e1IViZRggIf+Ixb838+/rlo4YOeMHZs+v/Ti+//h/7+Cgr0Vf//7X7cRcjfVsxZV0ypoxLbVCY/31bT7TxdAbEuxHgpc5IgN5c6F/rSSguzeX6NlrR8gbW2RI5+FVnbaQ+j9vVXAZ/1gxKkHgqLBN8XeNOECp9/qndHJ1WEYtY6tDNVSS+YJ+9M4aSj6YZCOtdQefYerywWnDlrseoJnHBfwEwMv8bYApHxLYANNy1uy6tKeGSZKrCO+D5RQa7+dIKtqNKemQBopqUqQDm6UicN/RZ/OG9+83hlHcWNqcknqgPcsYOohAhP9DhcuOy/vBXOzirOu6jBQTRlUPvKOL9u1cQ2mVPf9h7lgJheZG5nNjZl/1e5qmA/BApQHalXYFx5kDw7jie+Pc7e/17XLdF8BzrGaZi7Id4TRMsjkX1+mmxrD2bYFWnqP8EDtOz1kCLlynZA+DFhxWDJ/sXwPcsNNKy5bOOQffpgd+5CDPEXcpQntolBpA+YafjySoqvPZDFsTgSmS5hHqVPAhOtr8EeZww6ETRVYSVdauHLZuo0pkgWxDTBi64/PjEfeAqT8IHwnXamu9lazAbrrN3djwUtaTH05Pw6vCEASRAnDh3w/2G8a7uQRdotNhRXkMWzrxR3rB2NeEPVmZg+n+PGF75IkiR2y5SGhcpoD516M315yw5twXDMw79ZCbjfaSQmSiq1Hd7DMdM6XRyDIggBTIW7DqpowRialFDizdDBVkLC+d0QGngjem+qhvUUgUMgV3voddCfYfz15j6pVgrOl8lkxcnUA/SlhALTT3wONw0jJYMAyLE/OBiALHWkzcRCJQ9j66mpiP23P5qV3PpXhvRJiC8EyZU93ufNnbP/CntFFonCmrn1A2NgldoHsbL2olHyAajonErdmXuvpLSCSpoB+Qj3m6Ba4oCtbuBBs7B0FYAvhW4lHGSP5t3g5FVvX/z7XM2eS/2GPCrWf48Vu2WIxhfcdIxIHGJp7vLObvIVaWzQfldXNw4cclsrzfp88EQLw1oANBlOntMn0T+hea36yLNh52QuA9bYhsJC5EI4Y/foiuX8cADd++vqs7dOwrovud7iXs+I7gqr9dHurHwvv/513bv5vNuWFo/eezTPFC568OVmMINqiJo+31hEbmtBulnAWeLyt+IP2hCc/5kSy0pAF7cvuKVTkjX1eLawrYN8u+qjbUQD6TnUvbp2eYns5//nJH/VHqZzePfiEQuBdh98aPZPNMC1+dvl4vMthazMcRd2zVfzprlcZtEqKj2rtA8zt1FYP90AkHaqzWbpVbL0DjgHvD/2F3YZ8eFKMzI0IST9ROEOx0hy2KhRcBvVBThaU0dt/BCLHT8lTZFTWKpFWQiAvPS1SliipRfHlRFacdE9ZsMRvhd53e/ybHXmws6kTQ0Tvx//N0n07IGU1kZep8XbPKvoy9rsg5KIR15NKNrwVHWM+Pp+OdZ7O0SCBKfSYisHADTyq9WY/+9utevByAn483D0KG/UocolbxvViQCcmrK+om/3r349W/Z+b/4pqHkRJ+add1KexiGNpzpAPAT/tAe6zAoBrydSIv7jZOjEeMMiXoBptrIOBRoh9nhl2ax5pR9JhH6wdrN0RCmGH0CtXiZnXsP//PlhQvbvVX7G//41C+entw2B8odLIyk+ZTUi4q/ASwtQPoJyT3vmQL7QjsvGsxRMZyAWi6dOPU0AlxzeZx3+AtI2zDOcBVhGH8DC44xoJyl5U9VX8yhzh+163zdxxExmHdU+nmWAHRud95PI/4nJUvHSRW3XO4bltWp165atQzUv/Mgxnx+j75ZQ9JFtSpWP+nz4l5rIF8FYYvs54t9httPGtbR74Q5uOfEFRRqlun0qv8ZPGK15tnEX8+8KN18BsvUeLlo7ERirCqwEHmN85H4kYtGhlkOAnDeeRLM/LKbdxcwTOV/lLrTf/yLc0cOulxOk7ErLX9ZaYhfLQV215FwAT85wAD8W93BmJaPj/8O7m19y/9JdLkD8Jnong1UZpeuo5CXcyDApt06purQzua7wrpbfwpV7uE0LCpoWkqEUHf4oWoomHbXXfYzaEi3mjmTYzOvxV2BCQhqcJRhz5umYinCJW6M8jzKw4VvuCc5LjgStiG9lz+kxOYREA+v9QOIIU8CTMI2572B9ZLlUbatuag9LrkilrBV4bj5WpL4tXVDTrItGTj0JF85+Tp2gAHkrv1U7cgqM3vHykcHcWlMz0LlLRafxGFMVM4HPpwU2q2TwwgIU2AGk2XdX04sxA4hnbJGsjtKgBeRvyCForIDCSd1cq8hduaR/thjzlI87UIz2eIjPbQIwMewJncz9PAoVinR/ftmsKRtIJPP/VlGBQ8iwTILnU8UVoRg7vVG4nsOak64nSB07Tbl2gk4SygBRP2zfKEaXmJ9uTjzo3s2hKhhCIZMv54fjlgmGZsVWGjHwloeXivTyf9fZEW+zNOCzK95XT1eT9cy86m+ivY9Ea5V+Px68OWGeBLsgxjqAH6+VW96BAjkFq/s2WmgKOWHa7Inhv7LorvWlT1aZFYiuXpV6trTF4dSArgt4Lb9oO0nn9FzCX+9Ubhq44w8yyw8aL+6pD48YkmqmodlKnRoRJJnb4XLC7UhOCk4nV6YZhUp66/qbED0DeGyESrPsbFwOJ/9/NO/jBiQTrhmtL+FeirgAiWE6WgSTkZdy32hj08++xCpR2wUXc70HyLlEA4fDeeUEyEU5ndmwMNJ8oARdQxpTQSToqNo1HaUvi7ktP8M/uL5kltk5G4XaIaFQ6YqzoB2yOCbA4S9uVTx6az7vfv2xM1bbP09kKuMxhXn1w33cm495c637s/+WwTpXakUWDqukPwuL2nuvp3qMaHkAkPVRkngUv0EnYTLpygxd+5uSmmICN/yK7zU+XzVW9AlUlmA86k2tG12h60we/538xvqYDK1ceQkenL24w/e+KS+KgKXrY/4uQWIDmy9AmALTPUjWvIoFieyi3gdsKRI7SugjTj78aZiR5+9lr8jqAEANrRoqGiMB/UplVWJWvk2MSBn+KNSW5OGQqozuYa3kQlaWMJTgivnsG5goF88Rrnmb6Kv8vJH3Fund5C0zmarf55ACfGVH4EqHYmXsM19zh6DE9WELYTCnCfTPKnPqe/zC4bSYyVYDNgiahChxf4vic6qCYX324zDNHSBEw3Wd7RXNIWsZ9Gm7G6YQexyRCm5wBk3cyFR+e8xMwPXmZe3PMmOpUQocLfgXP9nvGUCNvufP3fgTd58zIByP70hfBTm4TU1NvLX/kVobplOKymN36bwdO148EhNLrIgbk1z/geiHWQ2oprefY9a8aTlAJut8p72UTlA+QBgS6dYZoEEKw7eroF7QPRaObWEwJjIXWRGK6MdQ4BREp2c2Nk9phVx7WHBty1F3Kt+QCOPFBSDqxV0ESdlv3fHV4Up0lncV5xzaEzqgbVooxnXEtBNsiyIfKwuVlJcT0iCSVL1WIhBPZOxrTFB7Qd+Fbh4gLvACW/l7A2J15CWJ/WNY13NX2ltFctu/IHgQMnA+XbklOnDcaXxpAjqK0ExBb/+wH7kuiLeKGYi7y7bpCFMST/PDtiR4ePmnkYaqsc0D7rZYe/J4Ac+ZbZdPwxmLiGFKVdATErlwEO5VMQHiRAhB0Kc02gd3yxp92oN+rm8RURc0G5Lm0zTinOt72EXvqsqAuaylbZpCJnIntWnqfFLMjIllS/rmW8LsSBo7Z5EANYP1K3nY+RrjF2HgRbJRnDFF8x5+MiovVb8UJ2BNCeltmYjApZhMfrsWx0wBB0n3BKhpZ6wGy6vQiE/p1QvhkoBtzS5VFHnPq4gO8h09rvGwoPfOtN0Jb/77jayIBjvbvzUqZBsgnm5F5ZYiPKnJaPQxRmIZ23Rxvs8ompwuog/Jr05nOR0FWiMGOLW3oDM81kXeV+f1KF/kGw6AZZsVkoFslQky5jrqTEfZQbqeMNdlgMBVBYs5xGBe6jm8So/4qwmc3QtB7sqNSnbGryGAiM3EU0KOfTVfFfobzIxoOyKOO0wMfCzxbcFqvRPwOzpwuLppDKMY7GTlfI8AySI8ViuFLpei3InY7wu2I75t8ObEhttkNmlszDCx3orGWnypmUkcAjoc6vXeKGW/6glZwW/Yf14pZHZwFILHV9juPQuaAXpo+2QhepkothqiznqZuLnW7ZRsYcXv/gFi9SWnXb2eAW0Fy1yCVYJ/ZlTMhh3ZlaiYf+zB34rwnxombLZPtID1/h3wufF97wo9fQR9B7nIQYW9JdC9osfJKbKCgo307C/Z1K4u4q6xO+NOXlCMldElu5Mmvoodgn3gfoGlqpFMG+fDwgF9Ynum3w4d/kyZkNFOMRBjjUPcBJunqgAAAA=",
-    "UklGRngCAABXRUJQVlA4IGwCAABwLgCdASosASwBPxGIv1esKacjpHkooYAiCWlu62AzCM6VuvzUA088gv51SSxP5BT2nIAgcDudUksT+QTcvAO51SSQhJ56dI2OuhZTZ1SMgi/8haPiPCleT3Bwqn4GFYk2dUkrqLoO5LcY5/Dk/oJT1cOG3Puzk1SiL/A7jeqUtY4pyg/wsQDiavrwJff8GHtTzEfgdUksT9MSbJIgzUEfC6lKXp8P5n10Ak2bhMtz8Ep/H+RQ1X6pcSBl+eMU3l6O8uTWwl74HX08UzY1NuapGdhN20Z/Pd6FPLGaLQgPzWgXHeVURGzOrTZDUm2y/2hw4VdOVL0sZAZtcQUj0mciIyTRfWnaneQ7OFST+QU1VPTgAovfPL8pRDSg0cn9wJKSn2dUksT+QU9rmRzVJLE/kFPbMjYRifyCntmAPPEodPAWEYn8gp7ZgE3kJihJqBf4Hc6pJYn8xH4tSBBC3FNnVJLE/kE3yJyDJuW8gp7ZgDudUksT+QU9swB1gAD+8m7+g+Ll/oPi44T6BLuIKMMYaLBL1VDuUOmEbw4eQ692a1rEHf0pIYirHKC7OzTIvAF67uI7lPkkoRBcXcNq8tpm5gnyo/y54pP4/TOm8S5dQhVhuu2LvKaMFbLzNAR/qzo4+MiDAqrHDRn974mPKc+3EO7fIplqCSux+dkFnW1NeHrw3q6stv116b1plpcqceuBYSgbUyL+6b1pkrJFledxPuplvT4Ov1srpiJjqpMmOWei9Cq7d2HcOhf00jUmf7i0H2HtIb7FwPYEPxHVx4LoMueVloQf/QvrhwIibYJcCGlBYKyFuyk4F8AAAA==",
-    "UklGRn4RAABXRUJQVlA4IHIRAACwxACdASosASwBPxF6s1OsJ6SiqrLsmYAiCWRrA0ree2n/bpu2gewE8EaC7133utPkf49nh9odfnTWxx+gtO/v/zi5o3oPLzFvj+is966H1M9Hpl022s5ouf64Cgf0WnnojtJeotODimAFc2TdH4Fg8Uv73N8I517WJSTLJjwVIQIUriR3fc1Upg5zIW6njLhugNAYyiuTJAUXV3kn947eQ4YsJ5lwlfqCAAHvbIoWtwWZnc2FpDEtdx1vUw7q++fb/aAl4VOHXZrPqC73qxfSR3+ctj+Tt+BG0xMiKszUbO/Gk7bGB7fK8XbiKL/JtA8ZEnUWoGQyatgl5r82mGjg0TEHyUyAN+zNTWbSTxzZrCQz12PJ1re5dLrCH0P/058uQre9+wTnMry9kkjkGQQlaj3zxf+q9cijienQjjxJV4rqrssdRyf2ckPzQUzG8cGn9p6BoZZO83dEtPnMP9AIWPbtuUDiIOvq0xBAwwKQKlF/ZXT0IRPepR9hJ8m6TXXjxWvY4lNjm+dJ9/XGGbZ0YxnRcMccmS6sSRuxva6rV6sPNf2zvvT2BzFWWVZ7XQwxuLgcmh6uIuz9WVJg0Vc/5Iia6E91Oe7J48ENuYigGm4+IKVRjXLbSqKVKyeTfEuf8CjGGu5m2qfhNCz5QxgzknNdGZXE8g+v2Y00N1oT+dUMz0+P6cwdPdL1iS8gRt5H+3xQHvZpeVDKKwE0p3qDb3ifR8zK1odG3EXo/waNeM/07sOY8i+6Sqtddk5iEAiDjX0xOFnnHt49Z0j28yb/L7XWMOgS9T2GAyKlTCE+p5DtXaY8D/ISUGOa0Q3c1uIWkbxz2oqPW53FdAqkrhFN3aGJqnZh+abY52dnI5abSTGL4AbpwkfAUR0WhaaJGIt9qTJegZUvgAxWO7+UTmiy1PbcGG9pefCOmx5rhns49OFL+ouclv/4irvyASs7oxn30ew+mwrODQWvKyQtoPfz3Awh/yWj3KdkPMtjahWdeBPUqGsjVFlDB7h0wdC7UJgWFQLNpG6agH+i7hu3qNviZVHpCUAGqeHyaOP4dDYTWhqBX3yCRPKlvCr1EjV875bssBrN7BWGF0HOFTPLimcLDzDvrGJSdKJ+QLgxhq9vN5FUUhrlaNXzP4m3grgwH2f3kBC2q9FMmphVtP8rktHOTYkfObZsSfvKdr3xvFPwygCx2aYtffP95Z3h/4L1h978J9InD0ziGta928vga6dS4o7+InfDhbdlYV2sfSsvmpO7CRRLjLj9nHvDqfUeZdNbtgmhvJfoIk0xrqSucHWz7eOkmMdLZS+P5LzJvo/WNlpYwZOuVfDmaOp0g8OYPFvZCcB/+FEL+FJCvF3N61XIJ4e7mtefZ89AGcwse1D8kvq8tghuirQ9PVrDbgCPCaW61hm3LviHRnG+L23FEFULzqvs+EvmFe4TLPRVA/q7xDHTvD4KS1LpcMwGcAqM20zRmqz+Lthhyu0bbkOoGu1IHfFRrZ2Mgk/NqLsbet7/6y1ubVX73RrD8wI5xRB6Dt7VIF0qUTiKzhb6yfT2YhoXRVeHf6gfGnV0EXOclUuuH9+Bn/KzlpcXwqh57pfQhvWVJ62TRPQTGBp1WRcIUOF7ZlgdYzaLT/eka4T7Vv3ME7Sr5ahshw+7qpBdPamPdHd5HEmkXaz/uq/9jNKPqCgLsruB52cne4ysiDXBFUcKK7w2Q1Ysgxdd9VCvlqkbKQoa+F4/NptIArPhspu1iEfesFwe07/fgJCIg9Fd2e4mkanrCz/Tzv/9YXakxr6dTa+Bp9H+6MrqkVQcMrt4bbnBmmvDZNZavM1eFCzQ4AUL5AtW+kvp1iEv69nYXxFws4vS2cVzUFpHM6DXW0PtavsLNfhFMX6X2RrnUVuDDPGLPu/cqoMO/Rkr349/aQZswYsB6v3Bdn3F6rHwoaawC1c0zPss49rVJYccVIu2ZbKwl26g6Q3cyRcwtHAsy/XaW7VYtMnTUfIwg79ugffGbSNoyr8CzoxEnccaW4BFeT6PG9E4TvaxQ4z1SOwsoZhQIvbJVfr7Gz5MwnduURzVR0wDSZed7bbLGBwSk/GhtPjclspkkjCqAEyA1is6I50gAP7NbZbj2VpWjf3FOx0g+UWDd5qvzpQr9Nu0all7yPwNR3hrOFngHR1QIQeRf1Rfmf0K+7AWIuFVm2rZ40GWOVRjG3F9kifI+EjjSxw1mdrZDm+HiW6Tnwoor5F6MqG9loSzWZrZD+ztFY1gTVU369VonyWZ06Osj+IeDyMgO1inj6Ue+fX9BWfqaWCoSG2HGgMcmRtb9Lo3MDo15UmbON3eREsHy2tUgTg2Sl5hJjr689PHY2OmlOOaLKMuKLUL8NWndlnUukpG8vECy4o3Qh5X+LH824S7DMNAC4IP8kC9Rp/8OMahqYS4ZZak0KKt9ewx0jin/poCKVaYy64q1OuXu72w0V5/XxbihefSnQ7gJtGDr56+ylqOPN2ZldUVhz8ZxnxDjckNlMxQV+skTOysdyrkcKJNaw3tegcwC2H91pNC4ZPBOeYcwnS6spkihgzFVJ9+JLxLIjpUDNssm1KQrIBRPZT4fuGDw9aQuq/NI2FZkf0GdzKR5fQaLxvrPUNkYMnPfSttyEGCShmF4zNmRGeZ6HtduHSrwf8jHdLnJqSTbpD1QwBE1kqxPpmi4qB8/4R8E4TVhFHxT9l71XxCe5Uj+Z0OqpJcqXJz6nD9ec7n22lMNhSUs2wPbIYjqsdrRoa8vDfFATNJONTM7pp8lzppvngeMuApconZW26Re0we1sGC3S769Ty1FmTxp2n+nA50ACDYt+lwr3NldhrdqXMOba4mspwi1s+bu/8QL/GOfXmZRO+o35iMpePRWtQtwJTNWza69ZVAFNZknpR5LU5LiJdK0SylBujPtQFae9vUDnhPBjpxKvzUhLETRVmOpgPAxVJQzsdeQyk26MiFOXHVrks/+3Qeb1LQdjLDWl/etUf941yMwM9KHRz8S+xt4oqNix1mTyVjF/dh1lIIEUqREF5S+3Iy0gwm1tgDAOwlD+xSZ9fhZ2jPjE9SyGDvvVthPNLQ6cnfwgggvSl7GSWW0U/ozmkOq304nz+CBQlpa8j2FklXI7Qt2mTTIK8gIyV9eok/+o2ZyDLuRFz/UgOHfgMDxqp2egClxS8y4drPKABCwZR9IoxB/y7NfS0I665cjJyuJKZBCHfo9lrXDcRSpGyDYgWxJ4drjoA9p2hEGhfCmEJJq6PTqUC3keellwuATq/PjonkKm2CSNSGwrJsmj9jysW8anhmaHkjyZhMmihOJloCPfSASiJbG/kUVqaFVokytT6eKO996BXNIZAqWJ/js40zMy7pX9vFQntRZCTpYOcY2zvwSWEt+aN8ALo8xPnO2Tu5QFru2Zm2Ay0R4//egXHxAuY6ZwZ+ofxGwgyjljfJo+GLTISzdxkqO29RpolqrLxV+a63I3gxn6k5QlIQxBq6i8x5bIsdFePhFsCnAok+Yq1Ayu18ADGClBCO06RVNcdDVNzWxkhI9tufampdjFkQ6eHgKw8POIQMmArLjHSbOZXR8Ztd94SfpbxYNbLHDYe7q3VO09A443skfB5zstVL00LVSKWKQ4s1ldiyK4QlLtAIP6PRy3JfEfyzn6flHF1TMDxaofYJN6tI816Z5jhcIKZz/Ml5ylrXzYTK72VMAS2Al2eaC9aWqp0dZGsMg0BWnXG2YohyY62MUZt5Qsqmz9sG4+0IOtYpnbV/o8t8np7BDewOx3OmYaa4nU08DandpClWCmDSdbV73VYxB4Vd+he6Li1ScvjU9O0q9jercPebTw5/M7Peq49dUZ7xHgCweCL5Gnmwb2ahbgnWSgnlwga0eI4ZCGAbljARGC33oIw6be7tYyY0Ai34oOrRyYTZaxYqsXWNsO0B2roaHbcsQAftBFKKrUgdZItvU14lYNYKwCx/tG5/jGvDuS2z6+EX0ct7cFfxd8F85/+JC1VVZcKCNAyZnTCVv3aEAZY9htiSZyrbCsj6KzKGH3Ho1RBOs9O56HyyBFr0KP2NXN/4lGRcdTRQzCRBLHzp8ZcBD36NWfPMCT2Yue2/OnakWAc6IVY97G/ZF8dzOJ/1+ar6osJFq3r9dm+Asq4yOTfBTGiiOyPEO87yihjPPqs4lT01vlHfOVK1qpE//YgeDTdtQQki/DNtoxYyOqrs3mN1/hh5TBE6aT2c0QaRoW7LoVU8boCxODr+rm5ZtFY0qysbAaayFpG4c6/I2vNbdvUKFm4RANREvLMvTuRswDEtTlOcdkSaagidRxgWyPTk4zNouxKXCoNSxzdr4uMriGhGmMconz9qQfJEcLKIL9Jz2e82aYbrV6M8wY4qE/YAGMweUuBYjOJLtbThgrkrUatsETDr0RIz1ouq0PUR0vVxToOmtAQsj+ypDKTrCBWH5GUh+SDRm4UL19Bvyf6j80fy1WIhEBne7vuvSv8hv0DEjzoH9xNbvPCm0W/my6bFlyS5eL09fcQK1D7cVST4mcg+nYaDDoWa0mjBkI+84rpYwW5beWtR6KhDydb1cZ/eeHhBeu2uI1NP/P/OhRXxv6DCRdP8d7li1I16R56CGMQVvRTvLytiqU2O7nWmkfiTk2cm2QnG/K23jlL06nAWe8B1jBnH0vilyrZrAURouql4p+0c7uVCieEVYYSZsZd14aEnZNcr8tjnTCXNd9sF0w6wQWneN9Wa0EsHhTVds//q8Go3FTAg8RnLoxraVxRMjidUcCkEudGUZ7iDruMF7KyejBhDp5Ire0SKoqtS2YG6YVWFgqMYEa0caf+Qsm8niZPNCYgBWjimU5PvX5RlSVZ20cLj1qHTvQOR5g0XXZ1jqm3FFr7I4BCFdL5/4QWvovINvMH6bW4KdSLAZIZ/LPc8HX0vZ+dcRzk7Bzm6ZmGVA3YxCQ+yGFW6uKMbb0cvJllJR5ac8z7umYHuQol7lDe1MAcMqz8VD8KVCMgeVqflS13Ioex48nexJDhhqCctGKMsIrex5EWwzOpxUR3dCWcZR7qoqiIsPQQq9SLNXOnCBQqqV7N1ynh1c211RItfizESBWNg2vzbjN5PCq74gRSxOcBm9xG6RIEdjKXvoOrUlzIncj34EJ9SgsAHi+nx8MM52f9QelhAfac5NZyhoT8xh6Nh8xqKyD/SFQKAW53FEFBUuNv+dM9fCDkwZVeuIbj4UR0bnDrYc0Zpfpc100kpkF4+eH48H6u2X6sg9I0YWYMP+ONyQi7XsMpISGxmiOK5Vpp8pN+sQj+cfIJcli6L2QEbRO2WIcxUbsPJ/9A21OutVJsYMhkJQ42vFw1jJQchhrbbZ6URZt5nnlS/5HUztFsV7Sd55dL/UBGjkls0z717scUmmPYOev6NJ+KQdHfPprbBX9XXmyblEqTjnI8cxYX17eruomgla5JDt2uRbvWx+cgRja3oSJVdbPXtO8i7gMvNIWYmwMRGHt3AZj5KervDP3yD1VfYBcbL7lHzWeQGzBalD1qVksBV5uyjbYGhjgasufjvA6RB9Y7A8FhfOEnSWxEoT4jllPwjtcUc+ihyqLweWaVl9NMI7AG6DuUUCGh7KrrLzOkg5o+IkPQCgvdzWA1ix8nByeAfy5kzzaj0ow1aykamArKWxo+HjcQWuh8ylfMcyL6vYmGN93UepZAZVl2jfCXGzJGBSK+UcCUOXhR1VVXK1aaL718o89BpuGLPjWzBpxJOP3LMzS0rAH37RIqoIfPtsYIjMYhWtq9nIuYp6bA59hAZQHfkmL5uLCV/KciKvZY54HzLIgnivE+J2g3uOlALxt8DRCLrbmL+CthrjUiKouiITSCeKMlwTwMxTfTnf6hbsSjQRO7bpQWusTNjyWzVGXBtjwRXbWw1WUNK1e+mZFLWtTNcC1qRoH+8+6yk5mk8dYpFoGrJfGFDzxASLZ4AAA==",
-    "UklGRgYEAABXRUJQVlA4IPoDAABQMwCdASpeAV4BPxGIu1isKK0qIzOpCaAiCWlu4WOb+1nAtzmAxYj0L6YUBwCvfq+g0SYgE6hGOgIUgqbzWzlItJRNYincIG2or9nVMeukW5XnFm92P/bxHK2VL8AMFN/zrzgtW3wmwzNLpPQP/9KAkohaxXmSVr0qi8HJ7kZ6kvt9T61xJTjh5owyUHlcdYrSQlVmvWaHpGu5Im7EC+dETzQprdB0Xaukehi13OwJ43cC4xkYwajbUfmoPYpu0kgO+A5Xb4ainrEA6CFTJeAifTMH24XpM0BR1ZGbZ36MslxJaIVSiivRQOB9qnBAN/E/xB71HPhl8pmiNqMMUBvjnjXwLCzQG733Fwz4GcXDBVgQHn3Fl1suGXfMZ9c2rwVkZtp8nZPwy4Jtwef61IPtvjDAG5YKfbpYIFGz8ofQmdWu+uGXNw8BjZkA6BGF4bZufGC3cLi4Z8DOLhnwM4uGfAzi4Z8DOLhnwM4uGfAziy4GqFprs21GGXjbUYZd8xTx0EKogHQQoJTPi4Z8DOLhnwM4uGfAzi4Z8DOLhnwM4uF8AAD+85J/TlbH2fervt63oZNXN3XWGJbz8z1xDpUvdZVlXuSJ/rJLIKBhCQ6gzD6Twg08mFHNBJtXaC+f7LCQuPSOdQaxB1wQZm+wQSrWKZAZhDG5fxegVn28D2tEhCIjgUiiZ+jl68VqUcOo7uPx2amJwZONcIEU/9QFg77+JE/wz0IFF+kilv8XuDkGr4Zk/NCq234tejDwIGG7hgMzfnyWGX76M9ae/FpdllGvNYFpk14uhv2cQ0InKcUi4ragn2DmTKK8tY8ryvCTQZ1eDN80UjfYoYY7krU4BFZrDCpXRnpkO6kNq7CWyST8m4HD/8cDs8HA7fw1CWWbb7zC2ibCCuc+k+DBU/87gChv2UYCUSP+QBq4TPuXt+Qv0rEREXT0czy5jzd4xkGoo4rx/qXKap4pV1dp3xAsrQt58AkBgcG3zGGYTZGnuXapUHKEcpuRfCWybrZTwlkExWR3p9RdFRvEyjROpZ2WW6Aatzfkstq5L3L0TKOUy9Hp8qovO720+odmesRcmlbySMi9n8hl1lzv/6EojfBbcmx3RM//2bJ59wTsrud+b3ZciLGRHiZkBueCHnt48T2+uExikr8S4PPuhWPt8WWRI0Mx20HWDOnH2n5d0rZ5H8Q2iengiMPTbyRXWQOfXfPX64BNQ6m1Bdw9wyCI+xXVc+jcLkk4iVN6sQD/m/gDVtIt5N4hHHkxMh0BJXvcNG+EzrBqBPUHtMrslVlhTehYnPKLt3MUTfCCPtQhOlTaAAH0VVI1wVmCUTgopUMgJwmpMTB23S4THYAAAAAA",
-    "UklGRpYCAABXRUJQVlA4IIoCAAAwIwCdASosASwBPxGIv1isKqcjo5loEYAiCWlu4W/RG/Ox8P/2m0JjA2ojPB4M6QJo4EDnBxAbDtNUum7w+hmwPQbAOBjB9UBfMpqp8UHLRvt3TvwK7yZngs3ZyWUlLys4SO8qDR1q+QVNE/Ar7qaFUuqS0TWocZW6V7Ayl4oV3XrkDKaqfE0qEQD4oObDtNANNZN7T8TfAC38k4DtVPhJafOw7TVLqp8UHNg+SwoObDjThJSjVLqp8IhCYeIpdVPig5cX5eCqXVT4oNpvlGHaapdVPigSPt4oVS6qfFBy3yGRlNUoaHoBQHaal6Zd4UHD+rJkLZ7AymqnxQc2HaapdNI+bLZAS+9V4DtNUuqnxQc2Haal6bBnYUHNh2mqXVT4QAD++5s9h5BvJzPUyPywm/sNghgz6Wc5/TPDSRHH2LBntj9vhCg2ukabEeM+efKysf9hsDMdtmrSYpggl9QgGnyqen26wV95WDDVOmzeFHxf5FVhxtTfXQ2q/4ZqCBguBPAl9x58shwblp2mlMUKnb83TeAfrXDX8uz8jO0d66exTMS1/PFsmcQMzptxX7XZPLhUFp8EgpyfR7RASI296QOv5B0C39h9Wg65BAaqgpRH5mzB7saVYijag9INsGitBGl52fTFrNIEXtZfYi0CjfiDIH0b/DcqOJ0c9eFrv7/5fXnCvgfUpsl/lrHABXSDWJs04XefYsJn15ZBnRUsyG/2HyDi38ZKtgZMsXoEuo2KB2LxSGaElwnhvV9U5xmjo3mrvpUxI7ysxWgIhNCewDZmlQmYdy/UEPwn6ELQ9wQQYAgrrt2xVw968lI8+BT9omSJLTTVnTJY1pQD8H3ZJBsflFiCSAAAAA==",
-    "UklGRngBAABXRUJQVlA4IGwBAAAwHACdASosASwBPxGIwFmsMKejoXEIUhAiCWlu4XPhxp3iQARlTXxAqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIqG/hA9g/kSlznPoTZ7T6CwkM2UECWP7V+5sirwIBUn6YHVQlz04QaCbMzIyr/uYmOJliGvQlBIhUNUtTXySr1YkKmn/Q3ZizQTZ7T6C9/afQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2nzYAA/v3/5u2TvS/3J+iMAAAAAABUBVi+wLUY9PxLL0YMv3BxYXjv+2o5EdLy1FgRae+TKnqQTQRA6rZuYbB4zD8ehtXBNJdnsmy4n03MNgLgXPEO0VIma/zLhqd0cbeezDetj0elqCPhf2vY6kDVUzqZo8AM7xfs5CsAAAAAAAAAAAAA",
-    "UklGRuQPAABXRUJQVlA4INgPAABwYwCdASpfAV8BPxGEu1esKC0po/gJQaAiCU3cBADbBOiqi7eOQcPeqB44/W99rhy9KuFmeezvwvRd+s9/rMk79CWf9nL2Uy3AffK8+2dHPpr4xR5LwFfvomb6nPVLaltS2pbUtqW1LaltS2pW3QW4iPRX6p7M5p4bEfytv+f8s4YSqswJfr7Ws76mfl53dzU+3XWclhXeL10W38/8deP/tS+eZfUv0czaMtOp2LS4jR7xuMdy+uXMuxHH0j6C9/J2EOSlHOEKc+TMt07KEkR9U9n/mbmRjtmCN9XwBQ3WwEnsp6IVdZgEYquIdLv/AFis38Q8KYD9WzX7lZTh7RenuSV+siGFW4DZw+mADbzpaYzNdielhCGfN/2/y844U7FlL5gEDe2a1kVt1fG4r2ZVPk60YNkH+k7d5NFuK2T/Iv0hhXcbU2RFHFVIO2zEWsPIBxHORsVkRJbu+aeq2zSPHDxjlQviuLIR3agcL+7KK6BRDco8pYnIX5wXsTCrBqWb7qD/kClkM0n7dAX3ZhVPGpeV9STvi2k+04IU0BoNiKcw5X+PYxjnE0cTBnDWPjM5GSH2G6J5NlQ8cyatbsyqw0EpT1L5ad38BQjWX7LQ/Aq+Rg2bO77uLlgdB7WeR54J+wk1qjpMBQL4x9WT4K8TiW9QB3W7oS5pZc2VfYU1ZDx/sg9CnG2dutiYgnk7o2l0C+wpm+Bl5+S0OkTSCAfS3HOQTtdGz9cMBQByxfkqXtSbXx+nmlHGcxcIC727Lyp807phUz+4xEHd4BH9rfcX5S+bb3ByXUOxKap8CVFMZhkBcullM/dmAZTwk90y9W6hizw25WhfYFGfDmgfq7BEk4a+ekzxm6nMIncQJEZAtW4f//lXmJtMk7k9tjTcN5zXuakLNR7eUM3VJVs/6com+K0ff/9yRf/96NkDG8WQUaHhPuhkPMMvsxz71/u8LPmssqNitgZbpED71dmORVeWgj+Wvx+JQuIHVGsDohHHRyf8VdPTDVzt3yazuoqbsPsCWCWv/q/y2583OecGS74dll12752UqWoq4cio1+yke1+yKmAA/hXd3ljYi2OWP72L8/2T9bYZ/kzuHV3A5xy1Ji1akNqco2WwPNF24c5HBpvly3ihQ6l13vbK1L29Rn/F9xvzNBsJFfJPVGjQ2HFjuAclWTvQ3VpSnX5iOHQI9aDzV9+rNJ6Qcj3LxovZsnvCEKaCKM8QfiRD4iElP9mHOaq5mSh47qUQp3g/TgUFQRtPBiLCFX71Ksi6TiwAiLHt3QSmYylyGX9oBV5dhE6QPJXICuFHf8JPFo+T0M8b2jzQvyJ/QPKeK82xbPGWzGf13TUyEU8Src+RQYqXSJFVWMItSFp1sGTNhHV5YjJ3aQ+WyY3HqTSLAc1l3c4EJSgiqZqRAmJTCHfAT3z8qyW198pZ0z+wN77gBAKzyVVB3wT4GqFCAOpkLAucCqYRfQye0mrNThNF6e+BasG7H0To/5VOZODQLM2JcV36rzTB7NvPUOS8UTXiDp1gVyYHXIEqmlICR9nZiy/CDwG5870nSGAzzXpvvjg33odjvy+0+kfR+U5N62TO4PX1RaxhYRbOutdciVlSRG7WQIfBNuIVt/DMoSJi9ooQCyACU7ofIT4ZEv7bo55ma9D86k5vJjdf/as4gtkE5XJCHm4WFscOI0xfuelJrJzG5xCrExKK8RPMJhuq5JKQFenDrHQ0jjXvhpM60tEIaVf+ctiz4lZmL+VKx2fblb9+Z/fpkGjuIi1kAHRXYMjcAZ1T91T3zHqXk3mwrZ3SFRtX694vi0rdThjKayVHWQtXUi5Uvdvt2dwfjTuXoeFg8sAoRx9xtQM70pYqzeNYWdV0pEHpsjReBQWxWSbuCbiEaEcz9n+z/5Mx936qI0XcloFdVhY6hXtLhBYNoKE5xP4BLTjAYgpt3sdvOqRngqav3eRF3JZIopc33Y58BKorqJMhz3wh9WhIXOoo/wcScOU4MtjXMVTXEXaHQd1HBTaH7mhcgIlaYVU2shwvNwZqDKA+j6BAeih7EQCTavMyek88Qq+TZNSEKmTcXFJxWn7Q8JG14gEjbgJSBgso2xsWKMbwhk47FzyEzOHgQBogHIg2Wht+gcMwCuVramg3eLAnEv6aBhoT3mkSutcFVWg/vkuku/kDnycZUq9T7utr8XWBLAYgSzRI2hBdlML6b9j9GiT/0f5AV1xILdmzPoWPl709ruAmv/WuDRTofBBIXxq2xljyIXy6+yKRkfqsvqJyBvth47xF8OGMZgDkkywAwVKhSzWq3MiL3QkoFgG+JNc5oBqjIH5aAb1Y7bkkdr3M1u03Vd/K08iJQ0tc5x59E6cy9MOHGMz1031Bchi7Q/naqBad9Ws7khc9TPLfdgwRl1qf5mwT7CpXVupseYa3T5FQCGPqAU9nkEqPYWoGuqtY9dHLSQxQaiIyTLJeP6zNImHyQrzj20G6g56On+xIpnZxoQZLMbr19UQw+sKqyWin+qq7yxETN4xW5hWftR9GmLMXJ4Ie+088a1f+bHVp/Wsk50VrTJdmxIAkQ6og5xgFWGlZtq+RnwHHC8FaNARq6JhNdLvbaxU3O351WgYxFjQ14N5gxROFhYAWznq3u9PbcO/UXdPD+aUXCkcfu+INkUhJaau4Ke7KVrbageen6UP2PGAP2xoVBR4HZ98rW1pb4Kd7Y+WuLJcsgvMp9w4i2Onna2TztFMM++sM7LGLf8ZAZZydQN5rppSdOHcyv83L9qzrNvXmTpSXVBLlGzp8hosc+518o0taQziTemgXZ+4ZgXiesBHbDTVYBEHYvjcLQPKeg8rwrWaT5jhwAG4wIxskwvvSzCEHKw17jZU+sVl/9ZC8UEto4zqPMjx++MXPwSm+cVBbshO1of/Gou6iYn9oz9ATqCyRns9pxjaVLcfq43i+Pye/Y6f34hiXCAmdoOvKtUaoZSTP0TZoLEXziNWOE6oCvUxuGTSIhhp18aeBMU5muz+aQVrn26Ed6q8XA4/HuEmC0A28ON8zIFB1ibocvoZSrnD0oppqPa6UtflGCBY7h1c8SmOKOkEbE+jsW71jBYHaTMx4npjiFlLtnUZ8qN9C+UxSaYDqZVtDNawUlzLrlskEsPTjHDWQ09HsydMCh/5uJN4ToryB5CIu6+6PtJaEKyJTlNGRfFmlbfxHZN1H9e5E+1gsLSuplfHRmz1J24232U8d2zcWLP8LjNbxMGcNJjfRr9O+rAqowKSAk9UVEND+BiETYyVx3KYPiCX77nghynQbZHOgEwc7VGeWLWHDxhi6G+9eeJz4uieK1wJAP75v+HPXSvMeNmGuiQavAs8P8sKPMAj8jtJIhVR1EVhHAHwpW/URAz/C5ZdP4Z1MARMJWilHxwWPTzama8YmG67gTc/XpI2lKtc2N5u7j43Dtz9zPqYGK0mftoVGnzvf0MuKiZnaAAcR4DTCg6WQXs9bWsboRdTon+KwPixiZUOIUyOF2VZpbNbekDpGxBLB/IR37uGhYWESExqEXKDLptpsR1WGsPn58AVMKdcb3MyMGF2VhKYmIODPlMOS7c52NqhQ9/dGd712cW4V8dIesvb88NgXo+N3N57V72h6nQsQ3vfxA91jdEJYpfLaOO8Do/IlJQXM7+QCNWKmqaItm436vhWR5xzdyoqOkR1NZqTRJk0fA60e4GIGGD1x18b9osT07gNCPFPBlQmFf9r3uqgtgVgOoSu4nOUcfWgkKkMqEKo8xtLXJi05zKzhjwqJoKs6kJ3qWyGnA5toilT9B0vGPNbod1pJLySsCj+FqHr8ZDLOTx/KrcSDHgja3uxc1lSlS/cdgGpJ9ed4ZnRP6odjsV/PqUkOvhtN6fdJggvEHJZXGaUcBPkgEFyImbMvslwoMGnw2sQ6oSkCIWEbG9cYgwib/cHSXAvnhL04KgvoQ5reJ/z1oQzSr9L04N9Gh0V+vVI6piyiaNSU7Ar9cxiBggbaTj/bjzyc2r1vngpeSPnwy4yUgU1Q2QeTWeAUUnDZ0EE0xe7ihLxAEu/NxQjIveZSkOWqG9tyHcXUMN8e4yZgwvno12ZeBMhE6IeG6r4VjehIllRztg+pvumvyZcxYHZxFdZTLJacgx3KC6wuUPgGrKeJ/IKFv+F+MZVvX1NL1Mi5uL0TI+Aezm2DiaFkwR26cwzEgj9Prnv84tZSzmtgxsXB28P4J7zVz8ImFdEv63JWchvoI/FzDWb9PZ3HuIzd9s9pjm9sr64mt/myVLFgpvGt4ubJkLvUkxZPqi1XXJvhD63FqE3xXHfQ6M5NiWhF+9X1hFrYkSl65jzYwtCJ/MW7WvoJmmLXQUNuhnZlH1NUhIhD9LYuGAAFh0TJ1pw7OL5o/rJ51LwU6CSGmmNlW7Tzig/75j9v7QbR0blkhde9M7r8M/OAgyfhsLduD9EdlfKIgfRQ+VMl1UzxDgV8CDFU/qNXpMwEl/XddJifUI4U8hdShB/T6mGrwIjJfNVhgdzqz1OssbydyUkQkWEVBQDD1Fh5tn7PWGGKAA1de6hQ+SNs4CDA2Vau5sWN4rCIneni7dfJVXrOh3xCR+FcDT/OOmGkWBc0xsejn38ca0fAU5/T4vSbIxl9Kij1qTZ0S2q4SRbKI2zhuI+lUBXzNvi3UaHrhkx/Jw3UCyxCuhMtaBPxay8bR7X1xN3/wxSdu/OKFvns+acM7PnjIlXPFv+snnRkx3WzfPruypZH3a3juuPp8SexsPUAsPQzAA9EKs2MJRKkERwoXWwhc7JUsGy8sxUzNT6bXCc6LotJ9uIZoGqlT2Lzskw6XuJPYKn2LscFmMoVrpp/vllNR4glHXoFXlNir0vLxAT+L6a0i7UJnIu/wIoPiN0xprRNPHbPTr3TkaZToROyrM0iuAOKukhTu61q5eQ6InordfE4TuvrFRw9e5k9YafcATTMVG25eD5W4vvI5js/1ZWerh1QdGWBKvNmO/iPk7lMmoYFeViF3IdJMLHcDO36qLX1IUFj4XLAbB5GsgigWq6AjmGLl8pC/WxrPwmo/gI+Z3uEGlq6p/QSK5fMqBv8EDZYWAu5XBGMqk7GHNP+nIomQ9r6LayqgRwmHr6Lv0wACzqGAlBmsUvEPlyshplqhCa51A7jVfL8UglP8v4HJLplAfiOcbLSRQW3aHq9/okAGh8OGC34j6M3GM1i7PN0hUsWvjaqtXjL1Fy8WRMRqTal/Ss0gOO39sWnNlZuxnUACg/2nSb3H2DZ+h5KkDea43WL1vfflmWqZJHJpstpaHtO9DuLW9aTdf2VlDFfBMHMf57X971GTvIscBhsV6ovyDsNIc1p0AABmSsIp1fBvN0OK0mxCyKetnzknqC7unGoTyUQ+WfIAAKzAFLQiqt1Y/1nlP2pi3DFAAA=",
-    "UklGRtQHAABXRUJQVlA4WAoAAAAQAAAAXQEAXQEAQUxQSJUAAAABgFvbtqqq/xIIKYBOGGQeurvDESw+HOT/FRET4Pgf/+N//I//8f+PYksrsYeMPh/Vc1pXW/WctvlKPKlPe/GsPquntTkUtqhNZotziJ6ahqlaLhRLld7JI64SFp0NW51+u73f7cyjf5FAqDx7PS0I+B//43/8j//xP/7H//gf/+N//I//8T/+x//4H//jf/yP///dDwBWUDggGAcAAHBXAJ0BKl4BXgE/EYS3Vqw3paOns3lK8CIJZ278TojAT7bfo5R39XEbvK6DbzI+e/kAHRZ5M66brW6fOAv/7v/3UGV/q/613//+xz++ETRaRIv2n2oIpCA4lkH0G1tI0AcxZmQCumwfQbW0iVQvbKk7ipvNNX7T7UEiqBwPmPDbwfNg7dp9qCRoGBZjzLJqu4KMDribfuv95EhH8Jl/6aHkxG2DmPPvgzWmxdrzjsSjjXP3N2TDFjrAeFHf+7hRfTCbRzggHEgWfKVboDzUUKAv3IP/UiyeeF4LGtpFXDgtxMDXVYsa1EQvZ9+ic/kwrVA+L8homdtyUtNvGw8X7T33dHUKdOj5iV+tFpUusBou+zsNSAyD6Da2kP1z0xQUcKRtIzoUHQyAcQinrEJVakFIv2n2oJFKhPtOm9uHZgcDk4P3QFTbQc0x93/76H6fJIA6beVNv/DdFpEjAW68nFekIEXDjYAtz++VIFkUOLUt10sloh70BvRSdSWtNix4SowTT3jmp9IBupYBXNody2VmVhq5v6ncffN2Ur0uVhk+I4Rz6pA5Y6kxpyWn1AJElD+AUnOUyS0E7Kpj7oN2aRzqboEH88ctvi023NPVRQ2vdc4aBoB9WQZfIggXUmqI3IMFom0EjQVo2OT/aR01ZhqVluYQqdfFELf+mH2dTbPUD7B+2MVHEdTIACsGRZ06sDSG+U0koLUEVPGV76mdfVkMOjvxJ0kG1NZN84xWqhoix32d+sLGGM4JUFLlKbF0cKF+p/h444Y1tIfrS348ZFpDvDnGoZVBouxrwV1Qi/afagMX2EG04mUKFkvtWhqUW0F9xWoJDlliwTzIkX7T3j+Eiv4DThgXn5wBOR2sahkXTjFubNmov2n2mc++hXU2+wGvAyEUrTAv2xVjmIlcjs2SJZB8//1NeTHJqH59qCcMLDbGAAD+1kH/c1pRAM/8NuD+TE8ZuCk+inuRWF+U4W4AKnuRVGbRDtPMy7kYL3trfCc9rkfD8bnmSPmBHbV6isytiiHzgpsT//4NexkZgsDH8qzgmP+eKuGGkLUaYzVCj1BF2YwAAVG1F6rbWTyBHDC9F9CmnHf1oYlwK+GIah36re7jvj3TOuU3gn4yJiXswuPcnlPcACzqieHf4ATRkInHEK2tizvSXoq3SkhkIXcSEFOFBINl1zFzxIZLq/VdAU877eFU/piEvO4F2W6NEs6k+x+/apEFAtslMaJLpWIlkiTmS3+EsneV0p85rSRjyv1RW0nj4CPIeMeQHBYk3QmN3i48GUdJ+S/cxZ3ZZQBUti09Zvf9mz/ta52fNon3KSYk/05e5mE9MWSRGzJKZXiMFxErLOdQgncJjSkUqzBRIjQCVLIJZMz5OAVVXTl/snUE3CpMas8AL8t0zxMgO/dOj1xYIq/YICIJsZNJWwIEZy2RUbOs6R81/1hR0WWWLcuotvWNaARtV3uM7tkAjSk8hWktcXFVwRn0QDsngab+NMtTLUmNZqmsOM03aUNSuJsbSC/ml5VXNqF8gtN/kF1XxI1PmmKzqIiXQylxaI/OZoAAY5VDlf/TtxVuLql02f4ytEhlgRrmHWm+CYhS0BlqBbmBfcaxtVz8YfWKwJWuaSfxMBpdxPkWiOjZ7LwnJsA9EqcX5Y4k6fT5pagBPZVSDeOdohHHfY1FIfcJxnqzkPn9GY3L14LLcEIRtihhb6yUKS1lSs31L9OHgKPMmdyuq1FLcBXM6BOnJNcdW9xFX1W88gA2MWURB3OkEWvtTUEMzYIVfyPuzt34M1JocExCWRB+1RyIYoVSHwAYUQ5iXBl1QBMOIgyqXcckcUDM+o88DlStUy56k3wqO99TydN40HWpvIanQv/riO2/q9aJUslOxvlN9a2n5sEkxQ71113X9fcizVwGG6lLgkDRtZAz0S+au+Qc9OTIxCfHk3ax3V9OLGfypzbzC4F/mGD+KxomJsCILSHP+tyhuWOhWgfaHURksjLBwp8ypzo4mMzuGlWFbBg7lLVl6yYMC1uBUB/qKVmElusTKs6pPzRYptTIkleker1HLMCgbfIyXSzmtFfo807VS1vRUgCjnEJvrnvZAC3epHjJwOIRFF+vPBS66ADo0fQoOEzj7TlGMTj09ZJU3Fh6x8NXCdOGDvy8rSL+f8XoYfgtXQw68THKJmmgNzaJFCneQGstw2xBxwEQpgTJSjKXdtLW5tSgf1YGUOK+tcT/5v8pbIuETSbNfzqiER0754ce6LxoLN9VikqvWGQlqYYeRPFfwhwa3KDfoOiLUFrGEAf4OA9oxRBFCpcDposUu4Q2LwCdwVegDQFglf/Pqug78xAXLvmBn+vCfQpghE/h+FJjVJfUpSXA5cONCcgOjTCAj9wrfdVYtPQUZR4/IhbVUS9LkJ8neJfJYF2yka5sAAA=",
-    "UklGRtIGAABXRUJQVlA4WAoAAAAQAAAAXQEAXQEAQUxQSO0CAAABN6AgbQPGv+p2R0REJA/akWSLbps9iODMgQPg2csEeC+UgPAYgcEMnH8SD8LTF75c9SL6PwE6/X/6//T/6f//Mw5J8U5lSIq3J15QSoj3hpCEQlIKkJKQhCTeEkmRkpKQEiQEUkgKKd4NhRKBJIgkEgkgJELo7eyKRMlRJRAZej9DXdBAv4QQ4lm+E120C0PzF0PsFG+E90VXGz+rFwgpSUl5JCTFsZAUx1JSHktJ+RdCUhwLSXFgelUPVwMNBcbQPFcbBQcyJMWhDElxSEjKQ0JSHsuQFIcyJMULw/tpe9kGiucFDRTQgSQIhaSQpJQQu3hBKSF2+YpCGRmS8hWFMjJ28YJSQuziBaWE+JPX8LK9htcyZRe2oZ/ZlVRbcikDXSSlACkJQgiVpBQgJZEIZfQuEkKCRBkZHyEpEkICERkiL5JSgJQEIYRKUgqQkpCEZK/l6TW81nV6jTnKFGbS/jMMjFSJEkJKkEpGuqQUyUWZkGp1SSgUoqSAiI/4qNCHQilaEoTykg5lpFLZISVIJSNdUorkIiVIqEsK2XOt4bme3sccY3YNr7KX7WlP255XirRMSBBJJAqgSzgERBPZSoCK9EUkZCEumYAju5RAgCoE0KitDIgkEgXQJRwCIolEYq/P+xxz3dcf79vwvM3Na63ptbyfy2MubLfp4mlBdWG6DV3si3Zfabpc0cW+YTLoAhcYoAu4NhTVJg3QQFeDoQ1dvK6kHrfPdb+t279rre/dbbuN9fhe69daY9jDc66xXH0fLnv4Cs0XLtNlUzlr7qqXq8tuM8Oe/wDNsrvaBldXrx1fhW2o6MtH9RNmwQQKKo13TdMNNIkeP7/Wf9t92/1st/vj+/HYtp+f+/ZYa1tjrPU5t7HWmNvmuexhe3Lt6cL9+zb826PaNs0Y5us6t+E5ZrV7VuMxaXu72WO5XJgxJ9XD9ly23QWUu699bT8vDJji60kPdPr/9P/p/9P/p/9P/5/+P/1/+v+ELwBWUDggvgMAAHA3AJ0BKl4BXgE/EYi6V7C/piOiU6qb8CIJZ27hbiNQmLZj//j/7jvgBnS6P5a3X3JL1b+NTgL3iBYSuwFS+V+AEZPICpfKsCa/sBr/tkSS8O6LqERgW5VORmZZGD3VYdJLUQs1kynjn5LpkuoRGBblDUS8bH10KpyM+uhU82goi0svwxobn5nxnq8+M9SXxnq8+M9WtK8engCUsgkItPmDbqq3YIn6rUJcfL1yPQ9iXrkeh7EvXI9D2JeuR6HsS9cj0PYdU/Ro7vAw9o0d3gYe0aO7vfonoDKJ92RJLw7ouoRGBblU5GfXQqfdXiqD71lCIwLcqnIz66FU5GfXQqkWDQW7i9ypB96yhEYFuVTkZ9dCqchAPRXSoIi6Axi9GgFpIWAAYxejQC0o6IKABCNZO/RoPsxyjdC4KOnIiSXb3JnoO/ICj0XK26quOpjrssRGTyAlJg26quOpht1VcdTDbqq46mG3VVx1MNuqrjxSKARengVc6I4DGhQv9EM3pckqkrAMzkgXHJAuOSBcckC45DWQSEWnzj0wbdVXHVCONx2UfnI6XJKpOSVMcdKj+uBeWOXMPQNDuyneqrjqYbdVXHQgAP7uSTmfyOppaBW/6FeI3fTUvsyqpDbtXbqxJf9XR6KHsgqdkQq3/1ETcGG44CLrozJsy4tBRCXEQ3CP0L8Fh15QoJmwVNKqeYqQFEXP3wzrLa3UO+iTf5CC3M6FrtSu3Zo7CouDVfARKJJqs/+aXPsxq4oOjY7UF1smULcAIfW1O+/AnfPIDruGka5Id9YhMC2nJo+jwYLQfBb4bPy9IT358JgfHyBp7NCAlLClYQTSdZIFbDsQDVKCz9TiK/HBVJ4BV7BnZcTV3XNS80e6vaEHsRX1Q215ddHJ4AAAAAHLQpFfVCwD7j10Hl/wuAMtRZI1wNQBVfs8GZzAd6e8aGy5aBARQ3OIXAltHAB2Zj7c4l3dkKkA+9hHsVJgb94H0BEyBZmMM/Buiua4iAaEoBfZzixEVeNvrkOWBubgmwHWy8vGFbXJaEcpd+8P7uE+kWp9CEu70o6i04KZ7pdAAB3sEbdlfZJMzu4RGE2joq8wkQK0Qp51wGippsW+Cr+EMlL+bl21nODiuJ7sAFMAABGgKSK2hytVHNMukKIRhLEWuXUX4KM0xvhICX5j1qW+jr2HX/Rm0asgM4OvoAdNNdjreeI11cZ6gHSeSIjIOM9nmZ7KTMsqyqgrn6pO9Ds+A2ZZJK0O3+kltKc0HiJSQaqJDEAAAAAAAAA=",
-    "UklGRvoDAABXRUJQVlA4IO4DAAAwQACdASpeAV4BPxGIvVcsOLSjpJUZExAiCWlu4WZbwJbI3ovSpd3n1H7Pu//9oC/rwwIobUELCOoweiIycEt5yVZ82aPssjLY47+c5pk5Z6HBZFgdarTQsGp2zhaUClHwbbc2A61scdE1VwB5dkajUj/IzjLv+mcdJW+nPX2+6vqL/x6j+QYDgI/CIphp6/hi3UlLTMqzq/ANvLpRp7gsyja3BhYKRkYxCKmeb//uXMvhq8SNglbT+Qy/tHhQHDAyDWAXwPe+8f0rnadiehAIfULkrp6Dv7HxaY+/n5JKJJz+kWYAAVnsyl9UlCawohmsUrAw0ihRdPn49aSVh5//7dJJ/bjLMSiX/0vUbKio83ebUVVkZgJI9FA+1bPCjjNwg2tG91oJpkrBO0+sIhhMd5IU69vcw3xfu2d+VvZ2vzudpjK3LcX2iEjcIlsPd2S+qR5ULWFTL8G+EKqyiie8GY0NTNtMfpWjGtNkNxSRs4CT+1ddkZQPmb+gx33oqH4UTg9Az1YiscAqGxuUm2MUP4VRGKA+E+AeH5rONjbORPV2XGpOCpwuJP8/97alQRPCB3w6ZOpNlBUTAqNXccGerV2Rlsyjy+OjPVqLskb9ERlsciMtjkRlsciMtjkRlsciMtjkRlsciMtjkRlsciMtjkRlsciMtjkRlsciMtjkRlsciMtjkRlsKAAA/vakCG7W4CS3xzZM4Tdh31R/XKy1d0vsqJ2oOpsfZCMiBH65IjlB4n0EwjD7oEtg8iigeJ+lKyjfJAdbr4HLCiyGy/0NSS1cl9w2lpZSS/5MTUzhuvt3NtF2eyMiVZTFtsC1/2GPhaNNyNKuJyI1fkJ0/4UMziDvOARMNs/oyBxl8AdYSGp1H7Qq53FXtkREYRONF2fdI7zIw3tzrQhmcrLLls2QPVjB9NFmdq/QN4IF3bw9RHZcW7ZF2ZulzTbUEja9kFedxbU1Jui0BXbPBwQ+XbdvekRx8h7tj6cbwK+jTydj2MlVKPG+rQBulgGH073bATJkdWBevoDqRmLp21x7SHtF26Pyv8mFvBfNCZqVtD7PN12xVrVA9qXB09iABF4YZfr9bD+ZGYAVDL1aq1Fa+EV6EflcaGN7R5UArgutzcDslKqR9UcWRucF0OtAChv7lqb+3NMLYYQNQe1te2AMlDNNTFDxcs/kzASQmEvlkr30955f15A7I++kCf7iI3SFmdxyteCGboNFSAz963Dd3ckdly01nGA/UR2J8YFFeQdwZR/9p5oaNRpx8HGrzPSkIQm4GmiAACECmXBCotRv+naCNdiLKCseDBfU3RkAABYBwAAAAAAAAAAAAAAAAAAA",
-    "UklGRuQGAABXRUJQVlA4INgGAACQSACdASpeAV4BPxGIwFgsKacjovLpAYAiCWdu/GfEpGJWs9k///9djN//j/7gX//MbIf4169NnPaP9u/8JxvEOH3Xhz/fa/Ke1DyEYn+gp7ZgDucdzjucdzjucdzjucdzjuOel9EfYMw47nHc47nHc46wo/KTWZQenG+jS76X5f391Uh2A/A7nHc2+R/kI5z9okm5DCFTjHlgaeAmLi/ye45Js225YRSNkLNPcepidWoureK4ie3QtmAO5x3HON5wm+8pDTgmFfHSy2oMR87p4JWwJcUYHZfioA3uHVQ3iLSf4oTx3K1n803FpJJmAU3ApmPrxettJu6VZHg5NiiUMeqMsE4aXIUwaw4fYcEswbDeWqucnxs3lPtTcmE4kw54toCCAgumPzHc4wxeFh0+7kX3lDMXYUPs0rSrNiqqwzUmAelQCH1ztptRvwayipl/Z4dwDAmXQ6QrAeV/+Inu2YVE0JXCTTkCFHJg0+3d4lkuBNHC7ZJgPLORJ2XpqCsDm3pdgm8Z8mLvyZjHCo2lLvvy7OJ+gp7Uo8fFNNfQ7wBaTohIurTdyq+/nCt1YsZL1iFaIQTnWHW1zFojsbzYiTDoN+Xc1qfV9b6/0zHOvVPGw+fRBTa7s+JZmjl/3110hSwxKxX8GfQPblwBJJLrknJkrTdxzCaPwQTmBtS8kX8RR6HOd/yteqOu5FlNQy+U1lZjXqUhX0FN7CtMB4gBes7WGKaz7jBI8QGGqpo7wNemfI+LykkjuwiFmMqRP9AObYAi6kwst5EFSn0AAP7uvodo15agAADiXLH5KWQLOk47+E1zI38Hn/SOBa+rHsRGu1OWMfQBX1Fn4Wxazfq4I3qeI7d30jweCjpiCNnEwhUrznFhJ1RWthHwFvK0RxC/KUu8uGl3d4gUUR2Ug5XqKFd1JG/owzCx9m1g1DYxqxiFZUCSDJvJv9jdfsH03+4GwNQSC3XbgkDxBxyVRPHdm7R03J2E2+7S7EgTU2sgh5/QKwc6gZxKsJWoXDb0CXkENNmUMjLunlPuVh65i3NiBkxa1lP5GnH4yUFa0kV3ILOFefkT89UrQbbL2/0V68z8nrpxSkRR0mRxCk2fG2LahIauhMkBHBvp56WF5Z8AzqequZ+lCumRSh/l5xHBbCqdiflTEvL9zZJ8FD7gsgZ/H9/KcoF56uQyNrl/7PxfEWIAz6BkfN1X+l2JzFTvHj07LW7S61l0qWYRstBuC8QetkOR25DncHnMtpNj4mxLCSjIe/6cuJgNOW+8csV2uUX+jDM0BW7/Sf2dNAZegRfRrxTFheSG+4qY5ELEx2vZX1QDG+ruiDzGgAurgH7PeZAHhdsXdE7yL9tRmA1QxgweC5tBwesb3KNebvGW0LcTQ1wdw5gKLi8+KzBU1CD5phB9gU0DTesee7UAMjx9XrnRhvUpcRRvVDDC7HsGz0Vjd1XNTUV4NqRQVsKGpTDNx0DdBmQaot1iNqfnDNODYxA1WBf9SLkgly4XwRzo0ft8zjo6YNWMTDGHs0S/w/9SJ24WZCkEB0quKwsPE3IkohKgbW8Xa6i3r8+FCz26MKz3LikevakrHf9j+iFFqJ+ywB5mUheoF8K5MIGeMoC7/bp/lW6PYC3xbrNH7oiJtaWxa52MugmsCZh6AHpEfvrOZWDbZlqGALx2A2BAIIUtMwCRhDL/1aKfwgVDW9ohr9WBk9RQ52jUyKkFrV75dwti3Ra5CtKB1YJH5M1Wc7yA8DImlKK0cpgyszaIDVL8S32sE6uBJIy4TneWU4J1J9hREvoMn9okbKNXCuiydY67DkBGZehor70CMMCyRiJFZP4YX0H/SXubEY+PpSlDRp3CtOORVK3WdXyBkCGQFD/lvsoY4yyQSe1mGVW/osn7Grn0jts9BXUL0jFeMiQD8CetSd4lD45QqbyoYzovstwUmAe/srMiIWvj/4dQ64Nv5TtTK2YiTg8zPeUSlOAaNHkYCGV16sfILti5/2j6mAp2jy4R1cuEhFRKzyML/FaJJ9DatUt9+hKmJza0QJ2jXqpBZruIpknFkbkTCgUf2Vac7qQ9VIH8tH5+NQ7IjEMY1WCkVP+ykuuiDUtot7AQ0cgvG9Ff2e/N2S5tgx7nYGro5d+xGzWjqj/mUT54D3YyFe7ITTo3m7yY+WXV5SU/OwmY2afqZBMHkOLLVL2zqdSgW8pgyw8wg593Qs9bTr3KFXiy6JwM5xFU66ILZ1w/ZSoNQnK2ZTJsKl+3C5tT81eJoHq2HxAaA2pzcRBXeGIIhm5AZNX1b8tofUT2pxC/EADGkRxPPbMR4ADeoAAx+APMAA+hzSViGAIAAAA=",
-    "UklGRuYDAABXRUJQVlA4INoDAADQNACdASpeAV4BPxGIwVcsKqgjpVnoQYAiCWdu4EOZ7kD/u4f5+gW8KmEQf69pfLXqRosDQUFoMbv/nWolnfn2uXf/OtRLO/Ptcu/+daiWd+fa5d/860SZ+Ytg4s78+1y7/51Vtfp075gmiHnWjlsmihIK9rQEN8rwNflDBonVK8xNHi+BI6K59od+K/DammAbapA1se7WGib2nl/Sx/b4YkIARCps94IkhhfnjFb8O49grWPug0P6aiKLjyMccIDyRJWpHGgPewFIZ/EAhgq5SN+oJD2dC8X9no7lRIJsNn8oalkBJubJ3G9nkcSoQAWJXS88NmO4wFqiRV0kE1ueqRpYujGKptoHNBPJ72ak3zDslKFfVnZQLgLKPTGVL8cJA3thMKWIVZeypSlK6ZjAMiiGnR7YdmJ8oy99atlMPLzpi6KdoqQL2uXf/OSLN8Wd+fa5d/86tMuAspofa5d/861EfFKUvzrUSzvz7XLZ+Asv+daiWd+fa44AHuf/OtRLO/PtcvLKxXgXtcu/+daiWd+fa5d/861Es78+1y7/xGX59rl3/zrUSzvz7XLtwAD+6yaYASy/GIZ1gADSojQEJEiROy9ixXsRQHti94QsQLBMTz7nXvq38zClK3LQtiNxpzX3bPGnIUzmeHaxEhjFlDOu+p2gc2NavvICKsVs2qUFcJsRzpEtnKWWbM2GQ8jr0zsYKBJI1DmKHMIcSiOQfjB6Ydweo4IuNqNU1xGLqB3R4ip0Y3EpOLleHWl2Pa+0ax8s7Y7CymfGxDlvMT3wa1H1EuoLvJKsjpi/22cLkrrzIVQzdKLBgQ5lVZcEO8h3W0UP526Uvdpe1Xzr7/SnDPjpUOC/Xb20UmkbiR//r46uDqCmMOBEAgUmmVKlh4tDMNcjjbc1aJUKGOIjzJ2mtYTB8p6N4sEfUEHubhwN4jOOZXkxVwJnlY2a33r3/ODUBAC/T1cFKBehJ8ER82e46eqHTDIHi89KQJoHqC/GXby5Gm8ubCktDcKy9goWeJp0k7gmEbcI8vZlb3r31qD5cH+mErM6zCb1dyefeDh8DLqDbGLt4ZeOmJ/mKz5fm4fvTz48Ufe3qQkmu/rhgBjr1UHOj20mnmTAyQIzUeeqkoLHl/DSxlz57j263y5kQJZjTQuqu+QBvvdiFG/AoRliFGBpfPP/nZt0a2y4xQPvN5PLpVsAP3CNCNmRKwX8FSjtq7U7TwyKglhPv8jt7WO5kCxXKbgOem+M80RG8D5Z33eWZpy8vLlyrsWXl5au4Oq5gNDX01iVVVVDAaaOyjyBoFZaOn7vLw8AAA==",
-    "UklGRooCAABXRUJQVlA4IH4CAABQJQCdASosASwBPxGIwlgsKagjpBIoIYAiCWdu4XKJ1t9VTd56rTSaeLdhThQeYfgKqA/tWBa/IBGTaoRfRSGiw6ZLF/OeWNeqoD4VUB+gqSkQ96E4vCK9p0L96iggWp8x+2ZgF+lsEASJSFl2ZBq5bZx07wiMuaqdSZUGywWh/3WrVswsFqCrK8ObpIjCGKgP7VfT2P2xxLhGBhVQH9qvp7jVcYVUB/asCynTYf4P7VgWvyAUFywZYsL1r8gKnrzuwetfkBVQH9a3I33rCAVUB/aqa0Fg1LsyD1gWvm8VYMsWF61+QFT153YPWvyAqoD+tbkb71hAKqA/tVF2IyIoVhtMhj0fICqgP0sInYp3/Cq549mQesC1+QChIcrVsyD1gWvlBq9zpkS0WF61+QFVAjiAAP79d6hfM5lkMzv95j50zLKD3+5+5gbrioj5ggQjnc7ExTWA88njCNmM+3KRkleSez/4djhatahbVL+TV4OZB5EuHyXaVPxfF27ONduZMmjxjP03uDeLNfJNHjq3oUlKhvXbVfrC84/uDysEKAnEZth939jTWF184A2AMKb/V2UriKUCe9VSLihIA5ZZ7nAgqKQqlLXpCilDi3DiDtnCK4m2in+Am+FSak4SDMF8n6BWnTgKHqFVHizN7z1h+mlz7QpbPrIijfJWR+UC7JcxPuZeH9xa7s8DhP0h/tXq/UPuowBiYZUszYzc/0ECOwu2OgJukOKS9Tb8i5h2PdR998zVvjVLOAAAAAAAAAAAB9VoTmjm8Yp2NFdhDYF2uG5HB+7YSFmMfVcclRcLW/+CHAUyyzBo7F3OKd+x4On4GtjlO+tou2sGdAAAAA==",
-    // "UklGRqIEAABXRUJQVlA4IJYEAACwOwCdASpeAV4BPxGIwVisKiejoxfYOYAiCWdu4En/AZwDcr0f2r+RDG0vL/MxpuLtelnpJf/f+ijXKgd65A0cVA71yBo4qB3rkDRxT+QWOXYL0oC2Fjl2GxAWwscuwXpQFsLHbVPihZ3rkCNEYcatNVPihZ3rkDRu25f/hUBlNxWak4yX1mLZ4UFjl2C9KAthY5dhsQFsLHLsF6UBbCxyLE0zs12M/uFOlAWwscuwXpQF0eHdGvvPqePp3tJxBSr/rZorjl2C9KAthY7egAJ5BvMVon8vmEsI1HbYv4aDb8mwXpQFhXRk28TY6ayIyrl3qTLZ+mz+fQUyX33/j2fF88kjhH8Ab6kiABa/H6az1qOUZNTneUvP8Ub1fNajj//j8V6qOWGCrUsjsttJpYLoTF4EXf9MshDDODVVgOHl+sGpkkKlPOw4BQRdpR+x+nvc2EI9HKgx4U8Q5sAa/oTD6zPpNeAPjOdo+aJSTgN55VS93oLiT+t5EZMxvw/w6lMlxbtC+5dDYvFDgX+FrADjmhtm+cGRpc1L/HNTOiXsooCWSyKaXqpc6lyVS1hK4FpnUtGuVA71ilzUdobH9HLyws6SSg3BlYGX7AXpgKnsn0DzIVsUwe2HGiojDYh7TVZjfa78xoAA/uN7/wWbbovuFXFgcwABtwEACDSUCTmgVwAXrv3cdaZCiVZf7Nr88gGwAAAm/Ltl4lR6QhgW+m1oY/9YP/tYpJ9V8/0wZUTNi39Fz/AKfqHADzc7qD6+y3CvjkJu6G17rIfSXDdmN82SltEyBEZ0PKqAdBfDmxKKSQ/qkoUyfbrMVvjTNGr1DgOlDSlOu3y2ep9WaDe/4ojUsZBmri3GOYpaSX9KBA7TsQPeEI/5mX7HSzwu8SmtzmSi02YWHYzHJCQyXXEG/zArarh6U0y8UsPiPPgVE5SqLVr8Zb46wPNVDWGb7r3V7x4IOaWI7UMKQNoEjSuDgwkeCdSs1NB+fbYefTa0Mx11ZqlKU3dW5jNN0oJVvcXoU/4SS/Dh3ytz+bu6DkxPpQzI/AebxQ9TCG4F/Bvf3GlPFj7yH5cs+6JRHPQ2+vLHB+PFT9jj8tMjfKb9wZCYkn851EUzhnd/GYI29lVfxURRgE+vCipNPiXKJ3dV+i8f8eTBoEZ77hsw8wqCoD3sRE2dUbDeRU3fD6RcFLztkv0rL3dX+czWn0td2XbUDZqYS/KHgchPA5X/fHS0Vot1LFZkq6OuUpcrQJnYrisVoAbQ7maCcFVUOBpRGtHfLA5yO6RrUQobeI65JLiP2Ph/XF8t0gME8yAO2CdCbzCYC3OgYH4B7n7rcBLf9tYRX25V6hRkbpS7yM2KI4HSpEEnbCXVDRny7F/nUBeAeMGQ52KxmXFj2ngWcCiqY2X9IO3m/zAJdEASnsbIVLqzaBICDvHzFLXhJzi6HelaJ6RPdPVKlebL30O0eSyYK0EQwI9fRjp6cz2J7k1sPU++XCRLKBnb1LHJwAUoJyCxycdSdQ2H5Yits8LwJQpI+DOJDZSNbQXvlFnG0E41vXH9FAmUKDMEkZqR4AAA",
-    "UklGRrYXAABXRUJQVlA4IKoXAAAwrACdASpfAV8BPxF+tVWsJ7Y2pVENMtAiCWVuxuTvPosmlpwQd/3mHOdS273mA81V9C/bjvd6wNgX+U3N3914F8BF5vaNYR+G39bsB55/tcf/UaIJnDbB7iXbZ2PjeFp+ipniEyefpGz1OEpV01iS05rS7kusVHuKZDbNdgUTui6sOyr3TrRvLd8QNyjBx1Gpljsk0YF7WR/s+kS8eJvEIQNxGk0ZtStR8wKr9FJGX175WEiWQQfi/t8krpL/23WzfDvJKm0FDGLzWYYFr/P43me9L4/tlOoeWFCllh634PlqrzY8RzdUU1s332DYKJOb88p+9p0LKDzc28iX4GK2RgTSHC5JNHKKb5pOfKYUYRjp5gf///7DvleGSIjKFblrjNwP/bjY2Jn8UZjKq0GJx5Lp5hPsjZh5f4VsieRH8HmF5REpCfcNLAYdz47K7YiGv+U8s0B7EV8gCdbVm3tEWg7HHCUJ2Hw+ozYq8la2ZNEnuaECwxE2HZzXxM+mzV9ud3txUpDt4oco3ST+8r1b+RAvKvWzSeImyPxypLEzDahOo5qRp2n3zKA7G0kvU4FYWVPRf5LSqvg1QCYeB6m/5nDfI6ZkQn4IrTO/Imgq88vf3wNaqxk2AunD3ARjdr97qCxc6LCiC5UVlO43cGmW//A6fepdDOsbTkhZ0c1E1WeTCi415Ge6YTpfq1gklX2PTKLkSsM8PKmOERO2zFaa7nkO4/R/72p5Q+QJ0wmBzLogMgGqreK7EiLhwPa5NLjYcx7SG44YsDCyXn+PF3neAIxJ2YjUuBEbdbmsFzieRb1/YGibltSasNaClUv65VFYYNaQL2Lju+C6039vvwnDG7YaEH+/6TYHhmvg1hVvxLHFwBSlBkPj2EJ0tMPmjFi1D8x8zDiH2TU9J2F8snihRxpao4eSUhLo3KPevLTHgcuM1XT2dPU+f9UmjJrIOfFC1iyHgxGapAfdBEQjhsySP/8dE/9EW8WpkP+k22uxVmkgZWihBf9HMNqYjYfL3RnlDcVA0dDBm4ucAPpSVZb6fjp+0/8DkpWaNFxW2GIej2jVmXa/Owg2l4/XlLbKZI2yrsvz/dldo0imTwRPe6S2iLEJoWN7HUTewlkCPHqIUlv2178H/bjsKxRZNgxegxT5muLhzPA3ZXf3EBsqlB2BTgZTW6siaGMMDjQWtPzMbQn2xLRS9D4EQe/Ov9/RLPuK/LQ2z/Y1trYpAowsYOa98RjJdpA1nGAT62BjqaXnzOKMrZBJMmh346ju8ukxIMOHDZAmQvJUhQz+/zvPrVRsc7GmpPGjmYGxegEC/9nf8ggC1LixoM2D2FU49rq5j+/p5SgyfrwaOWVsPNPmTZ7m7H8EoUgQaD85xmV70S0HVj2qSY1cG3ZRQ4A7SQYq/bJ1HkmQLvqoJIDA4R2RFFfxxDfo8KfHkh0paVBVBs0/IDsIOVWlbOgwvfb/yA8YYtKOeEj7ov6JfqrcLetS2k9C+m88AGyVs2tOfEPlCgHaR2o+aWDuvAwlyu39GvTs9gA5sHOjwfmK0HwBK/btcTd+6Yw/UxGm7MY23JdUoLLBUVdSe1QmE+Odu/cnhgv9JI7lc1VITEY+zHZtwi7HEBVoLLfdIJpHeOLFQ80qJ6JCQH7xx5Nzixb2ZKI3bZwkrwrkp38m74xd0eizhQ7Gl3CYjYb3YbEidJxQiwqP0V5QFIA3ZjZpRYDnNT3kOJhwPUOrnDVMc7jYUVnbVlBkeUoeikjjoVg5fzZc4jJFD00eaj30t1Yu0jq0Hi/FQe1WPhmIu7ZksBDzYUZhRjpqIo7Ui87JpTE+2hzBpPP6dzk0Mj3s9ZWsKeaAwkAA/qAkvPv++s7D6QGzv8W5VXcXbid0rV5IBx13eyCoK7TclkQQ4LXg7YPeQhR0cp3urkdnuZq9yVC+AyPZjMFJRQxU+/UIv1XMyel9kuD12qDvcqGYSliGMn0GNF/UwTQIAx5Qp3KLbvuf1xAjQWIuvBy9PA6eyGZIbzpML3uvxCWvoNMPUnP78qJZkpGSjlGC6NbtDEx7U0TQDfa9wwXz0duJpmqR6BtZt69RqrffkuZyyACjpLmOsGurl/uqawtXiV14beSaaCGU01DZHFYNxq1QeRAsybX0H+rFBr+wfoG7cks0eYrHf62szcMtaOj1rAq+xOe6oQVWxIqkIlwJCHM52aY1X7iQ0gLVwC5ihszXF05sApORszljo+7cfx8TTLIhbal5jjS0wWPM5YPVj7U9laDR9LilG5e6FWEad8xmpaUFkEgqMiwxbWy21kZrjjcuII3tpieulzQk9ZRxXSjMTcP2Ug15Z70XUGTPNZX15ngO4YECmfJDJ5BgLt7f1Qt94Na72jysWE2RfzIx0Kyek3aEhSYEZ+Bm/s6AJELWKOANOxg80NMB4UNv0A1kEQCJbneyZojDbgQDf9iKhppQSNLZ7JRXmZlibGjMRUkNOULylkXijiaUSPc8QLQvyEFK00w7U3QoRLhWIohZC7PsYR1GtgQ6jrx0yhRHsaJyJOZLFsP5tqheStlb7n7PioPCJzGu5X8Vw95g9WLpzi8y/sqUukWkzZ1vgnvr2MdA+15/58HqsC6JkpQlnhFZPFp2u2ofjI0YuTtZqm56ievuvIF/iv03Qd6oTTVru1qHHhguKJX3Ftm46kfL4HrEdZI7KzTj/JDbszhNClVnEdWXeS/GHoRMcikU9f5+ZJDEdjkocAwtm3Y4cYCt8sDtUIPZg48N1m9JdLKH0Kb2ivcdmP7RHounSB4uSzKwjl9IwuXDWz0PsduNaYLzeRZrDPQl6XarIvIsyIeFfgwPyGq7XBakYFNE+49uIGfPBrUVfhYTHlU88lsarIES1Yo04ldtrQlQRECRNf3CQ5t1vyBSsw1R4J/p4NGn+OqaV4KpPsR5NX5xUI6H/ExFxmtRsAKKrenhBIjrS1sKOZHXo/IjooHsskzyqwBQWhjg+l65cRUJ+++hfxxkWCD3OYgHe5IeScvO4RcFJW2VJoAGZ2tqga5kspTANCokNaplYV7oHFW7pDQmdKwubtWYXxtPiXeZQYFmbPKkR/5Z/D8mLWgRqB3XUFpIsqNcdwOCGcZFB4SyTIVm9Gm5jiHplYaOxQahw65qGEKCEEDeti+OTLwfW26tU+ujGnaYSU4SyYmxOKal6nxeUl6gYKS1dEY/QlqlQ9xTnlfAROf8kUp+tUUlHIUSayvgoHc4IGqfTqApTWs9qEyawUfHF6C/X9MwYtKrvLSo+N9ESKSTV8F2VA49FYTKQBWlQu8P6/RdZMK8PbP+39raOmaJ2snRa0eWJJSnAOjE3w1SecuFz45YXyR8kg8VYPwCoghXGW6LttyrgpvcYk3/sl3KZmoYSJFxA29gxjOo7FKHdcSbejtVC81jD0D8uRtJlqsxHP2l8jSDzbPGvL/npzi2HhbNogPVmYabUz20G43D3YWGKuL+vfNz1lN9EJXReGVkDeMQH03S4nPSsfMtwHewnCwTARZHXkmwP0usVwIH6hNt3UXIlRY5SqJGHua2jYZs+1fh82s5bzpB8AhWEFNsBjBHlaJJ1RZEliFkm2pUC6uY2xnaT1gv71WoHionJvSSeTOQlh6IzieeJyUzFfAWA6uA85nxXOaeI07ypbFS9/hruyPLqB0saa1gqYWA74D1HBqyMbovopLx6SvGuw12TWZFKJhnNJ52kym+yEjO5MHrkf8j/k38G5O2sELK0BOxTf1SgVQMBcB+P03F9E1I9RWVzJ1qtQxN0rhMsz4ajw4Un2jPqhxZrCMZZZFQKz+xy+ZlOeyfP48WmOyy9sJTMDVMVQorS5NJXUDejwO64qjNCA+Ver9TRGZdplBLbaXDYLpXql4N2/18WKJ11ohmAi4EzoE3mynJOAmNDa1YH1OfY4Aeyd/6EW/ReAeaLhYaNqUVUZGPLo49X7eF2ZFDvbiRYYQy2QVuHMeVErBj5IBkF4szsUWgIAOIqjU3sIwRCr9ZQs6xKaxsxsJESKhFeQvEQD14x89CCsit1dSG9hwJWb4+QE0NF33KtTF1O5ZOQvV785M9MRoC4hyWjweF39sCDZtJL1nxC8/P6tsm7ULFY999vuBObGnBpdXOZ+LLhkTO63nuxwn5BNwl1/DNHIvBg8FdXUg6L555lLYqaFy97xl0eIs9wbcrWLeWLzk9avU0RCQMHjZQMuzkiXiUC0DMlVwqAx9goJYiNA9KFlblJrmI/6TFfZcsHn2K6QYp0f51/+NQVIHBDV27XtBJonBRH5uRjTLqj87wYkx7GQMbVBu7OwUMuBzbWJQTMsLrm9tKHl3jVsCwaW95F7enq7naIFJmcsf+93muCVzJQb2R0VXMKghLDUqneXq1i21B6ouD6Mtlh2SncBa7NCS7g56fTTdA7q49kaS71uGyCgXyf3IlYj4Cne/V0134cygLZaaA4J+b9UqGWBWYVPrGoCgC0FkGni0HjhhEGPjQVECjbaUuO11a8Id78CwX55EcWl+Gk2RwBSi5ClwRxSNxSMwDRim/CYDQZfDFYMbqrLRPSHLH04Oh4gWx+BN4DpBr94Muk5XXeN/hvW/EgmBukbhb5JrScH8yNfuOT/B2gx1zAMPgKJFYgBQm4LI3N5lUTPqMQFffQMuBGDUKE2cGAW+HX8+xAuTePUfQJ2RzdeP5nlLXWsNBUZF8FculU2YZNjbMtLjvvRyVGQUFWkihHf3WevThFRlCJAbt42AeT6ThxTseyb9hOKNcmlCzTYtCR6CHFOLAFPV/inpM870F5c3D+9VEnHy5r2wuhPZWBSHjn+j2/XN1OLZFX/w8yoSB55GCPxbnRnQE018+cKbhzDebEHwjpi1PxBePYmMVTYDSeYoPyTNcc7uph5/+vsr2iF1xirggG00vfyVig/RN+BHozZ6YS8nAABlLkOQa9RgauiCQrUzGfHrC8csvsa2lV1svI9NoRqSH46EyE3xHpN62iV9cqfHmUzIJGtWKqzklFxUhOak/9f6dg0+fZgNbaJSrsaIerIBVzXtkH77Uyh3RmTDqEkcCtF373oLm9FoL4T0+N3HNjNMPx3y0BJvOClNwOd5fbSDOTBdAlGxCKFpvYaKXc/ZJ2EXnurvq7VnSddsOiKHBq+kd5L9AgMAbvuevNnVtc4sFqNYkkcXQ+R3TL4ObV7d8O9oOeTGvgZ8LQYJ6LGFo77jnH38oRnyCS6POT3pY3ixwHm8MRqzURz4RTF9I+3y+pgxql0Xaaq4WB8dX+hkyZUfp/GuhKPPpQolTaVaoQAH2Q6PUc9Eu6AnqOh2ho7ASMr+Zfi8AKaEWOvCEkmCpGAJdDbcHVHStqlJZN/XyaDugYn8Oq2EP13kXd1tMzP6fSsWv9dElZJluUYb0Q6TicgwWHWsNjPMvFV1BZBlVznZaK7M+5bq3nq/K8cHWlf1m0AdCB8ywZV8aL1nPYSivGN65KiSFkf7wwyb/o8DcW5D5iFchJ3eBU8WRlztiV0O9gnvnBFvPx5JwVpMk8zZJea3q7skChBQWYrxQxEl9DJCK1FTyM4YlFgYA5lpSACEnYvS0Y7CGXGNNfkIUwlIRb3tqHiGinRZtAN/1wusj3tYDPNQWTBizY8iSRuXzD+cAx326hTKKpPZbOSS7uAgABr6u4+ibMy54kHQ6fOcL1D+XrCIxLEqxFxIwv48gYL5o7/TJQPQ6AMA34xFHeOcLgW22SBYnBnO+mUveqXs4XgELI2qLEa9x719EaYzNOt5+dKrUVAUba6FgkjWxiFJUXnPrDZuWrouESWQk+hj9aaIdhYRH78d5k8vcSt78FxGx/CMmPTfCzemZZ6zCREedVmONu/rRYLle2EY0ZTDBx1C+KxXmrbiQe5OFIQ5Ev7UTK3dmwlUVpj63gvklqzhXOcZA9/JZ2r/1G4TDSoIqAakq5egMivSHKzf5f4Hdmf4xCGvfB/G7dxKXkoR5x9DZqe98O0eEauN640AYz0gpbyqKcWuYeFCcVlSfkCkVZN2dFEj4JQ91VFtQ9yYfgXo0FWwn1fnANbDan+JR9lCmjhvWw/gI3JhW0I39QOdeyjUrb80qu6IA3jmpPj5ygF5h4CJa1SobdsSkFtZi3oZuMqGEXztIoSST7kGz+8602Chlj+yYsrfFE1qTf/bxsqvbr4cTUGyUNX8INdarUSbL8udIPM1+PfdUDi4L7/iXJCrzE2RAcoAqt0Sed7Ns/bIisvrmP9pX97EFAvWb9RTahwPI2laaSUgxKaPHyhvh55A8oibhFBtRU7lr3PdYge0rDWC/px/aKW3nj4ZodEUyZRxbGcQ3Jwihqqtb63FVlLYIA6vSqvz3qBloXNNj6lG5uIRCdKJLcn2wmpbDGEGfesUoz0yBjAZsHqioojjbraCr2GwgrSHsoRz3izbTKzsTg0eO/3QPn8ltmD7mICLapi5L+BA4udncPZabAo6chgYRlFYXYz3Gl4fYKsomu0V2HZd1lB3aj9xobxkWk9vFAPtkzveNjnyUpCRgbfMwLYQkGW9J1W1u/WplYFKCNNR7ouhDkmZaKVWxxm9aUOC5v3BF9WSb1F+zZ/a57BSn3jCrpaAO7u2zbdvU0sxndVqvoHjLU5/IHE3RH6LlzYBCboAg5o/PPOh1ZNUxtWQjp2NApPgrCvCdZYZqiK25l9cBxBAC+TChY2Z7yyYWaRY+p21Uh+D0YFnfvfKq2yHpSk3+EHmP2bNUTEV4NoJMwoKQYQ7uH38uC4uErF+M6RNfRRteX17aukpdBmU+xv7BcyUDx05qAN38guxn14KGxR0gZDdqrla1ImpKsf1IgwGclodpivb0RQjzmrpG5u3A0yMEiEpC5rU2zZ9YogEjJcqNhcgFEP/dHN436O2YCu3AY6kJup9Usqr8IVWQipRtB4+DSNlQlAv7s2irAfPWTdxcpr1utb8Wx/6amlYUOc0HgIUxPTL9HvTB67FNuIZNOHshXLJtZ4ZUCHHeS9QtULkS7EY3e0yvilqSzB7zC3kUqA5b4khktKfJkdb+J0eNMZzcd2kStHA8l//qoex5xOGJLuEMgyyV5zZBFFBwLFKsp55fqXPVCXMydzyZ4koAsHH5qv8OpNnAyfx/CVDh63h58ySHAd/2ZuYXVw+1S6uchiU11NWH477OdQY2eRnDioTrlcQ/rfNWCKn8WYzIec4/0EqU4Nhovakbv0EuHtAB5jNmplSTHccnHeA9FrBBG1S4rH2KLwSzgFaCJAJDsaI/jthzd9335Np6AjZKN94m5iuoAsSREp/Qk6zqFY8p7biES/df4oaBE1bH0A5DbaTtNSzTfJS/zOg90rphmeJmUyzOIj9+QicA2h22qE82EFxkL/IuykWS7aM5+RlGRycgP4YOqkx9u+NK6RrxC+fkOHp2KND6l6s6V3gTL1m7cJv9KUuo0VH7ddTyiKRoguMiFWe786O9+79Plg6gbk+wp7EJyp0vu44U55cBALtmB4gfqhsXkdoV5FhHeT5M3tgTo4wvGrHy4PTaK4LjHrp5obhw1A+3l7Yx0Z1EEodx5hBB3A61D+PgPd7HRIZcnnmtj+QxFoejT32lWiCthKMcM0WEuW+9V4NttfRzrP24FyKyduD9HMEXZPgx6K4qQWE8NK8Nk5M4yM2TMuTUTjvC6gXfarhqLopLE2TbMRZDKOfrykykvGoOTHoReRXHI25fpM23c71g/1X6njgfBAj74uhD4v37oDRN+bSznssG7SuhdnK/2xG7X4MPXhqr60I97JYxWxJjjgkpatWdi+BMGwROKjdKXd7HIic2+6WFpE2yRtlWvfLp4rQPQD0UK4+qwsJmDKFLuPyrjqrdOdEdPTVtva21SWLcA9SLFUrxMahGV+zbenKePvhi220S06y+L6UzQ6dLrCAsHN1nyY8vS7bGR0vHPBOhNGyfaWdkNz5p7NDFLokZHalUak43WBpr+kY8jTo0tktOAQApNSFZlo+nhga5oh6QqFYrXsPARzVH0a0mKIzqX4obXOtUKn4CxLdSwsF18ky35PE9RXBLzkVBryqdEYY/PMQffMJ8SPSCDzgo4YgW8qXGeQAA",
-    "UklGRoQOAABXRUJQVlA4IHgOAABQdwCdASpeAV4BPxGEulasKKYuI3J60cAiCWNu8p/R/8/4ECX/mh/sgG4WGn16sL6xBKfc/Up/nN5dzo+7C719fCaGfuv/f+NLiQd/qqj/8GDLDj6M0PQvihIG8+MlHsBCNVftJZ2LWSSplOSaOEOIQTiJj1T3nkZR/50vpWyWV1Wt4OuqoqukkmyrIjFNUn18NE7Dw1jgRhq1IM8iijUKmiMD8oN3go1t+DVDocp+0lf2HcdPnnqGD7ojcAH7tCtHw0SUpW5RFUSaQcz/wDKlPneA31wyFAByBoflCidZUUSyXGEJdbZqDFyJJsPN3yOAxBb5KS32pQj6ASJjqN0mWUAw5YfPir0oJHrTZyYPrRc5aSGa6ifJueLjt2QhCrJHnKt0bN572RZKvvU1CYM4os+yxtIxgRZxMNd2A3Od77E5QxGMLphMkB7lf8aXnFLKm+HZo8n5RIAmFf+M9fTYgewrgeQtUpLR5cOtMNP45W3socUf2oPLRdFdheyhf95zUcMCZgyUhqaI0ycSY81SbWmh8MtPyi5bnOS92BZA3bu1uUJEdyeKgodiPcnZjsIYoiWrXnLLop6yH3ws25gZn0WvQASCHuGGL8O8y8RcIFxOfrnzn372s37L/uz5gmarkBXedYDxLPPC5H8p93teoAtzhMQrBfYEnvurYWDfn3DBavELW2w04e/wjZb56AyPjBbYZZn2o5Hy4W9qH078qeJagi/DJR/+YZSfjC5NrVjNv5wlgQpJnJiwGV47sog8NojvJsyBZsn/0q6EQ4Hz4PfU6uX24iJXgRHHZpZnm9QXujJrFZYXt7TsikTI8ek20wzZSOhIlXiaCJv/1YdoJ62vmil64NkRC4EXTGs8Pjk+yFd57I4BH9RGN8UcQ2+cfUE3plvdyZweN3q3QrMMWyHJmTLK5/BHTZvJuXBzV4YnsnPbRKdN5udR3PlNgBKKBAR26fXGvzV8ZRDZvHGcrB7iRu9CZUz9nxj4u4t3G+JsR43r2igr/zK584GkFTYBMGSgGfhMnPsH7MjUwMuA/UcVch/R+RK/7YPaMl+glecwJYUKU76Wmol1ZAfIxGPRy8U6OGXFfuWKtCKVftfDEV23E3dPqzVoyzzpMR83Xpz5cV+4M/5EEGVU58uK/csVZzaU2PBdMVaERQSd2JR8Qy4EAMt3qYq0JcV+5X/pdh21rxJWsTHWzrHReBT5kjDKUr8kLiQB0XgU9mzeL4I6STgwWK5J97NdF4FPZf73+SsiI+CQB0XgU9mzZjNtoVeyFAAA/o7t827U4vaP/JUCWfYWmpZrL+uKbpth4RcGTXVEnkOVb5Aww41g6akaP9CG35sDcm3QMpmyPThFmkADs7qd+aghnhow5Oy7gY1eIIEnpiZ7fVTqQU1Qa6tI2iPnIplzV072ygRf+AHtucQ/8ZFDydVlA87jorUTP3OQkAqz+JSG3u/yGIZH8vtHtuEdriDO7TxIQmuYSj3j7EizwRrNCxA2dyYNiGYjOurpARQ4hTMzfMNXppxm3aTEsfiB2+pLuWpnc1hv6pbqDRoKDOKHa7PGcUT1+NmLYFyuqNFvTY5ltPPFENr9dqJuZeKDlUZpAo8nePrNyQ71xET4MLS4wOg3EWDdUomyyqQ5OmAMhTTpmbpcZRW4avC9tkcmj4NQmuBczS5iFRgcnit0i3Y13tiial5ddTEL9SdYzCpwMbYvY3P5vgS6VKo2krJpVUVzVpVMC9yVb2rImW9f+6YCXFmtv8HJYNfuPNCrtH/GWIGS1u8/zCw2uyEbxUJeF3BGgppl9mA2ZaUK2Vy4bNCiMIMhQekIViyC+bqF6f8dN4iY3IZj1UJG/VLPzaNToVVpo/FM08W4U+WsWVO6PqhwmffEAKr0FLywEI+kjNh51RDyuxw5fjjJ9Fjf1Jyy7HfQONDOyJdBWw61Y81pri5DyYz8bbeC0wwW/MHtgMmg/trgUX8w8f3erxvaT1tAZ0IvkTsyumcJj0FL6HDkn6WWoF2hAKT3mnnIOS7tJ9oua7isVsMbM3AReU0NxMcCFk9U/cMl8tRwgVt8WoL+Vz9jKcdPeHNvqME0GUS12vvoIzVSPtDLMeuEvlA31HxWZznI2g1qP+kpVDQID/s86htyJ2+/n7XMmpBkJ2bIPRAKWAI7UURW+EETHuBvP3sxhvm+r167Nylouw3R5llTcXQAv7uEjOBGQHSQUHjkM0zqvi7WbjtlK14h9Xtj7fONpvgC047adKYY0iXSNJKcHIZxm1nftGbSmgLomz2+j1kCMhAgTESUKXxxiljkdtGFMgbsBb0VSlmLYjl6p0igAiw89HPIUJoytlz8XVaEesINXJyGclp4qmXWs1x/fJurgAOlRL+6fukMjy+yVvzyRDrdJTzMbjiigZl7BLky4ZZFkpxHcFqVMoOm3SDFEMggrwgONH+EVs/fPelcuw+FNKlBQcaOYDjejx+5/C3ylHRuOe8+0szvvkBG6NTjRZNVAj6uaHVxUhyxGl7dtLV00Q0ndcHb5wvEwI+qQH6OlI8YqJcb6T8qp4gY/936IeA0TklZwCewY+a53NTlKIUYXeHDxhopZUQY8tXh7weJIyDRBn9iYY+11c57vR9MnmIOljnlC6nhGXslM9wKEvqZOYQLntSHzpvI5VjNVIO01nG6jvMh3R94YW/NT8b1QAllD4DllxMaK8irLTRI2jPTEKl6a/PXNdPBndo8H1mbVFAxgDxg3XPnf7hf2eRCLJFj27vAjmVcnxi1S6yJZDCKR+yYPDem7KAwimm/C5IjlYu/DUm/G8QVp4dYuRNPhllOQCG51W1HS1+geEi2uwCcEVOYqQMSvpFLXcnXVOp4r5pE1ddOmfL0R7Hh+7pNfL9Cxlx5QTHYAcXjWN+GjwVDm3BlG6RFVYbmOz+6SQZpAW/Y7NOxwqgsJPi7DuOHGohhRSZ26Q1hV8cGa6cJyCRLM3+6qASsMqzrWXDATxG0PyUlAORKBQHbCxA0G8rmgzM8Wn0QgqhLUedVBfWW1bzuT6BM7aPYzGOPANcJqDfhI4l/D3KyY6K4eFGgO49gnOrqc/xE/ZKbJZ5Me1Yi5mNOb/fswbUAfBdm7pKcQwZyPqYnegrdHUyoYTNDYrTK53Eu8O+sopRxVDIqjVOaDJbT1BDluVMgZpp0D0dYgGB8SUyOvczYex95pDz2fl1jwVUGrRD6MAS1wvhDO3HbOcUkN8TzXd3pX7LnHhOZOSvqw9i3DKsgSYbQVG7JHkri5s8tHqzw1momvCKKnZs0a9jzQEeKeTxUqKTfJ9cAqLq+/c4K2khuPBB9DydSyIM8X9SHdRhOTxaRu/9sbmBT2c7dvVfKBBxW7z1+7wZoGmmqN3CWfYIE7unJeK7EICS2JnZBU/4K+GI2wqJo/3yYAIPKL37LB6P2saXpKnbh2BVTQ0KxvBfHf9s19lewhfmJgqn2KqD6z0Zk3nsdu77i18rKvzp5cfLkoDwRIu1GGKPApkLm+2SaGZ4oela4t3qxPcJnwMx/IytW8RT8kqzh58vBhRhIv8kC2aU08gvJKFwW7j5w3W4lbmZoZfrv4ThZDSU6sGwtExXn9TuXwLZV8uW1Wv1A2NQpztPjJs7asKXkYcCGbrkIRGSj7iJ1Jw5uaX5JQ65I3L4qJN47Bzulah0djBf1hL219kikNB92lGScxPlqh7ZSqhMSpDp+WnETLJwZLGuTI/Md9s5tcLwallqFgTIOMK/WwT3fvw1VoDuktnbnHk0B5gRumt41URsRZnLVVgTj0+uiMIpuHpnpTkpIR46y+DVfHWFezleJ+rz4ydPKrQ+M2Q5IY6pxy8XYrnSV/+pqPNT46v5MSiGdt+UYoptWqv12Sq/fF950NqeXpHZnYoK7cckwKANfLdYJWNmHvR1UTDrSGkc6BE7qz+9qCzwsgr8DXPycyO0kAxxZ+JwDIIFJ4pDHU+ba2BeM9qSYzlnbgXr68hxOinsBQjlU8IMZHKPjtfCDW+lgNaw0xYha0qapaQpsCyKVEZgYl9V5jLdEsQ/7jZ3/rjGyg8eG28kCDWlHx/I3k+8/Hz+0uBCsAbM2zLzyfaFLmvPvviQ56H8JzI2qvvUbWqcKqDnfxjy3B7aKQv8NcOTl4cMIO0zjxLznIcU89tE9OvlGDVmJ/R60rKGFqh64ZiB8t4DM/PpVuRhlJE+ZBfkAGGDFF3mNGM+18ikhD6WcTvt4RyLvDsdlAcMTcBvr9Xq7GzD3evFghqyorAvo9vSX3o1xc9CcN9SnmUPYslpijS0MUWH1bH6Y8g1NtNkZEY+8EZOM22npCI0+NkhH/EzgO929P8tKig2J6acIEVRHAk0IyeJItpjktQCbHl0sMf1y/AVZ4pP9BoIbdPlqKOur3UutzrcpgaQUqjpDi28MVqy9z7CwHBb3aY4OO3GcupvGk+jJKn7jsC0QvdggSO/IZ+1tjA794LSk0PGb5eo/hwAGb7YbC4Bgll+GX4S0bfXkvR2P+apN605SrFA7vfs3hGDqCyyHiLsx3Wf7ZDlgewsbpn2CZTBDuAC9rCm4zj6+yWI0qL56V6y2dQYN7A5W94AI2ZY/mkNNiREhsvLnTWL94Sxjx7hHShl4EZAblSoqdpq/JSAjBIHI7/hahEl9hKOgZbkEwAFNQAw+G7pgNOJJ5Pwcxo6o3B5Mii4NPdAAAAfIjXLuGpURFlmkU9Zr7Dhyt23CQDIAAA1Y9lP1OgEkU+Ow/pNwW+yA0NHRGAAAJ4xmScAAM3x7f7OzelZ9cbspgGrwdtMBg5e67Axl4jTZwIPP3PBNs5vlWfq1QMUQnMAIbz3gpLmLu9d89mEZlk0wt6cWGuSQy0/vNMoHtnMd9gwowAAOf3QqfjZNfA6rXqxId2hYZDiDJiYZ0AAAAsYFnO9CISFR8MFqyJFjErw02/ynEpL7+AAAAA==",
-    "UklGRnAEAABXRUJQVlA4IGQEAADQNwCdASpeAV4BPxGIvlmsKLUjoRIoiqAiCWdu4Xa4n9PcNSa/7nEP/JlJV9bsq45I7e7+CzJbgItJh6Kcq0/JQWchlpLpUSS70UzHcNnk6sC/q4CLSYeinKtPx5OAVXABea9Yg0Yb0IGX1d4VnCcSkxFyyUV3opyrT8k/fkVjAaPN5v4jpGi5W1UuitIasAPf9ShiwxBYvHJgJDOHuqpjtFOVafkoLOQoSohM7TdnPnamsX+XgYhehA+SDimtyF9P3TSc1ArkDyKw5wxMhH8/Zwf49ytsRmiW/8CGaS3/laiyyl+KJpE0q6SPSVJOIOr2hMNmPImi4GywbpOk6TpOk6TpOk6TyWXfGgomolcDlWn5KCzkKH/wS32rS/yUV3opyrT8lBYGNIK2ivfedH0b3nRyBeLZ7JW2s0fbWaFNYvBXU1FuoE4gDooLq+M7qx5hI5BNSWsZ3VkBYzavZwf8+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkgAAD+/pcN0d/6kYAux///Ys62Aany0BviFpNsEDZUr+QwP1BZEG8qXrM5qpo3YJwqK4LJEjAOagIWWglTo4T3unZTlX5+YxSJ4+sfOUE9Cb+nhMdj+ou49WOnNpZTaCOYiQAEUJW9lUXGUkOMs673tHasQxZhgP335J+qnnzI24+QdAGQCqmS2yT0XJ3Y6VOVQmtmXGKf53ylHZwHQKSthMsEmxRRG+EPtCESiceKOymkTLQiFDPPHgkoDV02Blhj2R63uqxsHz4/eO/cm2usAxwsRSgVVH5W4fH/hvboI4Sj8MQbS+Neu2C0SrI2jc4IKAEd4d8KqjtorI5yn5rD3T7M8buUcliWvvn9nJpGSCoAVEw9hZAJqinS9R2gP8fmYoSnYTpSntTZanru/ePd4++UlzbQaB8Uj3nEzVH+eZ1vEbvqW3eZD1G8d4udiKj+/jq0qI9qjyKEgso/Pq9VEIkaDwBL1n8RULO/1UL/DMgozFk5m3cQtmSKa7iZDTVBpgRHQS2VV/8bQuYSQKAeDPUcPvK9eHEjyXFKZcj+/sGZnMCNVudaDl5EbHbOpLcIf3oPARIr+NuazclBUldwlVY0g9ZJKH14Vtv5r1nrqqzCONrty5EGjdfhG/NP0WMZSaRj/9SgUa+5GmdWVXU6n6QkcJ2pVLdA0lOPRvu8kFJD4WsMXiu0lpiCaT9+Acnrfe7FLHYse57sPS2lppLwSMdKL2LV4FowxcjnJR9Y7oaLJR5CBpE9VsDJtsJSi+xuDp2WKSF2bT0XA26EAbaEG0BoVVNmVDc80UkAAzIYuKjc0VcF8CFEfXF15ZccAt3igUtZ8x+wuj6In5ttttttttttttufYzn8VgAAAAAABcJ1HwAAAAAAAAAAAAAAAA==",
-    "UklGRhIPAABXRUJQVlA4IAYPAACwjgCdASpfAV8BPxGAtlOsKLCoKJM8ChAiCWVuz0T5GbL/7P5e5Z9/g//W6LHbO0vXfBM8rz7t11otfK/X7gVOFVT+TbTtEKGkL48F68E2GbpkGQANlOYKr8TOWzPq7LMZiT64X7sDjaR4BU4b3dpbLjhlZ6hb4KguykM83PpSJOejGHkGeOywLDo/FCzYqxIjZ+C3AxUMU94MBsutsfYhrqx/agucxPvZvHalbXMCJH0jCHzAdQwuLOTIZd24m49EM4Wmi/F4K9K51eWon3JyMhcb+/uPdcXidc21Cgijt3JVecx1RBoUXCRPvssDaN9Sv52aElG8Ve/EEhfhPtsz6ZWC9cNpolzHUaOhgiwEQLmRYdxYyYwFvhrA6AibCcxdSrsE4k1a3LLe4gTKOaueTkr2fcbdv9T7SzjEkPZkSlcJYSIe8r5AcnTWKnaf2b1xJWKcmauPxUWUoKBMUHkERTzXRwNsq+1UoOj5dvCeOjIpZf6b4Hy2C5AzHHuLnaSVyZHr61f8XnOn387jPGWMHvrOE7qlj5UgClVrZOMB3E/Piynmcp4mIlWecPWN6WFPQEl6coISbvpXbF0UMPTK6J5J4bmJZvk4JBYsHC3Dtkp0CPSviiXVhwgbYaFaliRcZAWTs/l379ud/mQtzTKYWZXDE9m9XqvqnYWYyhk1fdqhB5zig6qfyEGSRwX0+kVlK7Yk/WZI/mH2pCVGO3brtKh2dJaMK8J67Mw/ksIemqkMXm6FzO6j15vjE/cGFpEalrVWYjeMK8KDMFsMsVI1t8+xGiknwXNFM8rZHayJh05wcitHnToP0NfrGtEJcM/3jIFEs8T85h6LlCLW4t2AVikth1UUCS1lQRPoeTsNFW4YE5BOjJCYso0jLBVHh5l9XwEuBsSgqaBPWjzLQfaeSg+/ElZD324992TYAc5O/Di8fKAOD84mQU4Zvqxmrpg7pQ2NOJSbMler1kQo9rR1wyWiI9MA4fH89SawyISC8gUUob0VJjgkcW79QRQpxHSvyXOBnWYWPbH0yUOSGtpCW5OgdK3nOMLKQQgRmS/8ohEsP6m48ILaPU6fA2doq4er8ySO5OmobxC+r6i3bi5fyOUGTRsQKaWD/ukWG2S021Q6jJ90tqMWvxbOHnDpsRlxCKUOnDeNFYK+DegKm7p/Ezb50Rful9lGzlNl7k5BPMshFxnePZRJNI+ImgPO8S7fuir9PmtnNCKCMy4MMc4TyMacnzvrI198MF5FyZPg2Ac79EioMCmNfJAj8FIrKBdCluYUiQo8UBPiIvl1PGcVGSuXCh0Se0Ilk2Khbf5Fh2DECTJROQg6mCL38HBKExSwrCtRNpm9ojM7r+KzCLiy9aHaJv2I8NrxuZkgevG0PiGP8vSUXI9csz8XTgq6ajilELsyx5010kC6PdT1YjKrZKYUQKmA5ztmeVL4Wu0r5YXspmlWMpDVVLhhW1SMqYDnO2Z5UwHOc6q29K2jgwJXuuy7eIse64kkgMkBkgMkBkgMkBkVMfm1eptbmWKAAP6a28GEhMjydEkm+YWToz4ZLQAQFHaUMsNp1/cuQZEwHr6dx7cKpifTXoGGO7kpeSycBaBmUO1B6FmovyZ1EysyjN8CjQES8Sp+BcfTGoY4bSnu87vynq0JLRQbnQE4F8LeBb1fH8X3Yc81CGd7iVaFTO7A5w8icVE8FulRvWlP7VlytjVMnpliTTJTIJKID/Pb3fWn6z9UCV5MaSPoqYiRRZBMfbTUv3gYoV/98kr7nJueU8ArFYJEXIBdgf/xmdyuQXFLCGofzWcnS6Q7SkQyvHtKaqudByXJOiMmNzrG34OAk65t7AfRqnXhGQ0OZAeBuCfihyIzH9vlo11wdIwVG5Gyr+f/TwMHKCkDU3V2mWbwytWWiHJLJxu2BWp+dylgKGhwDf665rdI+dTmYdxyuiAK0EuAOAvIIQidSxZtaSBM552LlOIA+Hpf0HgkjxJF5q8iVbfAUB6+L2QUU+L7lM5cKUXZy3hkAAXfXpnI8UK5OApJke05CNwMhgycO3cShBg9FxJW+uPdFvRmUkfWY5VkrQH3hxrIu47OclOKE8/YDb0eNvSj1QDZso+pOAVULZYeCPx617skXH1ukIDhRKcqKg9Re/gBOgCTDtrYZd0vg5EemgM+AAhI/E0tjzgx/EIlvTQ5RhyBGWwEu7ZhWMzHys8rm9gFxlZdnYbetzDFce1Ey+uWDmK+vJGSrNmGjAxH/7s6OTPUdjd3YvCTmUya1Ef0bzIzAZx0NrTsiCUoIiPbKv3ulxmplTza1WCJUNg5flDlLoB2R+Z2TXze82V0sQYhYcMMQVa0/qGhcc9jb58KITgjwYCvWAwBV2apuakY2/iO4l7+794mvUqjVD1kuef2GT2KGblxE2UswVeY+dVTRmLPbu82Y+Lgna0MP9QLAI74Nz1SKgfeFLRRWUXJq7E26oFukAEJcQ1hWhVaGmSGAbFiGayiQutZLyAAKQkRFadvBQDQwmPwIUsZxBqvpXAMZvU9HmWb8v3jdDiYNcUfR6ZOYzwwkHt2tiJkh1cICFatPNrZVFGVV7vDaZZLDDpfhbj46J58KY4JjvloOHp1eAOv8SjkbsZEcOLIc7rQgCi8pTVEMbDuQkMnVtgjSJzZxxPelm6epSem+lSGQMCXfkSxzMNYpXdxv3og7FZSGBfmkj75WMcO3HCAftK57fl4wAk690c0HV9h9htsd6N7q6JZdyEPMvOo+hdO6CkOLXhDx4tW3mNc71lQqzTTmjij1FqbllMTNEFzxWND7auLU16539YibSm6YJbgBWp1bN6IKviQibvMxxKRGow+Wf8Px04p6JsTahj4vrxJ/3jwUNKnlaxYvVm9fe0R0qTJ4LVLi/ecmUjGSpOFF8+IptPo2wjqrn+O69Na9tacVklDml+BlvBMW0T9DmldGnenASG5kLKtQwZ3C0kFGpSIhB8lZw6XRFI+NJLeqXL0t7maZQx7OXSZ3ecvkV6+c+p8VdLUVF9eWDt+E2l3ZdpSnJaYX7IbJwoiuPB4QylRSo/xKnrxdtWyjxgLY8w5Yk6Z4hgFtNlU1JWcvCKGvzUZ5osDkpYyqNqCYE2X0jvL+rIKIoSU6hPpeEON00G0kWx647wAt1i75rCZam6qFL2to36FUmZi4Q0zn+sHrg9I0hcXpZRrs7zS9m3iRLX80LkiJCZKZVTu4ogMNh9j2LiITvr9VTN6qncU6MGyBRIbtMY2Il7OG42l+X0IPVrB+m86Dsd4wFgFhLbLEns/3T+luCMdL8n1t1Jo4hAVtvLDOr/D6r3eqJUkUh/ESkzh/bJrIehlakE0LDpEWY/TW/mldc8DIaWtx0GOaY25g+wS17xxhY4DrBRos587pfAMq2d1GpPTeOtIMDqesR+jyBzJlEi7H8aQmzjx1+hb4AEUsAnSLiiMxpts8VOVz9OuiwQtqOaa/KnUK7KHl4LcV47FBP74kEAAvFy+Hszp/olkLJCIYe2J/M1vk0SSPEubQu52nVlYzQOBZTXx3lNQfPKnAFu/KqRBfu+pRvk+JeZIlC/zuReMtbJIFeqEQ0ioG0nFqhTsQ380K8hXrwYJLG7+usYFogNgqBR0eQr4lRIYvkbdJG/lh8VT+YDUhI3VotfGQRhsvb7M5Uook6/3AxVOirLEYfzj4FjlXf4AKT0Rb9SEWpV0NGlsMUFHr9XHzydfXjShPl9v/erx//c0+2hjY/48O2o9CcNB7dqKpFTLpVfjtmpDrH6OdlgzKZtD83RhXJ9QNF454ykRaLeAZKKGlxjxfOlFIYIk/P/8pyNwpJoPEYDKr0jQGK54+M46JMmg2DXW+m031sx5MNrwlzjIr7xWN4YQfcEiReggTrtA3zWaQQeqKqp10WppKXoqcekP7EAwKjfm4zjcqz+TEAr7NKvFSiyELw5d7azHFcx9YGElK3zVGbq+FLJeC1Re0D1On/kia3ideJPJi4xB5FtS6YIPrUu0feTqG6IsPPC2qG3c1gWA9oIPyhCBRElTLXsT4vJvxKoG2icpgRgUtQwNEFpGOgdWGqr4VZ4oj9WPZ1JNU9nRv0V/pPiEDjNtUwqjKG83BG9ncWv1KYoV0tMV80NCcA2a+VdOuMSkwmBIuGABVEYf2CUQbOxTLbb7c5ltTqmNab0RfuqyWVeUjNR4rais1tJJzi+jqqnGbLIRy3Az4n/DAx1qUFMV1kOFTKlRDgsyD4JjI+bbBhom1UH/e6AmtCYKNg9Ghqd9Mtbr5DWASfxZ8UeQ8UYdkwxx1l+gX389b95oiGLPccdNFGbOjLjTzjBme7w8h6U4M1SOva8pEU2gAHrEc8Us8yFbi1I2Eq2DfAqWX/ehB+0+dUZiB9oei0Lxv+Aa64hUXPHzC9+/RR2U5xd7CplhWVh/bRnE5a7rs6HXHilbSHv3TAfu0XZIosZiWAiQ0n/TEssypUXtVidh7XdP3SPC+x9TOZgfVrccjZXek3PTPVr0e8yprwmPLMaYPhWBuUl6e6QtLxh+WHoTGC6cAv4HwCWXC67VNmifiVp/MksKYDRhDWbsvO69WY+YuYzonSVHwitApBizHWIJr5tRhf/wi0RHK8A438E1L7/QQ6fQMfcyI8E67DwP3cxHguwt2BhQe8BhVULvj5bpjibtHZtcYKfNT9sQgI9RkGCBd2xRZta4TwA4tA90nYBLlJ/28GyA69iWDmTTtDMd8Z/j5QeigLAU8fj6Fp0Fog3JeJWEIF2L+Hq/vGnqaBWDZeWGNkYu8DBcd3h7oPdB7mTBp8DAmoVDEusmx9KAoGRmUsoTUvzFEVJeg9d5L/7eYHoMj+WUYQ9QqN5E+4YTNU034jrnrHXi/CJGLcpiU9gAAAZnnjTAUT13Cj9sjqHAwWtSpcg71G10BdKRdJDt2/ExPE1x658WSGTNG3AAF+1rRwt4IA2tJO3RSM2aY7zFunGBT8TgW+ROqTtcxSU4O/PxCQtRHZ9vrsAAGAJOK7jYnOjNKhGyZNWh6eIAxp7qrLTqEbHnDGJ6sOV+Cd/bMv7qsFe+NUfrhxGu2UzQLpzXa6NkY8DLve6MGMM30qJTT4AAX2qbfS/IwLq/t7U1t0P/jkfDg0pCiCXQAAA=",
-    // "UklGRvgGAABXRUJQVlA4IOwGAAAwSQCdASpeAV4BPxGIvVesKTSjpLQospAiCWVu4ExaXKX86U+ZY+sqv/K1pAk59Ez/PG0KW+p3+LdH++p4vngX/0751zZlf1Vd5YR0YaIW4bn1AODU4aIW4bn1AODU7IbpeGiFuG59UtD8i5Oj4bn1AODU4pWa5bGZJRAcGpw0Qwo3gn1w8LkyLClXuznUS2rAaIV/cNEZhECixSh/4XJj7u6IX7zJYZz6fwl0fdsif/CKWKnAbnH6cIOmpMKC3yYhhw3d6+mcXzkSeZSLx0fc2i9wV+JkrsG5miRIrPa4Cuca6grdCMX+KQnis9emOQrMRlJ66xaRPE2mAv0/lrCqd0l77qSujx6qNz5O4tgQHVTlN206JLdcQ4rWRpsaGiQsSOQf8Nwi8ghN1rWuXjVpgzeVuNngniLytGIMrAMxNBILXKmELsPM3hQzPoOSoN5BbqUIwvYsUpzLAoTHZTyvvYrJ15DUgzc55hbElx1/33rdm8tTIVxTW4IV/DEaruMP7b3kzuu8CFhHi6uPyvDO5+ARpgjxmLcXrhmwOW0VbrQjhdKzHSF9S4QFuccwV8YQ/jrv55QFwQtIiZj7LvGy27ksK99B+vPnA+Tbo4mN68GPcLJHTO63yKmtpfop8W8FfyyjtIzzJ9GJDqJV2vxH2sAVXpZcb9lur4ml7ONJp33Sh57CmwhD73G/vCe4G+g357gr0vWNLnRv7ukRh2RJpeC1NY9FIcb3H//6S4XVFc6wjFgO+xLHkT4OVKY0vItThohX+J/zH02FkcDJOJYCwqAA/utmP//IMf4K/4K8Af4Ee99dgAX06YKUtnnvwBBAAEAbtkPRzyyyDUud07HQqAAarvJelUomsz0Qt97DwI2MQXYAIOYTwQeQaleysZQp/5+nWGUfEBOT3MezEQxZfIZeUhVyo2z2FxJI4J17dTivP2E8vjy5K9i7bD3PiAKC7Vv7249iu8ysVGsed1vdno/ggDDQ+tF43IHkGElU6DKP2ZSlSJ/xOs4moKbxbnKBI8gASLCz7v0NKVq95B/8IKvZ56ULE3DYtJRpsAGcAqF/sBfjieqa5BdeztkBq9wkqIVmjpI8IJ6HF2K+ck+ENAAqmDzjjssS/9Kxw5qAC2Qu9l5txB8vg1kMlgCBp1z9XRQoNXjkCg1umvGARiwU38uJvn8iWTv0ITNWpITtpE7B7TMnEwUIQz5cGH3jdRETivL572iJcmtIKGR4nKGXPYIL208MjYxgWDySUjT5fTqhwzoCY/UJpBZ0Y9kA1DxNtAOqhQJa+bfli7xeogzrkOA84G1aDjC0soJsGiqa7VseEmQBVaqhkEdOk6gtI+AA7mlzLPTc9FemLrLdyaO4fnhfQPLCt2Ys6VdsEfDdcozO35dBMwKAolFUT9pWotjhyL/jQIEypYRTo6rEDJdcuBpmIOhM81/WYckMIhralBpqcJyFQlEGTe/yWpyF95Bm4RIDVvCt2pcD97EXh6RtS51ek+XLkxtOaeAH5w1gydWNgG9GDerFUbtfKlfEc8EeamPmTbqXtpS3LXGf/MH7cWg+MoTc/gKe5XeJygQZ3N6jotrJJel5vlzKYnxjD1OrauCnU75+3G7+pRdfRS13j2gKCNCICrllacecEQWxLJ/qHP3xl7QA78n1Mg39CEyDsypQUf/AJk9usKiBKvgHr7ZpXftD04AcnTuT2NAtonTzugtguAn+7rXxoZR8C7zUJemB3I6iLfE6Zv88BsyU60ybCJ0BjFIrYYMOXH86JNT7pZ2hm/ZFj/m/2SNXpBNrtTJROfQP3AJWya5xVOEjfpdFaW8AO9jNXOqL1w9btB464dzH0okLF1bQSk7hzoEytc3ZUKHpXgQkDx1CO0D+UFKwsx0w4Bf4As4sYDCxUNv6qO66sLEGbkL9VIkzKx7NwQ9J098RVwtdIdLQcAkkcu/t/KgR8XUqYncXTSg405JZEFxbPat6Mp2b4J0U7tghULGrH24ox7TiZb6198UWJb1ir0GeWMiousJBDcxUh0NxmJRHqcvkZJ5O6iiBFk7UybbsN/tZyeHUz6fArQYytHkOsUuTuqn0WtXV0FKGiMH/irCUCzSZcwFqG0xXoGqsQt61gLDIs1sDCay02BgJ4Pc/bo/rfTE0z+DnXIG7p7+LYIOk3Yk4FPDv793uYErkAoD1r5/K51j9sEJEtClL1MERcZFYnbv69pJ7ub0NpR+phcuH8Qz6b3TAaNUrl/7crfLZVqPVyRBcXGF5xNDDCFa4+kZXPmMWsDyeMMRGIkmBCR6pZdn4ewypAnXPtgUhojZDQPjKoM4Bz/DtAWQMXkFulQlXm7zZXfN8ACDeg33uPZ7OyP/SbgAAAA==",
+// @generated by `cargo xtask assets`, do not edit.
+
+pub static BACKGROUND_SETS: &[usize] = &[14, 7];
+
+pub static BACKGROUNDS: &[&str] = &[
+    "UklGRkoDAABXRUJQVlA4ID4DAADwJQCdASosASwBPp1Oo00lpSMiI5loELATiWlu4XdhG/Or8J/0jtAshv13iBWrN4p655gfTrWBjoP9145dQUEaB2mqXVT4oOW+Pk2c2iPKPOHBKXTd/0uKDmw7TVJSjQ1a78CvAb2wqXlwx7hkryrL/gw0cJhW6ID04aP7lmQGw7Gnf9oZdJqf3tNUlKhVsM4gNh2uuLpzIRAcqVA2HaaAaayefXMopnfj5a8196hIKmHmG0AOIDjf01S6qejmU+KDmrL1pEwU8pqp8TKzBcLi/6apdVPSX5eCqXVT4oNpvlI39NUuqnxQJHa1L/pql1U+JnTb26qejgGQmi6qfCIVUuvXID2uEJR61T4oObDtNVS1T4mSH67/Kal6bAq01U+KDmw7TVLqp8Iha2wOhVLqp8UHNh2mfAAA/v+3uK+dj3xvwVLEwBdRkmVZBsep2AX7z7uJPKjYVWOlKkwbOS4nniti295tzB4nc7EfrYyHaaph99U8BUrhoNfp/OkOB8IwBqF0trZ8fmVZ6IWsYAyMo6lrODwrpIbgW4v/0GmkvxpIV4+aHR/j5RjkbO6d/p0OGO8JMaHtXeZuW2eC5RQDS8L5MOZ2zqOZcE+1rZfy2ICoomPVpzn2HjuSEIEJwnX1sxQ7biSzpZzFVbeFUMQSQG3Ng0ltJOA0TnFJmAxmVH5OSCvhCMxwF8A03a4xSoX3PcMhXVcUlVL9PXyZnTnO4agcltqvgRPE6J2pklBxt5W9J66WewZjdEVib4WGYTitGttF3XbG0ppZq4YJuMFJChjYtOU6dYmE6F0Nrt95oQOW0B5MppzV6VbIY+ynth/S9AuBW6ioarDRkXZfJDjaE0K/kfP+/TSA5LbpwLlTYWMM3R4O1I5MFPedTftR8+OqMQKvvJ1uhDdErPEtwIc/ourNoNyDqes+wjHl58/xif2pQp3VEoNOwKiQhUoEoY+TEcaH6uoqqLRqnO3Xtsuiog3SE3GbvmOPGNrk44rvHDwjiLiKjFKav7HBQieIT+NCSZO5sBB76MfSEf+Tw0IFl+0pjYdnISGUrTxWgsvWfXHWLNHCQkVKrNjrwHFvm8G2FDOs5oRStMwTAAAAAA==",
+    "UklGRuIBAABXRUJQVlA4INYBAADwHwCdASosASwBPp1OpE2lpCeiIXEIUPATiWlu4XVRBDOsACz4Qiob+EIqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIqG/hCKhv4Qiob+EIp69e0UPsJSJpFQopM2eIFQ37ySkg01Jq0dSyb1zuysCyaNAFt67fLgotlRqG6GB+Ud10iN4NqCXLmXX148U28jxzG4KjnqXvS/lEg7oEGzSLIt90jvbtCCxxkdpohwVIrsM0QyemviBQDeWz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js9p9CbPafQmz2n0Js8wAA/v76O/4ElasL3+qqevwOOGCK6WxE8CAAAAABbPrdsDsJfMHUGNYPnYMSThaHYenTMpzWoOagZwaPm2qVAP4g0oKYDYFh5JzWMm4mnJCjdgtRJpM2dpO0DwK5s6qGC3Vn867b92nPegAtCEBkIrspCAM6Al59018hxHeB3a/zrtkBHNvdENUBvBBKAZoKrL/7/E9Hv+FBgcoMz9chWzEnTzB/dfabhdKp1+Jn5GF5X+nWwlBAAaE/xTbUKBtHC2MJ8ocxxlGE+UOY4AAAAA==",
+    "UklGRpgFAABXRUJQVlA4IIwFAACQQACdASpeAV4BPp1OpEwlpSQiJVnoQLATiWdu+F7oAMGat4UyY784MA7c7+d/VTEjubCDP8Z6f/p/9NPOm7qfO0vlX/KewuiCtzlpaZjMA5Qojr861Es78+1y8AFfAUG628KxpZYxuaWZgrbERFwSq+OZiIuCVXxzMOoH/s3zlJhWAoN1t4VjS11/4zk5UoCVxKhhcB+s+14qqIN/gWXkihhWLqmpqFZ9kr7BgM47eiYjwD1Tcuftk1Woq/j/sUVV4n3F1LZGqOyjcPFBcgAxEbYJg6uAyye2xkCTb72q3Il9TrGIU0l4XNbKcQbylRM0p378ZADBxDABaCY2HfceKfcwDLWl8kM2u+8Eob3B2QrYTl7fader1nvW1CVFYy+x0Z9ztW9JjnQPsUQC3d9ZCv4HUbWEa/RBdyAXeHHNrWj2w02V4AdJgrmYbIomACr8Ra4S8wSUcYH5gupGl0RUuvgE1o5sOWZmpIsDk7/xHkbfdHmcSAJjabmwe60sMO8JxL6B/xd6zIbZaFbirLrK03L+d+Lw9RvfqkVcxdBjuFY0tdwgHDSCzVmWul9zAknvuQHFpCsaWu4VjS13CsaMyXveAoN1t4VjS13CsWSJ0p8BW/rhWAoNrtwrGllq6ZJa7hVWkIkzw5JPZazC1O2brbwrGlrfUHrwrjGSKhCA08K7cCFVOC4UvzrUSuAA/vANb4lDOWYfv2POB6pIeDzQB/Qk9EwAAFAXgj4ABOww9oJyXZi8rqrUi8O0NhZCwRUq4kX06/upGkuEDxz1QACT7oi1hIM0HqFJdiPnWDVV3clVQ4531VvCtNK7wMYHkk2vcoysSJKFqOXS0I0f7eKRvN556fVKgbbRmUCV3aMHoluc3cOWhZt2qO5hN7uZBuFMQNnw7rOYIjNEXYKoJr57JtSVo/SyytedFpXAH24JYXTJs3SEeWPEie08DL/Kmv0HFvdpsGAOD4cE9eOCMmoQNzbUJFKzg6BpHZDhhmFwek0Mw01g5WiCaU4aZmQw2FgrlbHVZUC1mCwS2t4vs2txIc5uHDhf0XSWoARI8Go31q2I8ttX+rPkf+5yVGuqlsEzLOFfyNj3jKThhEzXK5gtqVKJiVzcTRKbnkOZH2wDTqMboeMVUgkigK4mFQKm1BF2Fj4bwcNac3QTSQI71P9VdvGSxLwL6NAeoOzEbLu3J6sY94iZGXC+oYiGlmm3EKJa6O9SNHsjPooloL3khGcqvM22g0gXh4MjT2T9BAf4jb8BIbM9W+XSAezP1joH5cwZGfOw0zga8gpUtbO6qE+7aoakG3SFI1KLLBTnON2c1O5U4wFp26iTZzNUSLaQ4kwfVE71cSlZvhVzdEX3mYT0AC6PLE64Ef4qbvUxovZhK4H6x6J2HbnAsI/amNy/vg2LYTJ1N556fYX9vIvN4VA0WmTxeetu6EEBzKWiv1qcIETsMnP3S6tVMpR/PetokD4nhYqCyQ4YezQkVLUflcL8ZQsy3i075kBM8YSNX3L9OVjQoDxRH3RG+9B3xr6Pp8pd959bYbBZrstLalbzYtHouKe1G17632rIQXQL0boqtackIb3kSny1uHsZPE/awtLLkAmajhxL1vv8OR69Gs3mb5P4zSx1H5WPS+NCaA6/7CIzoLYnHiwgANzM3taFEYCJJOmsP7lPY4v0bHCvNYi0JGdmPUmJo8YRJenmrCzDUFiHdDj7oDn9ZFT9ljbYH6QxJMIzUGZODUcpnnbMM0IEUSivwkF5IywXLnyAoEzKK2kSAzKI+NgAARfwC3urn20x+kziUp1xMa6U1O3dOACAAAF2DgBHZsFAbGgABD559QABKCSoERV2CRnvCPNrGTFIAAAIVNj85oOIQAhTnSJ4pS0U0EaAAAAAAAAA",
+    "UklGRrgWAABXRUJQVlA4IKwWAABQiACdASpeAV4BPp1KoEwlpC2yo3J60lATiWNu9x39+3/HgS/91NFP+cKwD1Das+nHzu643ISxkV91Oe30w/5r1Fv7/6a/TF5kPNv9MP9v6ZT1nv7b6i3nTetrkCLXsmOP0iZ7VwAfWu6u60f949CDliI53uLt09ovV1DCv2jvqBaG64y5kDhCY3c8T77D/RbdSDti6sVBNIVo7JsnV2f7pusSsCp//+rcG9YKTf25cp9FkWdJvPIHAE82OlxT3eNQA73VD3Hjene8YCV4Qa+Eo/PmarfWbex5RdgDwOcEGB05X40IMkKj5B3OxLl5mljKPtco6Xi/P9pjI5uUS3uJ+FasLWD6O1TGxmfIka2PyqgVkJV2q3aI9w4/PzvBisYjhEO8XRM5nnCeOZbQcuN+tt45Rxq8FMpEoY8CABkhdugyCVB87rHpNQyKGo4JtfDPhW+0D40Fp98qYa35UkNUWfnPiw7KuFAF07gf0k0qLxrbSibSt6SiVtegDC2qoKujw6dY3wqM/zuUcALQt4iT4s0PlBNatFN53seMfzu87+eQhElVST9+sijCyt+j7TvcvNoMoHSmbHj7ydlgb5lqxV3qzz8WP2+51kPO3bfoLwVHJTBBqngzrqeJsFMVUBUvUEKMXmJRU5MIO/OtQg4g8YQ3OgaRvgZa55apE1q1L5L47dxxg5NjUL7LAQ0qGawho3Yx88ftdRQfEhc4bzIXNyL7+9/GdCcEj3Kd/3/GoflaRZadFpiIYn61R6mtN5yzM/19nhJTntS6rvxkhpvzyOj1kz3JYXa5aMYcwLPl697/AihnMAam5bDdN9Bsfu2ZdyCPWiml1Q19Skuz+zMOTQd9iHEjgEA1ziLCMxbjUwxRN6nvkQGwkY6M1Qtp+rIyuaYg+Ni1A95SZaYE6uvgM1a8KByMzvQzpTZKxB0jBdgcflB1vd3pczeVQQpa6+De/UIYix7515cbP4dI1MZ7Jwf9dEfiRX/iF3UvCkb6D4wSSO1ovMTMOsboL6Spj+p8Mx85aEflJXR5epGvDM15jPGGKOMhs5fGQFGaHSo3NNtdd2Dfn9yJCNLHo0Gc2Te/IFcncbGdnTspMGHqvznPBli+svziw+hRl4szLV99eKWZKWn6KtiuFBO0kdynq9Ut7Yh7gUGEgM5k4yQfAuaGQkb57D6F8/DtoCAGXAX6PDlj2btaxVzJ5dKydyysncs15F/FEOCK0E8EKyelSwEAMuBKDYSdJd94RhmA1vZYoOJIk8ErzmaoWaRC7oXsyHxfKW4UBRT5BU149EfLpcerUWSFqt5oBME0psdzqYnN8Zs9CoxV8r32kxpJRryqVjhYsPndTYCAD4FKrwJCTBa2UDdO6SgEAHxQ1xYksroJQPyWpksSxmNB5ZfrlhwPJ5Ozdc/bg0Tgyt+0dF4FPZs3jMHkc1TB6ifaatSJAHReBT2ZopQ7ooCDAfrAAP7mUVuY+pP7COzQEOpondmHVPu5Lf9802NYz5Of8O6qp69u3b+shCkfJh66EqQyzaKD7Jp9uENlsdbG/4RPvB9PSRH9gqPw7oWkt9JTmJT3YF5tSdSN3QpaI9XxklxoYdM5Iz7+JgRTRr9OaaDS3A+/NNVRJGht5nFnkCAiRMFKB1TIHJxjiPArHBXZbJW9GbQW6Rx1Awckf7uyJes8xgp1/kgAYZJAlWYwnvuSG/p2NkY2CVOXO9/N0DFTffhUsTcQbXLYvTCgtCfzmFBysJNgx1huDU1bY5gTRazmc2rUikZ4qTsDURkNDdcXPPmSgD2MARTGewI/B9A+uGxmvze3Q65Yw9+1Dl+Roa0V2VrJQQAcD56VIn+9uYHgaINYQipy1lEzfo8A05So39SJFcWY0z6o6IVxIS8AHxw8/0KM+zffWLtRrZCeHvNeJeavYcguOBBBRfCG8f/0blTB9r/1N7qmRDNxbpNCyoDo0AEXooYjOc4mWg4t5gbp1QT5UmxtfohHcPGxln+cBMEFm95GmWczPZcUw93TYg/9JtnwVqEDVXDCC3Olwi+wpOx1b8uMOzrfG40mm3tVWlhGw0q53USIy8EhVeE+b9ue/3geejZI8Rvrgw8mfQrwbe6JE22+Vg48vcNWnmPHh1f0WV+1uXGG9t0jPLi8LidSizew5L1N4inLB92E9ssH3aoOEvsAOzr0dGg1iQ39KLKCuGAHfjtyJm14Ir0T+gCJpnNYmd4kK6emJ2Jta9Q22dG/Ju9Xjfd0uX3BQtcpEzlF3DVsH4eF0WNznmWTZyvZUxgBxLWDramWNqXPIOCi8O22tmzy+Ps1L4RXSm1k8avkIL4ozr+/WeuWvzviMSAwE3nNXJCO/wGBx1QE5kP859hwVejDi1GV5TTrQKYQFEwH63D2j/Sy84OP4DkZNB5xNqfGfiE7hyTqZcJ3E+mo/Q0LQLhHuQHkwZnyMXAeZ/i9tO2nJrGBf4pHBA4h/vN+ZjjXYqeaTPYKiQSG42Pe9TeVL+oo7vZszplgz+2lTEmOHISzpFaq0/8oqIUCSiFSrZlD/pqRhjXTJbBwPrP2jM2AZcWVZqpU6/JprP4vD7s7D2zpEaT/awmtC/+3nWjcxAoDQNIPW2KgFNAxBgwl0RtvNR1ebVCQA0iYvJpehQqv14ZGIm0cwguUFhRnS9bPmIlRDLG9K20yD5IorXSssnQm6+Kpw2ioMXazzSYKyLOntKkZHYvWFCYOJD/JIAbARubFZ/heICIsRRsER4C4TDM6aTVGeQGhh+XBzNyVKYsqkkyaXegbcT88VJncqSU2Cl5E+xNqz++BN1aOOOdUo9o+ejg3LeYZPvchX00OyumjcOejLXSALniivjiollhf7nWdDnx65MNt+IRAmbTs/6lCmLbZZSCeAe2F+fwfWYjaxipbVv8RYkwQcmJ7CmITTP9S5Q7poHSubV6WYRhrLF2VckjNlSnTKXwHuCuVH9H7WIk6icCb6bWZSnLDSFjE1P2uVHqktmr7nROx8C7HQAqz9LNtPeTddW8iIuy7NgA/r0hYYY1+DlgyvB3OHFefRXMItFOPT9gV6gRpwmldGeW4cHIssJGAtZycnnV1S1oSo+UnFO79I1xCri4UcFhBdJTdYyOlFF1NbmGle01DSWz1cOoEFdErKhWMS/266AajjsfkGmXTgO6Kcdk2mJmE4dB4jbULc9l2A2zXCF0GpFzLAC6qEwpHEMV6aR86bIw6hg2h4Po9L8HV4kr/4IaP8G7eDUjoMfyV5qRf434ZAarAqdV1/R00HdzmPlQfTlTUGHVGRqdYfUAsoUrdB+Cd/1Ll4X/iXanRUT2FsFWdxaVNEDaYxKBchuFJ2Wt9xSWEqFWLoORugK1S9Fvst3GKDrcfeqIgFsEWsKWwivU1aTUJ4O2CxUFuMH61JmnuPcC2yXKxfgrHdTKfcONJNQ6NvK6BIahMgoUoDYxDVRklXe7fFTkpliLYISGpMyUV5pXR+0ydL9FeHTpnYD4D+rj4kEB3/QDRNI0pMG8+LawL6qIdtqBBlk7JZpT8tdWjqyS2Mpz654BPGL/fyL1znStb8P09moCUl2hMzvQiyKpbaXnNZLYs3QadQReI6YVnMLwjyeO6qO7nKep7pb7X69Jq4pmgK0N5q3DPy/jzuTI7oLW39rqn5s6CCMfxWX3lJkhchEmNb9WaecdWQYmbkS4qyT7wnWqWPsHfeRu8St57n99GZsPrQYkL1V1sOcmUD6zlaEd1n4GNQ6P2faFPauVjXMxeah617giZT5YAeMZuXi2MS37A9alZ6Tw8b0OUi46MBn63YhYidozKrKRzx4XwvSsxcws9MYujSauGzdf0GuC1aoPhpKiF2wG8tyVg658DWaR2cOaXLDQd6vPUN5tXlWwRrg2WIsmtq9rfDoWB5G1wwtYpWEVgvv/YGFsNnvO9z3DA8SMK3gHy9R4rpNVewmXRoEU3V1uDdW2N582kjWa/aKhakHOQc3L2DiyAFSu2k1KwtwBO4v4JFScgaa5KPEwomJpqdMa6sl/lc6vvzkQ5hts9FZRygJOFi2uYwAxzeBMzI9ZrxMWkf2osiX/N7ssmm/2uSHfHlzdUYFxWskR8UhBWWdvMr85m5uV5yMrRgWem1OxHdDzrk2HEeRbTa5Nsve0/vxIbPj7I5HhsvssO8P9WHABXWo3gR+33K43xlVg6a2tNOgOR5seM1LBEtTJT5que4dqr36pORSoK80EPjMw99OBiwY7eczwCe4J4w+fKuLAxkLmXjaQodrcTJWjvyHZ5K51+U2eT0W3FqCvOdVeRImhrIVMo9RcEtSg6UtDZ/XR5N8N4xAGGCdS3yazC85h4KzI8+4j9p2i91AmLCsEE/owdNUb7IOztZHDNAbPkwkSpspuEb/9NBLc7TUz+xAQkocZCJH4AmmsTX9ABDsiYTHb/yxbZFaS+BNa3B4LdfM7743ZWzuEuGf7DxX/BmQ7hz2N3mNuCQh3jyNJbwhRDwq2h0NH85hw90hr16ltJNCACA6ZuKKoMVbS/xP9Ah4Td4SFJjIvICO9fTbW3I0GnR9JUCsrnE44Hb7qLhcYCMJrpYwVrw83hUzPNHnGd/WTi3m6hPD0DMBQgRxECwsxLOdVR/OBVX5OC+8ghDEFflCNfxZU88LJuzqt2DQ4yNqQskbcOm1Sx/+7pUaap1I0irD9gFswrpO7uQVGis/I81Uw3Oidf3cu+hVql+YdeBU29t42L9WqEUSMRDmRK3JXEisDAna/c5GTDirnVj2U9QkDnC6U3g70DblewraC+vtdsoCdYgiSzkhUPsKoa7CXA0K8fwjWQWJ3RokFn/LML+tJWfcDAnTencW9Oy+N7i8RxXP2phTjuhCfkfvSR4sWnU02S2NZZawq2UAjloZm9FeAGNYzcr8kgV04OhEaGgXaPFGiqx7xlK5zUzF3M4UN0yjcO/Ml5vOdOOZkNNbMaDPxAMxj55Fh8Mux5L7imUE1VBXRyposArK8Fn8j3bsP4W29c9khCaPsAWjB7n3ipgCOJ1++BV242GZDj4IiskOXVocEb36/XJ0k37mLyDmOnfl+weFu/yaIHcuGDT28eVmhtOfjKkWxKxZPtB4wIQlIopYdwDdg7Rhbo/sakV+FF0/dJakvfJ2t9/IkIyDJS2K5yb/m7phmB1wk0RIPiseeEH0jK114yGQDHjkDf8Xde5YmdlkpidnKBotLFTDMdPwtqbZTOIIrcasmWfOHzneNw6RCeikbWUnJtFM3BGHkIOUf1Hkz92WROaJtc0pa6ubr5wlFFwzGhUsZXaHinnzljy0QTgBq8tT7TT5vX7C6VHfLyvBUdRPlPOdQs/k6f3FT0rb+OqnRKeogyga7uMAETx3isb/Bw/nv18ZP4sItYZNUj/3m3CO76NcRm87tYISe2PN3l0oSgpLuGEduwXQS23jyxrotvi+s7X3wUMY1VTg93yyqZHmNmHnmp5kzXo++1ggY7YAKy0uKOHqxa2/DPq5C0RDJcRne1G8za1Y9gc+qFU+lDeOHokIs0Hrvg5DHyNi1mIimaauE3AOTBL+cFgDsvarpfuvWWIGF8Ao2G3Ewn4978Zc+EBiHQEybjS85YzWlOUO0wI2MZkwJZHUK5zgbQ55+1UZwI9UBubhQyZ3JyS9WFd+vsizlJE5tsBrD5zkIWPIrr3RRqD6nJOjOWnfcPxeIJgb0eJaWiy3CbyOiPTtBlZcsrQR0U79ri56l/CR4M2wHT7DLKviFqAfb61Nudn87IbYiSVRluvLqe8dhc+T7173Sh55tVyN5ziSWQX1opPDsjJf146QCnu6WRTS3Ov3VzY1rzVgzYHj4NDw2xqmz9OqCjMva7eh/fNQkFu5QaeX3CeCjcf9uaOlgEx74yJQ7j5Bd+xzGsZvKccYpqiz84zQLtqI7nmS84qbHFPZRhEdpng0VExfu/njtw1XroSx++2+mHYDgbPM6UJHlt0rRGoX9PsmlzPMDsIcK9jXqj/3tX53rZISyxIbNGIik3tcrtUOfYmnB1ZUbmsX4NXpJ4HHMdLIQma4NqOvE7OoTnq7u24fs9Se9b8um1A0teOGKQ9XZbZQsUekQEVR0VA7u5JJe1jweYkW0TB0vJLsub3Mei0dokwdFeNIBLeMH3m0DcBZLBAsDdFQhWnuU+JxBViPTLvVzDCytc3Xxbydy/utOPg9U6DEMPyn1M6EvA+xPDntYrwb7uY5JKEapcF6ZmOFxRtSz5P8qMrICpHdYS4x3DyRXlaSTRQf/gngVmG//f5BLqnzLYYB19C4ULU49wTXScmwjROVeeRqoLr6HIKdIt1V6Ua0L8s4O6ee+zA704QKYN2C86pg/tnxudzlO6Pmls2lmjCcFru3dLy7himAujvmbkENUU0JHX+6S/LvXMpDLGfafQ3echqhymFs4yhh4FYuC4cG66vMfoXvXAprRJSt5iarnSgQ6btusq8OgWB7t3tfKyfWlWlBaqUbcZth8DheXtHN6TzJp5K7gM9kT7HXFLKImu9CKhk8FvuicqO/smdsmohlEjGjg3InbBoM7p5u1W2d/GGhmz98N2baiiqF3UlUwgIsnJSCD1wx+lZSJq6UQRzD9nBWtmqAnbHmQ4ZPNshdUnO7uDwOZYYIYMBXO70Tnfq6mQzZMUn1YCNiWn7duImPxnKq7VhSBpMqzxKC5J1XubtTfbMh3HZKvJQwxoy7LMttjSHNcf9TQ+4IuUy1mxs4+QsFV2kHkPScm0wrFkTgax0AAAAxVIHomlAIKCiz5JYtRf2XOQ7RAZNPKIBCAMoImO13oMtqGpY+VjRMshYld9L7easoaABprnekQQ6s6/8RfDOa/ywVYvpVuDtWLVi6APf0hToz4XYRWlR8PKrLjht6Zk2ss6YlXnX+26ai4n7xKW9MfTE4VLE1gBcl7V3K/3MSLpGXy0CBFe7gklywf1dLj1v+0a97aefx2y2seAEy7hwAsJ0Tgl2LBLeTd6ghBS9vOZm279cMn+urmNRa1YCbhpX8I98F0TjT3cAednm8YlZgjjZwDZmJD1C00RmABDEZJTLMqpJy8wJcTMLdR503XAMlYVmW6YrQRW1yuZolUxN8DGFeorsqDzaelad+4PWVgFsrYugMxQ7QMljH49F5jMUGoo3nD/HReb2yTB2RSGyKIHfjD5cFTwAP4k3c3XSsxHiV0NA6yrbJ8NLICxiqc0mYO5tvtYp7+NaUK0dtpGPh33f57wS8pc4WxE//P9nq9EVzLQN1LSHiPlnikCPpAnUtit1kDK1prywRJvzLo71CT1Ju43p6uKWzDOrNXnlr5p349vMbUrsxkno67MovAN0Ft4iOKWEJ6UJQb+5Br4J3Z6U5plJwNH3t05dzKig+Sq1unNrnGbLmkVhYTli5FLPpWHL8lAnME3XAsGuGnEImMOpF977+7rOJ4FxHosuY7zSaqhPRon7WtlnfXFg3aGXgu2RyMUfTcp1ipvs8y2sVUfTBMgXr0I0AQPuE3a4zcNEXXs973UhMMVDuTYO9juUmDehZ/O0kAHyVxHGIYKTg+WIgjoYvtlB1exB26dlEPOxnVzR6ZuJk+LcEzV9Let0lMd73igAAAAA6tgVhuo0ro53xLXx0tbRElQwCnSefizTPJieIK+7qOVDUjroAAAAAAz18Nx32U9TVL4376Av6fsk8IBE8o5vK+3cLCvIgAAAAAXq9S/euYwb4SD3uPeKHOhihtW8VFbkkQlx2ywmt28g+o7DlfCsdkAAA==",
+    "UklGRsIGAABXRUJQVlA4ILYGAAAwOgCdASpeAV4BPp1Ook2lpDWvIRIoirATiWdu4XbbZv9uTCnMX4ESH3AHPM+eZvt1O6Xk3SO8uWAD6NMg+WSiu4Rd6Kcq0/JQWchlpLpS6JF++9F/IbBJkSWAIKW0iu6ENTZfDnqtd5yzgImWBEcTwgufXJDK+bATxSLTG4eK81hCgWTZfDnquAi0mBWYdfLTIlGYj/adZld1r8r0h/0MDuYKRfgM5Fn8NOaVgKyR88h8aEbmOVcBFpMPRTdKU8RGvJ+w7UPsqC6cYBkhqEtZBUZAH6aKopghb+3gL+Nd8sUtArkDv1XARaTD0NxShMhndJ0nQ55lyr6tjpEf4vcgEUd2n1WJvomIA9MKAG14GApPtT1P3/f9/3/f9/3/f+5Rd1olVRjJOq4CLSYeim6iT4c9VwEWkw9FOVafkoLOFg47x7zo+jfGKmbGCTx8Oeq4CLSYeinKtOmOWkwrUZaTD0U5Vp+Sgs5DLSYefi6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5Lpx8l04+S6cfJdOPkunHyXTj5LoIAA/v8gJt94///EOetv0w1L//9NI/8aR/wPzQnA2UIqjq3+TbeQMSaMdOXSxnwcerHP5R/IoP3i1P1JClU/UkM22Lz7smlYzhni2wsoskmAIb9fO22DPSaKv+5DwevJOBI8ikwHYMv6PPaq0Nnf9sDnkiHfbX8+jw38+j+ctSTe9nmIEjIRBlsPGVLkNkflIZPmjqsQwnEERM+lx7PHmUjBfmc7BltppBtfP3ScdhWaEkQwPLHnYebfX95KuVb74LF/ZCgxnXBS0a7cPmOrjWNtFTwHRkTk++VjIHqkx1H9KXnCW617kRrUV3lTdGWRmgxPBWXwcndVs3LTCiNOjvhrbG9nEamu6iv8lFnJc+iefLbTla4GIN39xqrkvWwrhSLjnT+FGoyPZf5P6FeSOFnP8hwVDCHU49yAFY+2wF3pcKfZLxzpLseMwX2j1Q50xXDNAU+phN27W3Uc7A5BVRm8fALEDkkPQ11S8VdqueeU6+iAdGoAKmDCndSN0ZKtR/zq7bmOUIr/C/2KsBJEXX86NY833HR7MbCHd2v/ciBY2xmSaaBaVH2q9UfmeLxIzu5PSkKAYpKzUWmyQ5fh4BOmBRd7fpeDdUbXAmnFDjx8m+GHaIGiLDDjBEYln19vzCmwCe5VE42IWsY0MaPW3D3587kTTIVUAuMqRXRTEwgmwMTVBz9JF0dojYG28tetuzjWxZBvJQc42z7h+5EJoU5BCnCRdKvDs8qFrzwJfuXMDnPhSFWKWBaHsTB9vgkGMzJkHwJClWgFlBdc85vrr6WQkQUUmvVwXKB71Ll3gIv+lI4QDd8Xpkcn5pdoO/WNltTz85JXbcwN4YJWSvb3vtkP6waFB3UxsDT995Q2xIHwuiz0VBOEGFviO8XWtnmVwFyuY//aFDFtZAeTUEL0k7tE9duU+NOBLmmDtp8bLM6/OZc4HneMBhu/IbWB6/wKk6Ai5ydKbyQ+Fs7P6xldLUBOvULxVvE47sQkAqMW15iE06/zcc85x5PbiLemjjcnFhaZ2MrD/QPknlaKDjLXno7jFTnqosUelBWhAUAMTujEYEAbrtsMiy5vvqEjLlogMbVdrgh4I6DDGNifCf7Xj3wEaJhiBeUPFy+jsHiBABzPWxLTqGm3l9J53Dhq9NWZoMeio5U4DqTHA4md3oAyHeAt14w9LgoRWvN4TviS7f0xGDknY9N8BSCBv/eDI/KU2Gc1A5rQvuqdVN9dq/Mc8QpmCw8gzgANIzOIDM4kBjkxkKOukWlGQ0vRxM9+EfLxbf1+hP2v1HTsMhSSkuGYcxWxs4nMKpaYO3tiXO3sSv0kcyf6PZq3Ae5M/PMARP4S47ETcBeo/lGuskHfdaTEvWdVk5HsgNjMaVv/kKTTm3TN/4KqpKl9/mM3rFfLX0dDy1QTOnifFDAS3t2QJgDgSmObbWxyDJuVaknhGuq/eyHEuu/mM3VlYdSZn3m63OPJ+/YH4rpmZjyrcalJ8/V2C3Y6J0TE7p2k87B3uQohc2I8AApg2i95mES/uqD0CC6h8Z7FUUXnD4IKrWMcxAHrAktS11zed50GKu8WJgEZfyB4X8mf+GkxvLoSC8ISC8JWbuwGVUiXSdWJ3TO8nnABGhPg+CbK4UAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==",
+    "UklGRuAFAABXRUJQVlA4INQFAAAQQQCdASpeAV4BPp1OoE0lpCMiIzOpCLATiWlu/DZ4lNh//aSrVr238+f6mMqckx00Ck2Ec5BDECcuC3vIeuOVuv8HbxmCs5W6Ow6QUGWbGgw+4soGyJObmYi/Q8qRx2m/wzp71lMH/gOLCc8Lfpwj7Rt1RqdWrdNbV0TZJfETe7gJn/+ueCnUKEOAuq/vKuIKffSSZejCZC/rMkE26X3hCu6FB2HF/gxno49LOid1x//OApz9jtD9dRGOIHB2N12+8c3Y2ih2WXD1AqtUx2MKtd9PSL9pYMA/dhOdIjO1B3yxYhHY1y62zjS6VouPWCUsdKTDywNFaG1x2PdT1YC+7c56HWWR8GQav87ANe5j5Qa42zaAN0WZQYP3bUI80eu4/CozjYBNvs0iaLWfrRq/I6bmNYuGhJhz8vxin44aWpJF+4VXeqT5kD0AxcDZS1H5ZF8sSo2Y+BnFw0+Px1ivMRRJasya4E+IUx2j7i4YEIh6FH4FrB70MxELybRr7rMP2X2KBkzp0s6KvndtCzBnQvY8xIAWpJKWDBICZr18WZlc3gX08fHlMJrVNc62oMWQGXEECSzDdA7kX/Djgg7oMW3XCLFUZtqLTZJywQqZMDo3RH2sH3XDLvmCab4K+QVBZAs744uGoHuciadPtXgiCWaCFS3F7FGauRwDeobUYY4m9cdQVbTZJOghVEA6CGXAAP78Fg//UpTFLfP/19YtiUSQKQsJhPfQQwId1Rs4sbZe7SS1wvjlIfZ94shLejosVKT8rfqXKcPnzF/65nEsPDfiHNVQs6kVBIdphZB+SuK0nsBjC6x6gW10s8+a+512L0ngjugiWji0VPKDNHK8rIdrln/KPAbMM+4K5x9lUKPbynYtnbiCqIj/P0rYxssp9Jw9VQLHq7/7NEpqhm7Jb6gXUXwRLpvKsvQA8gCyo3Y7Pp4d0+8sZ9ycrv8D4ebtb0bm8yKz6s8YiuRHejgVHTY3ekfMJ4wLmbGl4Ja1vvitEKIDrp1I+l3tjvA0/TUp6fHxMs5O/+X+mWNxSbpLCwPA8hMC8TghDK052F8iF2M0bWPjmvAXEteYkkuifjncs6yQ/VW+z3ZDSMsm0V+NPNiqM3I5dbvocAVKI/ZH2rklB1cCeYfwgI8vlVN1ywlq1JQBTF2nLdWbEhgn/lfOTgfN55gVYxF2GcaGabmKghdOiOIAyS8UNeI3DZkbn48JFWMnm2x0AW+gaE9Bv4WMzsznofZy5CSqh3pJXINxS0JCrwChAmRqrcglTHAnSchVeeBt7N5BGK1b9g+2ud4v07JEQn793SI+qx9axgXjOEFhdlZZ0+epBrh9R4yI6e534zs2U3FKj0zj6Myd+4mqU0Pia+rikkTyf/J7PZvwdaQ80KuELmB3dJ3VRaNi6R1Yv9z+26nwn1ty2YStuIG/CC/PPPzA5r5QaoqmO1U62X5BH+32iz/Zk5iuEo7KoQ1S901PmP4PxO9ckfqGjTXWQx2RNwfT/4XUT0CIVepDIftdG676JXusa0Hci4Ioiw1EUKVZ7tBy7ZtKthbOsF/zTrwsJGZAPPDWG+Q89UxDqQZfmeTiraRdLrfDhdi0gYWCuMWKbQNf/FJ7hHoiT9QNk0ALT8L0nNKWA3pKvAkRpd+zSyB+XZDQ1E5jKFqHjIRCfPF8DbmXEx3fD+XwHurjuEBFYK+BlIC3b2pHOgO+RzY93ZJm/nVAB3DD2f4/D0QDFMjXH6xpEacdcFBItYQAcHGFP+MWuFsavh5YavvTfiGMRr08iHJsRgAcGxcT+gdyD76G8IX5vQTh8muFspcUrCFJt4ANo+i1f/gsV4FuKizOmW4MDzcA6PO5n7PYDS6Te/L5jvz0gP3nCdy4F2bZ8WAX7/l/leYI74imfehTXmbnNDdjcG1WFCreVR6jZGd3953VTMgYA8gAAABDEC5Sxg2kVOgFD/npvS0w4fSvFo2ABGykPCVVVQEq3XCAAAAAAAAA",
+    "UklGRtAKAABXRUJQVlA4IMQKAABQUgCdASpeAV4BPp1OpEylpKOiIvLpALATiWdu/GN2b9Xzx0lv/c/56pF56CwXfcsTfrLaLKnYQaWVnn+3CGCc2cdzjucdzj+5RnprERJ6axESemsREnpqQPRjkOXo9Hrbu6qQOlX4vUGmScHo+GSjciOIC0JAaE7tpmvH31eC8L9hhByQMd6asp9uRe3Vq35dKvJlyLVJ6UfbLf929LXmH4rqvc5+5LvH0bzBiJPKu97kItrE6PPkRCn7iEqvs6T55lJMGqLyokmYGx32DdswslvrqukM3e/hcIyPlhAmxAe27cuypMSYWPta9dWq553CblWD4IgfSEIqr7q4/xxbttrchxmxxRhIcxjx5Oop9UWxAt7kNdlokYymhnwuAMBmH9DWgYCrtYozgpEJ1ZoRSS15WU6VOWdm4PobkV8g4w4vRLAVxvj2wlPzhVKP50cTs1k0Xqorwg2YFz26IfMrLVgWBjx5FmFA0+Lzj73yUZCSDJY/RlxpWyEGJsbRVItoJjUtaISAY2aTbZmXO1inHFLXLme3kMUNsO4qsgcXRTki7OnDPnEQgvkAP1qp/HO1xXmVoEIZFSyzPqXujwDCzrwzYfs/DcbxYm9DSUfEQ9FSYUYDEI+wrggUTgksyMgM0qTT4EPStIUmzX76enr3MFHokVyjbJ3BEMvfkWnXy60ImNv9gm27QIlLH8zAdnWVkM36g5Kg5fVJUpvwW6zxDZ0OQcDzp0n6astdYtvNSZGPficZzH+j0uF6JNlIaKY0tRe9EAjznu6pfHMbSrKUAWqD/5mpWwmUDInK69nTm5lh7jQWRx6/n5wAdoSa2YqYS7qnE/pmSknn3zYAG2H/jsrSbkv4GPI0AyKCp/+TGhs3UR50T3xGY2QAAP70pGMZW1DNAAAAOktLlIG8R+QQMvvltVNYNCIb7+Hb7H2zC0sgywlNGWcsVoyhX67wNh86X/GNMDyfwOYsu9rJK4gQ+EvU0coBkWLLhl1QNqKUvcrq5x59hLooDNJtjHLDbE0Ng9Z0hUnrWV9BT5zmPc5yJmToWfEfBubPgzzliobErsq0T5z4hBTTxGqYLfCQKNlPayEMZHgy03Fo8WkfF8qte6SNlb78uHGdXpKzX5hRO7ta8FtkGgkXaZsivjdDwrwRTMN9AzOjiilfXg95o53UT7J8DyWP4hc0nG6vi6xFw9nZ750iUO4rXpMLc6hNe5ak8H2A5Ss6zYQRMWcnwfH4+asOxGCFY9SpBXZkBQ7BskM9emDL5ciB+V4s9GJhciPLqfLsQMVpWzQF30iJiynk9JlDEmxvUfej7ibs+1xCp4SerNirtAu2e7zNOCXpC9NBxontNEPUq6KFPT9BZTLe7wOvkauHZfJ9ySeHQqsH6BeUC91MoqLqbtLuIea4PrkpJxiHNysn/V3O3UIi2ujVWKnBq5+3ISE7zdF93rjA0xjJ9sBRG0LotYtetyxT1fwdGH9djsUXt026amQ+XBcqU5PHZWbmhV/HE2qWxSlQxbOxk+++xaIThywbHen9YLnnPxBuPaPdy/go34h3KJHEvJRVi8OLr5/bMBe4RbPca5GLarIv9KF5SBwgMSdH/rNKIr0fqJGDKVLOaXd/pP4hVRLzFsYwlRIiEbWOPQz4pDL2SK5NLmowvXfiQhxb8OLhA6DR/VeaBL/6RIKPD3buUqv/pi7dd/ESdLQ3p4vEn47Q3LNj/aAmCTIUXSRdKttOXspWBUjVk1PO+WJ5XMAb5vmjZ6m43N4vtuRDlGEkTFJqr4DcxzToViBfXS2tm52CAOzyVyJvMiawIwZjQQe8KU371lNSvNNdtzP6XySrIxSz2hCUzQney9RY7e3+jDgUUAILVmRhyt2bk5yH0migLV4ILRx1aiFi8oU4mMcBgIcV7815eq8wgceiSJAvz/1TydFVT02YWgELJcnLAcVmXijJ/9okCWtS2sU30tM2Kf7LH604SbYUcspubtHqY7WgHlLC/JcLNCMonrJuHOma/hrH6xBR/+zuTV/VX1FGpZYjElZvNtCBAtpN8nwnSArMh5PHxs3jEpTWHbCba8kVcPvztxlRbF8gTVt1MlarKSRgTK9ymC45lVIHVFBz8tB9zZSBqTL4v19EuhW+Ees8j1DAETC8oGL6CfytZZazpbrq8f331CafsuJKMYsOOP4b/zm50OXi0IiVq2YSVO/XbiKeeBFKbctnJcTNMyiLr48Np6bpx2TLLkE0xFvirQgwc/F8vBxSiUSWEzFNxOgrIDVyGDaN5INTF45Vg4PqXmBUYQhDlF6otZmVJ14UZoOOg7IHL0mDLMFtCrnrjxhYh5u8j91WvhlZZADIj+ne/VsBy2DU10c9aiW09hbrTzqAeh7fweuYA9HBSBrDxEeF5UCxMk3t1sw1mbGBD+p7bHVqdpHyee2/tyZ5X86GqjbHFQSfuArU8wtLgAVWvSYOTgUJeGYcDTejt3KByvlc9n/IYfB6h72WpEplsxDyIK3bvsBHF54dijnX/WO/YX5Xwx8JMhQyOJqEhwgeBqdJ/hfcmjK8gRcR/+P5HVRltLDSiLk6YnQPjHMM4fUzYh2lkZU3ZYA98w3M1Gj4Zwhjb13WLhHY58W8WbdUgxHifFt7h3vSs10X3zf47hIvV45mymD1eI9X+SCiQiCDkTcHkVo1+V6boKeJl0dBdT/tXsAmreAk/aDKcGOV4C3PSx8VVsI+CStOXMVu9qJONqykvmiB3TTqg1n5NUW+zw+WhzlLEcbqWo2M87PVoTS4H5OzKDZKFWtqiZstgjopUXm1plpMvQ7DiYhn0FIO2C5hMUS1lNyDB1Z9KEEvSpasjZC4KNiyB3nrOf2YfsZ3yQKny69ZtRz/NM421svjKm8/uANQbT8GAH9t0RSqTXhzK3joEI/j1WgI4qK0leYJxFlRKxIn2gOEDeSfPdOrMzut7mVGb/dUjDE6hLBeXnn+p2XH424ed8exTnq7z0TzSVIjAgCppwt/++37CV6o31V4v5u2h5wn+sBEeKiXHHvuDKpJG4nOcwJR22a9NzD9KE0Qod714zMuIS0dpE2qBt1JoAayrHLQFstHyXRt9sW5e4KcG5lQZg+qx4PFjr/QS2CUepem5mjFXK8G8CxaRpY+pAbtNjw2KQdJaXSnkwWKTtQfm8xcowxnwfY0M3ti+UNU7ZTCEhp9wzDql0EMLTujobh0jJ5Xp2BvGgXdiI++3S/WYbXNK3qVjmdMQAU8sLcpCZV9l92hjhPY/EC9QSPYEEOtvXc2HjyzxhqSZ0RG2WBz9YrUpQgW7oSaa6XV2Cff6T0TUdX/4mhpsVnMVYFwtXgg/SoaZslDvAAIasq/a5td/erpxVFqLADI4o/+9YAhVk2BXZBHn24hl+25yH1yfazaRQaZKx37uuihbK5eTzSZkhU0qiml9h4zZbTfDHQLpvWSIIIfphh6ePIW5Al352Yru5SUOe8n8hwi5BU6RlSUaeUD2PO8eRcDipCWQ0qyVqBUMxSJmQbuQhgec55osY4Tc/eLlIZsxzSR7oX+UMCydWl6B3lF7P26cCMugPooWYvjqC7ky9iMRbFb8BIhpcm2TwH4pS+GwWNlEro+AAMcJoWz3rDPZNOKJVfbW8tpUDSAijTmEPxlypJEgvGTqRKMXnppL41dVRludEHTPc72ZgAAAA==",
+    "UklGRsgLAABXRUJQVlA4WAoAAAAQAAAAXQEAXQEAQUxQSJUAAAABgFvbtqqq/xIIKYBOGGQeurvDESw+HOT/FRET4Pgf/+N//I//8f+PYksrsYeMPh/Vc1pXW/WctvlKPKlPe/GsPquntTkUtqhNZotziJ6ahqlaLhRLld7JI64SFp0NW51+u73f7cyjf5FAqDx7PS0I+B//43/8j//xP/7H//gf/+N//I//8T/+x//4H//jf/yP///dDwBWUDggDAsAALBqAJ0BKl4BXgE+nUqeS6WnoqGns3lI8BOJZ278U1jXDBt8La3Px4/zgNpr9D+WPEk65OjV/Dmf8Gftm+IHqA5mf+m/W73+fy7/TeoD+Sf1r18/RL/1fMz6yv0APLP/cD4gv299a7UwEcW4zXP/1+8P//4gT73bwZ9yf87z//o/2Zzs9PT/Ek2tpEi/afajFNNYwWkSL9p8PoExP/3k32oH5ibVIkX7T7XI7HvPpxbAHkGqev/vfagkaCgMs+5S89B2X/zRc2n2oJGqQJ+I7JPst1Dk1mf7gIYIGmV7uAyCPGeLs4+VcGTwGBcnTLz9vVOQJgZxGwOjiv1k8TgHXwICrUVODwslKHyB4OS1r+WiJoEnayfIecaRGmObC2LTZ703BH8XdbRBifo31W258/OyOvhkmScgyfaOCNQ2W+xZK/Jj/NUMa3bc1UTkVpUImwCNYO3RxG5Q/OUXdJdVgzqszmwn3c0WfAJZB9BvpdbvpPrvwe7MhS6RoGOV7AXXmWFrZXJ9eF21TZsLxy+fnE1gu7EY1tIkX7VoANEJP/ievSGDD4CMYVMbTaENHCgYB9kol51R5hNJud3ijRQhM4+zd9iPQDEWkSO9SldUbKujzFf2ZbocaBO0JCd9AlqXYR5lWq7KU1WA6f1ATv9b1D6w+3DagScdzywyiKNnqFknyuL60Wfz5Gkry5bG7fOI8vRP9NnQFqHVm6kYihPIDakxixsb1XiTbI4buJqe2OEiFyuGaCbuDErXVjxnXJrJT0eQYUbsEPiEvwfEeQ9ae0F49KCYlLEptmii3ODmtlHpci9WeZJ/ze7zasy9460TSgHpzL+7/Hnook4OEYkl7dCNqWsGeG1Z8UnGxpSYGUsBZ+3cfNW4AkCBsxAk6M6TLPQe++x0ARGcx29NaJCPlZQKjmrZ++qkBKsmZOI0KvU0rWpVBOMUUhlUkv5/PNIPoNraO3vXZDiSGShuUFMRlYIdipPOBIzxKm/i/CwxraRJkizOlkj+sTh3wt+bUQZzEhYuv4CCsr162U1fGb3ebbYq/nwqsj5pinC2qyD7WpdijfvGLj7OQDtLh+pEi/qOwhu+LGaSvzzkgrtPtQiepQoStlFBSpD9nd3+b3ebq6Ox4Iapk6u0+1FK6xpXp9AA/vqjf4Mrfwr7f+lS+lODU0BEe2RW2fVTSXIzSdJ8yu+EAGmS18D0XTX/wUvh0inwHV/+Cgx6DCJPB/1Vv1BzIP/H/EYvYDFnwpei7oz83tsO6BPehgz/b8jSIIbP0+SKzfwinPYs2B9Wjfh0iTCy5qyMEOtHNkcYAAmG6u+5RVdhHZNBewC3D0vP/Cx4z5urfH426vnbilv/Vj/vKBcVTr2hiBeaSSOCWKkJ2pz9K1WOxixeQfGPCWp26vgu4z9rVbK6JLQe6sTGcvYIB2wlB0DjqA2AA8RAS4YAFrn00KoNHn7kAWseDs33o80z7DON2QLWcy5Luz6OfBuYAZbUvPVfk0Ys72Ke9goLw/WnL0H9yNKIQg6+9q0z4nUyOFt/U3BsDnzb3ucIzSaNkeFdxCnJkGk5CabaIMw5yVE9KA/bHi9Y0ncQmSGHdJBYhx2RFpTkeJ9O7y3Y8TYFKN67SH6PR3KqyNH6uHljHrakSfQpPcvNc4RO1h63xSYT7INTnWrVXu7NxuwePr+bcK1pOV2QYE2wiHljZIJPGJK3tjY8cr+B0o088W+qkxfTH9rz0NrMagOKfdfSigJIVuxJzCkH5gb/wbasjnrxZHm3w7kF40p7ybj0GSmHxL0gvrpEBk9gMTl7RYv49VCWFd1ccKhfFfY/ka8xnmXsC6v17FNLhpOQEhJT7i+Ch4Fsh58EV07t/9j6v8AkqkEi5laZ0rUaD+0ww3Q08jHATlrr/dumDq8vDolcweBQobpWldFsW5ucJXlXeB/i3TQy3QMCzA0Nz8+zXwK42kYJxt7XAhX34HRJ19VSNsqZ7BoRQTt+SKtkNenPR7tWSmHP++/xCR1GaocbJZJ3WyhaTpxcQS3SINUR9tRtuaqJYs5Xu0s3QTLYxwMZ8rJvgrhh4H+onLoVWY9tS/Y8j6Ty2VbZa8wad7RGpMJMx2ykKsEahRpnmfZ3Kgv/x6jUWagsnpWQh+DhYLaJKo+CoL9JTFybNiOkklP+R3GmjhmQov6QPvtyYb9rbxn81bUlXQoOOUp72CY45jepddR4PcpjZ1Q0ZnlWKoirdHGZfjsmOSi0gOM4AqtS95pbXNnspfzclCEoyv/L+oZ1m9x0eklTPkCCqv47RZ1khSUCy1Y4fN6z5Yy6bsTt+gIynfHTfD03or8Rj06YGa+t8F4aHAxe28ydP3RuvEqljWd0AMb89pSWxNU7JXDUko5PGRqyz6nXhLFOZ/YhhbyFt5dSi0xV8UK/rGOTZCGhEhi5hwumwEZjtoGqHyRsPPw/J1VBSZ1RQIcHnBSGVxsfC5z7ytyHQW6ttFxa64rCLr1fmGaKMsqx/bSCcMbZ0+AXH0hkrJFUsxYp8NKRcZt/UUN7nRCotAvMmZStEohrALJfIKTPuBghO45GKx6SsqBJtiN2Vch4X5+n916Y2bJC8Y+BaT0AWqnrmWS2H6UgYPAwAnfjEVqIAkiGTipoD41Cf3wqJm3p69m889rirnsn7x2awcw63ul8Qd+izc3XXH07ObwiuFuBazU7ecosyy1YL6ThMwg00gBhhx8px5aP0lv+MHqaFtgSprb/+7UxPrb3MuPQRZaqBmrbQ/odEr87j6POtd617OfVUFXlg6G+3bj+SXhrgedRwwgjsHuXNgpWGua45ZhqgBfwPTg09JvUl6w3a4PZSmGymMrRukVmMJdsXsjs2AT45oN4bwyD2Uhfx1+w6F6a5WM4WSkbybil+1GxBX9tWGumC7MhsmyuIt9L40oXMpJEYfN7KbQJA/ih0IEk0I4jKzTkBrUT0ZnTYTICkbnWY8Q2hPxIJ09oRa7gskP0fDEDMbbx4rBvdEMCy2GMTDp71LKr1hl17wpAAAhG8lqtBQ3YLK4w7Wx1/IJkDlOKB0orXE+OM68BjAPhTKFcyRwPSNpKP9Owg1IBxw+kkT/BhS8M/hxK8LJ/tlu3m0rtPz8jjiXsKRH8zWwV8KG2vsh/O1zTbJWl9BuIQrovzUznhTOuVPwlE3Oct3dgAAAYWNZLbAlsUZK8e7u6nE/NvTj3Tf+NFByxH/xutV0UAAIxaMlFas4Zy73c4jTq6xseWIMg5o0/tvudwwDljRJJjIJuMJUIDKqk4vEAIhFSqjvBz2UWltxzUhtDo94ZTpEWnQQNXDM2so/Yman6Wvbw2dmJ0dkzTZti8GDv2i20sFWh4KIi4HMSX/u+2h6z9m4TRT8OL9C0Yj2YMPp5QwLn3P2yxksH6NiWXzR1fN9mHVv3B2LTYo27Lm/ldhrb3cPYJbsoEYCgeL2/88hP6r38mc6aimOm5KZSgpRiVMUH33owJFHcy9MNIsWtIHtINgTUiovuybMAQyJoBXJ2wa70kFiy5o2h2E3qbJSktPZqxg7ClAeu6jy9SdHOCIFcMB152tfdOsV3uABNvQK/ugCvUiULIsIkjSFLvlkUQ3gZVucb+iUqzGSfJ1Wyp8To4tTVY0M05zPdjgDMkDgLUX11yeovGSyrI5tEDJN2oVTv0FAUv4yxWmQmkbHwBDN1TZZf4PPCgmmRNASAFt1ACrdGmJksfjlG0A4lO898qc2NJZpikSWwFoUguAeY2lOQl9iX6/eLVHgnaKEyYuALA6WDgAAA",
+    "UklGRigGAABXRUJQVlA4IBwGAACQTQCdASpeAV4BPp1OokwlpKOipJUZELATiWlu4WpQ3WObGJODv+/N9WaBA0sIUHKcelgoCpi7Kift4V/IRlsMdcv8g3PEx57jHfCszZbq4pioaAaM28jpEcywgCxeBzu4d88rs5dQdtZt726ekd4F1ev+41rbZfah0nktA4ycpoHt+qWvf1fIvkWy0uT/SZc/iXaaXEQMu+gnNjEgm27ZR9xkpI4Jn2JJ2ZxxLb65tZqoDOSlgIe5d48Jx85PnTeUvWhD1MO2ebDpXt9Ipaf4Y6z4T6K/NB+UDvYQeh9UqQJPA56talbszWjgQt5LxslQYbPk46WUMvCwf+0NTOpe0s8KC1HkgFL/reAGGIMzYvKZHad3M34o9ivfmz6VqRf0EzfoPso1o5ZjcjfozDmQdRfgOjpVbRhWO+r2XLOd2nFpo1JH6lzIKwhoqs0hesQ5UzO2sb7VpokxgduG/d3CECURthYTfydTwHQ4x8GRENrp+ahpniLZqUY3MivYkATUxDiWJUFdP76h4z4ZyIJjoyI7HziPL+G5bcNDl14BZPVQZSTNqRyoKK+UHJ9mvjmvoz9yVHhYQzmB6nMD3flxOypPxlREQbPlQF/X1B7cAxb/TFcZo8J8ByF5KjFwOvsxUSgIQpWbj+OMMU4kHoRcpGdxNZvxf17qSm4E2bYtIdgPRI+ylh6NF5SEvXLoeuT16bKeX9ukduuFQSVHk44Kf/oh2thIzIZpL+3SSf26ST+68Hkk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/t0kn9ukk/tEAAD++jyGn5WIVSVBflNHvO8Mb6OKGZjV7IBZmY+sbCBmy1csd9WxDiomIvXSAw66wUbCIQ9GJApQ+EqnBUGc3+2LDpi2Uov7d9tbUvgzVJ0B7ix90m1oUq4uGJlleaGTYhls0GUnhDcXd8zA8TngB/BkXYmmJE5fnI7JzYSwav83048GdS2RvYFcOexkhcWBFQNN2oyHU+oIIhd0n1bMLKZBFesHAA5wbqv6gyc5c/akZRpzj27e09VKDRSEjFpnEoSbs+0u/4eGTYwCjoJ26fToBTRmqZW1Xo3Gf1LgP6+pcM3BeQQm1zRtCSR7uho+6T3/FiR9gfAniIWZL82+jPlT7l/+1YjtROtnIdRtiW+XBFeKpCAAJEXXKuNrkPlpXv61iu0nhJ6l80fqxvL5x236OmBZ0zvLZWSuDhttMmSfOnXkAAANQWVhnqQeC6mzXtBuCY83h18SMnyFLPhgAoxa9d/y8vb8JDiOjAufkV4TgZC3sQT9mkvm0wRFzq6insxPmmx571/LhilgY4KnmCfwNqUfuqtwlhR3gpyFPMByce9HITNw8nOg4HNwfb0uKUw3lcWYiXMaStMHAMoAhAx+e7x6P1HkmLLc2gTGQPzQpIu27tNzDmfMwEaMzoA5jNI7VBf6N4SrXFARygqosF6rEIUsUbPIvutIPKNt/1wTG0OoVJWtFTiNpw19oAHEXdqUJFX0pa7P7HqR/EnLuBxA4w4fDWpSTQ820yeNhPVkm0opo4cLwTdJQB7FYAXzJfkp1SqkqwB74egQfJMk3UCilKtp5kD2KQkX/bNUI7LWk0SszJr7UvF5Ip1vU/Q3eLSW8f8OcmPYqO4J45fjGaxw8ibCMGLSNeXizoUqf4z6ETM8Xm46mDSN6lywUeJePlSn/g/QEP/8RsWv1ycgYp7jgcU2oQgWqx2B4oAXlWxQZVa9gcvIDEOCpfLVfhIxUJrhaJAap4AACLpS9FeIk7fyqWfs1+PoeE4Gbm0iZoyLW+q37a3zOwsEn/4/jsJDK/UZ8ZkO5vNpr/nBJA/sw1vwrz9yMDQy8WDWHSpzXIRHavhOybzqHLr96lpKKssrWDAdYDoonw7+0jbXTSLAErDxoSpFSK7RbP8BbSRafUnnaf3WfQPAMJDyJRA4PBVZ2GZ7eOJZZCLACEAs/ZHLNW4Zmr4E6xn7auEuNeUkV/MJeEWf5/NeGmPQjOkAlt0vzoAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+    "UklGRm4IAABXRUJQVlA4WAoAAAAQAAAAXQEAXQEAQUxQSMECAAABgOW2jSXp/kfeTpwGpmynemYf9bWJCAdu20aSO7MYnKvEkx5fuA+3jf8a/zX+a/zX+K/xX+O/xn/9X6PSPzjW7RwPahtHX1d1cqVRtkjKXitqDOfptKTOquPhM62H5qDE5Z2RoM7nnLx2633mOWHpyuiQHi/AeVePmic7qGGg5BI1iPDkwJzvVAdzXC8B9CZ/9J4COp0NrpVs4PtE+zY3g8O7Fj1m5v7+NdTvGMwzJZCJlxK8+/dJIWG/pqjywDPJqjetjN6/hvq1g0nYJhApf7B7R4cDh3P3E0gT7+bK6K1rqE3E3ymYnZ/MNE46ikx+rdprfYaVcC0juS+TOqTBgH2Gjb6bShKuzQjqCSEjKmb9ZsE0pUROipX+KEs42HA+p6/JpY1GPlO3BonJJWNjZcSZMYT65REXCIEJCWVhAebb1HpchdogPgymWZg9DKbL0JuDKRuMnk5qRNC0oCOyd7FKbZXvtZC2L2MgKagVHmejNldG5IYWHqFoqYlHTMypc/2hJI5h7h7xaTAMSubXk2DemqENQo7GAAUyyCJ2sW8RwW5HIiPMB5BaadbN1LIz/ZdOL7vxQp9zUEJIjD0h8adiaDD31mSK8NJF2gbTIaSdKaC2goElRe0F84bVHiquoC0jeLtYV6iCbs0XW1yLxqD5+xcWsYG7rEjalCtdpthzc0anRvfqAem8QeQoHJ2EVFLnYfOfGqZ8UCb9YP7SprY7Ol/csFaZHPikcYZaRJjzsDXIP9erLfu+2D6jKyKWFsAVi4IRCYAN50vEACwOSM9GcDw0N91Vyp5wewTDBeFowiFuKSKNP1y/XOGSWCGQYLJEEWE+tq54iZnPunzY6ne1QAhUr3R+a3AD2rJK73OuRw+3jf8a/zX+a/zX+K/xX+O/xn+N/xr/Nf5r/Nf4r/Ff47/Gf43/Gv81/uu/UN0AVlA4IIYFAADwPgCdASpeAV4BPp1MoEylv7giIlOqm/ATiWdu4Wqff7r/vB+sdd/dRWjHnmIqz2efyyCvzZvdPMV52mmu+gBZVgLDvl58Z6vPjPV58Z6vPjPV58VFBqaHRW5VORn10KpoSIuSg+Rnu5cMHFQVShOeBycZNJAndhu6VtILf6aATOnGgt6Z040FvTOnGgt6MOjR36H4S89p1vlLJYy88hWXBZoyXy8+M9XnxTEnPCkQET6zYz7G2mDbqq46mG3alQeRatQlx8vXI9D2JeuR6HsS9cj0PYl65HoexL1yPQ9iY0IpB96yhEYFuVTkZ9dCqcjProVTjWAsoNQ0IjAtyqcjProVTQp9dCqcjProIDJGSVXR92RJLw7ouoRGBbBORJLw7ouoQUYgAtyr/m5NzWfOhVORn10KpyM+uSlKX623kn5yUWaMl8vPjPV58Z6uwqc2WIiS1YuhZna1cmQYOFsGz0YpB95x+YdRgviWSCMZKUScmKBP+JOGClITx7P4VLHQf3yvwAjJhHdP4keQ3R5efGerz4z1JSfGhbfFio5AVL5X3/yE8gJXL0VvfSQGfGerz4zSJSmertrQh6nofKaADtLAIyz9STJfBRc06nvVaUEvlftVueADjEk5PomFTvPqsr7//ITZOfFpg26quOpht1WeCrsp3qq46mG3VVx0IAD+8i44Dvf/uDuPt/Hv//IMfY18GtxGfVMIxbjatUcEzZNzpXGe/MtxN4de/M6seIeNwRl8rSe6SkDlE3c5J2ycPj1tp0ARFHvn6wTV4kjeIhyTfLmFXT48tIJG7U0Mwvx/ixGX12s6JI8BmcfOXzphPQqJ9bijzeV7bIlZe4tU1+dvNVXVttNGy4pjhzH5Maax1W336zFdAYEZ6lbaRxf9sSg7fiK5TnWQZ/F5lBaCNZ+3Cnps0SE4flUmyVkD71YnF8XfCnk3k7RQqzz6k6IMDqvUmLLil/fihJYlihUXbM4iMV/YgPyLHp4l6KLGwpi+9ofh/ZMKVuNs6xYGpb9TMKiD/FSCF1/FeKgoDqE+H+v74Jlugpl6EZ9J2ACV8bWLHWKJLNxxGNQnDQNgj2Fhm/8yOt4dG8kLTA8A8E07rEyyLuj8hIZd8OPwhECMgaxNOiyCtrkDRM2m9u5VTVvJqU7GfEAAAAAAR60+aavA+nf3zzW0dZAAAABh+sIXz2yeXFXO1VGaqjNVU/dACQt6vbvrEOPFAWZOBXdAeYVVgpO6Q8xpl6hP7Cr9wEZ9ihYVe74fDW+M/c90Ydu+wgu+A7W4LtyqKIqsALh/kV9lAmSYzGAvPqX8Pi410OKreuTshSwPgxWN3lWzFSjxo6OmqDOsRKsI1rRBqr0fcXov7aIad71+Qm2e6yh1OrfxeTT5J259d5KtL5D+Ds6SVYYDjWIzh40ZQgeHoDgKogfTQ61VbNFe4t8dtWU5swg511JLYXesxIFSNeARDAQBdfE06fck5EakDdux+o5V7kM/VAxgXEUUq6iI4EQn7I+xD5UWOVDZqmi7SSzrDLK7GkPipBYCNCvUshIchfxOhFxkQzpQPVDlTKeagyLbXjHz+XxYU2ZYwHp7P/ivtFLdKd/zsu0OHEc9UGPz8AkAh1nmWPoTZ/WkRy9CpmtbRjj14ApZt4WQpJ76KY360hnyZmOBD7YWT/GztoiSTXfT2px9MJiKuted6cKFaTGwy0UPxzrSj4DnUG89jBMJwAH9yAcBhs7NPk18dtQt4faIFUUhR0b7tfDe0w08gTVpQAAiLBhJRhANuyQNAQgXrpmfdOOzyBPaSfW0AAu4nN5GpW58OQe4R635yiYLa1rW2Je7XFekc4r0KpwnLAN1sAJMjm4+b2zxREAAAACkVTAAAAAAAAAAAAAA",
+    "UklGRggjAABXRUJQVlA4IPwiAADwwwCdASpfAV8BPp1GnUulo6YnpVENMPATiWVuxudT0b52QEYlqf0A6U2h7zv5cdNCiyAb9F+wB5//Tl5jPNs9Iv+H9MfqmPQA6YP9wMom9L/7XuJ/4nMozRrlv53+c8dzLv5R/7XqHe0PA92n2+fkX7BfgfzZvnPNr+b9QLzv7/SgP/OfSA/3PLe/UMnqksFsnXr9MuYX6BFE+esMtxhafsiTJ7jAYBBoVa913E9Ggg2diTRX68Ak+InbWsvkpk/URDqbPH8xiDvcx443fpYmqibysHkzQBmsG7TvYRffnCPps9EfDv2Fl/HrTeOQY8xU8wJgL//xpVB/qlKaDXMnqlSL4/7lE+TOUc1/sgCw8sEjxFkkI60ePab67m2U5UzxqWAFSxrpd1Im/bnkMsrO8jGofWAFWHHi6Mf5CHHASK221KNyJSg82Yw/ajp4Elpqs9RS/fk2lvlQbkmVnoeSNIEODDAIo4ZUtw9S6Bj///9FGmvoj1mpRPG2YGbqLZygWIY298S2Jkw3iVB7RCCzkhBS6yvTiawLjyOwsod/0LIrJlUPTZULT7xu4XipAUzd2FKtY9/2DvJd0PsQ8qRRpgXR06RsTe0vcn937zrw+GwSWfjZBVrKlK9YgB4V+p1Wmz93cCbkFEpV0t1Hcu7IDU6cKYpKn4CAFbRERXp44o1SJQN98Cgg3wBqvkb1Twr0mh0bz5IvFa+TEPyIp9+UTCnTSyNdLxoPrHHMdlYuh6ltDkXlYQWT5l0L7VtiyS94SywvIbqmNQNR3as/fqiKhV3SZ+pNGLmf5g2ju3NLXtm9lvylT5hM0wGyQVewnTm0aioe30+3BE52H/GNKvSvtMF+EI2unwQRLJzmrIm8SUqgqA4AMmRlcmY2kTWs1gcyR5T+CjsYl6UkrVKEd+htqgRBKg3ZgSr4rxaW4WAEidQrEKatWRhNrnC4mH03NxnPecxYe9YVSQNEPuT+J/ity+/SZ6goJG78Rt3mzJn4QHZaAo1Zg3zbKyKn7Ih5UraRq/cRcPzQPUmn4z1uOOf2dr6nfAPcLbHivTaAT5s5hQO9aBbEb/F2R+1khcBoEqmt9YmD4HuUaBX+4RYMFkQaA+AH8X/1iXoDs1Txc6BjB50HCxUTTAQxbdu7PzvoIe/+flr+//i+3x/48s0ecQI+d9RLgb9rTsBMWuYdrNFiBF2LrYbY5pIpRLlNFgUGO6vUYmftE96ZWE7NeOYtGdslMomvJUyDIkFXWFXrK6E5DPiVbajzrJ4A2PkGlNnl1pXvh00hOjabTIsT3woCzG5YZ3hTvIsgEA5c3S9g8u+/BrsxXv29siSjbnFaz6Y3YF57PmxOhyNXrx6TFRMfd9zkbzcCp27MPl4qldIa972EsxE5JJ6emc4on0l1btUlyODLhOAesnvTqKbypcG2naLj8zA2fgB2fwOuR3QnMif5kjUcsAFwJbrJ29/OxQ2OM6reCLE30RuUN3vE8qyKp+y+t7waYt5dPevC8vo1wWXPrWUOUTJh8tWlPQuuejm+1vj7p6v7SKUuckx4iLT7p361Ng57CYIL6XbdZCsMq6CAZ66+wlyOagxsYvA7g6Wyr+rgy2TxuFQeMywilnriDUkJDJ6MEzrd0xpQXGd0rWt27Ei53wiMI/yav9Di3232HZqyluyCgqvBwCoXADSJ+c/0WLI2OK0pi9mrxSfXgJdsT9+iq/SmIp6s6sK/SINhjIzYubr1nF3IfCn7vGQ7rnPHIjEyAEvqejxxmTFvDs7fCDs4tYD32GUP7Ss2i8v2EXAIqKm684//if2daOs61V3YWSBnzmvJ+XqE1T5MmhpuHspoEyWVsRLVTiE2/XMsDK5NGCnV8e9Wppz5LmiSkdTlBug2fVm2q7C2N/QJdJYsDCizXsNisjDAXja1GXyv6x2v17FTMX0N4u1QMXWpFCVnWuMpzrS5r4p0bYNfInjgED4fBnxBPLFkftbFE3reUqLpUQYYSiL5FnBz/1XU4CIenNjqODKXmcuhV+1rS9N8Tf1rHnvrXn/1JgGXbdIZb4yxvaq1UmEpqIm+dYPGEKOuMhNc30Zmi9m0Fox8boodK9CkwJCtUbOAAP3VpDRiEl//O3+ofwyBflph76X+DRqJ9SRcZGK1IPmzGKpl88xUovxuRxz+HBmV5lGvVZ7i7P5x5ocF7UUEM+XACOjZAT/FTRzkbbQ0qcbmp5x8cIhX9LzvQ8htzQe1cFZQ9rfvZTJIiOmY7Tlqv8hC/LjZtnqt1eh4y9Z4hKbZ71hScgcJenpbOXScZ0w6dbm3vv2V07gnMk9OhenTvp/3X+2YpCRa6vJetPUi9kL8LzW6WvL+V0tzbiTJE0xM9evvxYf+QlJWmhwhOGihkyt0bSBS3XDxph/VWWcDMVPlRVfhMCcafMwJ5vzlWJ2Fey8tAFtz4Ej748x3daIjtPaMVwNRHPZ+9yORaQnnMl4zXJV3UmsI5rQsHrPspZCva1ryN5awh9Ktf3wgrsYgSzdETRB8/wBCb5QxhzBzZQZ0HMHI1d7Jh6wpt5fTbuE6e8JMYL1WUR/6e5U+cY+x7PQCP9gsNhFiRZFHuaMAP9qEGiqnT+x465jqz+MfFF5SG19/W6C95hQDYJA7ed18ythf2/bmIb4FgK5Neanzae34QYuCl7kBEQn01G1ncidpGMfr3IhVdbs0Qq56fnhJYoWLQ/VIqVhNeNBKtjssQuDaevwbsf8ci+kQIhobLaMV8Iehyt/RmCsOgex5pfvaoFXjLA7O/d1pe7nx3dhkTP/NLoc8e9fF/NeAruWGGfUlqFygLBUNZ9NoJhO0vsi0ryaBN1M/8yyKX/6BuTvWpLsqarxfZiZaPdbYRa9PjIac367l9+s3GI7XsMsU8p27uH4y93P6ziyD/zyr3NhhBAK/j9zYrJReqlns50P0tjCPqHMify1J1tQZKnAhBsFhhaSAXoVItVcH5goijOYCemRoGeeXjI6O2W7aMEiejFQsLkurVOBC3cdFaiJG8LQQRGncsGBSXzKe44HPSLWCKTfM+PpOrdonIlgH7tJXKlM1735FtCcw7Qr/PGG1dUKCYaXzWkcGH/XZaRrMMRiFTOhwS7WwSgFFnd/D7jdBVw8wc5E+22VqLjVhrFtL1PW7wYSrVeEzmKrUBSnlu+6NyZX2z8syPVWIXzOEbzU4sz24JWPqICzCY1fQ2YUt/93E9oWg9HuenIGGnlp84m/Gg/NPkRp3mCg4iZ/PCJGzpR3ZSA7W6pEYH3mIFTuiX1XJdsz9W3c+LbaNjQpffM4JZ73Z2EyQVs5RaWcwGPuLXCgUnU7gBjupUwmECaxD6h8B4UHDEpwBPINwbPlDfuBuCY98+HZs+YqFxohg96s/uiY8ZeEg/HsMpa63Q3jb+2JSHeT+zSgA0aoIXzohVnI4nb29VrsLcVSYIol0FP3a9w+KzpRB8Mu2U9jrVKwufbgf2AxDP3j5p4sIKNlUJlo3Oi/rrCVfhyNnxwfgCbx/Gbqp9XEKrum/i5iFeoxuRgwcDo0ISjegkqOvsl/8U3CNyZoGxuhuQQmUzO/5haPYgDeLww8gzOUGXrqTGVG5LnlP6EBWBcVvp7IwdZEZdwt2oC3CazZe/2pDsKG+5GkIEeeC1a3XgjiHX4LJEuWcIk5coT+2+nv+82Z4QW21hVoaAkfaZNbhLDlSHMqnVEh0cqd1fjzYvN1uBVeqovCVuEKC9s6aoP6Fokpci9jog6Tfyq1GAxXq/FuzXq7q0pjfodWhZTb8y99ljrxrMHmmH3kuGzQN79uavJ/q7YDu3szWtmiCC+8PBFuyM8py+3SdqpL5V4pKS4sAve/CTvwaAk+wpPnPwZmpqN8rOzfbbMSfTYJM9MTMFuDuSi6JnDBkkyXI//j//oVX3kHL424r8Pu64t0LmfDlWkNRs9ILYkdz9/UK9X7vFR6ES1NIPFcO7EdUGPS8914k/XB6O8ydmYIEZ21Ww/vJfLeD58UaobK0Z/pxHQ8CLbRYuun4DwooX2nLKWGAhj1f1KcD9r80Ke2GirtFUQpY0Rl6anHAhKxMXsfWDlsQ8CXEL0QmNlvGhFVIz86d3Zh+qdTEsKmFVUf+vBYIOhfmPqXMuE5F1nWbuNq1in3XiD/I4K0m6/w87QGKzX/xYwDrOjJtia+HujaU2g8NJCfiWC5iMACs0du5hG5RnFuAn8aEREShqj2niDzbUzb9EFeVyxxVDcfU6dX+J2AFp1PF6bFp+eILMkcX3zxgdlr9xu7k5M2Q+rrriIxN49OtFm4iVPsF7o5En75vcmc4dTxWYgNdyCrzeCE4fj21psKXmaAsVaYzBp9VP54GLH5QL1xHTkiic5AENpavyturzbhVFdKCuvyu3HnNjE802Xb+D61QT6MjDvC+hJZfna4bhalMRaKiae2HC6+rLZ8PJ+h1Y0GzjH6LvY3QmHMXoMovejoaqBQDoP4Ep97nvYN0g6MjzwvCR9wtt2m2QPMGU/B2tkF4mZX7Ev6aKJqOTxKV4hY5DtLbwXBA9RQdxegrhIXv4mRZte+UoVadFZmnVnx2vqgE1eflJjYkl2tdWBXsTiEdsLB6u8UouYThc01grLFi+ILkuQMTtFCzUCepG0wlSVOJREimDYgyqx5MtXFN2JgEm2DSYOeWOTsZxj2zOO7LZCNn/4TE2lHk9kKy+U6cj/bQBcpTm3wNcRfJ8SvGUgDd7d1SgkOU9QJm1/yZVJ7PIaHYT88suFsR3FZR/iMXp673QQGZ+gLtT7L1mVeyecZbTa9xd3FCKj9Qy0RG3LKyOBP0b1qPvh3zx/6EIGM15TvguxjD2g7iiobSwWQfvOsFfI4LZKcKLlgMF4oPcHh3403tsynKsyYK0vrnxmxHgJRi8ytBuRt6cPgEIObe+RYpqvCOmRivcwfZ0qp09GcuIpuwjSDBM+FSQQGg8lH+NCHkJOBLvxh5cHTgaWIIfCjU1koHQ4Gjxt9jAKzQlVjl5wkP65FC9HKz0GZmKmKI+ciF9Fe8L0Qdy2Deoyl+TB9I1K0iKXTmkMGK2sdqWe0Azb1st9Wya6Vn+/lgXXkf2liRb8UHzBuLufOcwo6Sfca+mFiGvrGog9lq8gqtCu9hCERItuR8RymfncLuyaMHEuWrAQTwFkWaBg7woj0V4xQkRCu4FJjwiISKvRE26nqBxxooJjk5jOhqQr3+c0f0lQe4EDJzKdn7uu5UsV6FzfBhntV4Ls8rqTRE5OSZqjWaMA8JlbrTTT2ERRzU+88Yjg2UTxPeuSNEzIGVjyq6EsmHvDPIM99yMzycmh56nWixdcO2mEblxzDDitwt8EHMRd7Am+3CCfmepXN8W1MDcZNuPiNS1suueg17uLkA274WU8M+unHlE/fyuWARVQ9gvjLg9ksGpuuNzFM7qJBpfa6q4GHcNoJhg1GuvFUQ+TvKsUpokLQzQP+C5vkUv8iyunyn+7bIHY7gWTsU08fh3dDituSj4JHjDKm03RSt8ugNJ6lBaqZPDnUoCSJ3rT1Dx1QCpXt9J/wVNkjrpZUlqkRvfuwWn7g3XRf9AFx9qKIDikFSARpgab8ukA+kJ6B2iuuq/GnN0ZMlK7Gv9pP1gBMQBDO4V+yl22jCR60s0y7Jd0ZllV6ydzlIKtIJaizCyf0JWE/ApUdo1h3mmhnJ7fMXWo+Tcp4V99fOQZhMH0VkWlPNbTKEJJR4RnlpDdCvc8ciwdlxELyCFSQ0OZgo3mx5fyN2wXN7fJZtN3mym1WZ6vBwr0hV9/qEG0BU2yMS3sJplNYD9kuU/sR3oH1E31n0NuqqTafpIRipY9Ft52fM/99H862+/3r3MaEjxTHZPD43Qei/3jb0yAJoXxX6bKMmX0p8r9A+UGswbmRuuwWFRSByM9vgSJc9EyCfu2NwgbRWr+LIuOVpcTnoKeo3HmGw2SukbxZyeM3Jad7PgGBouEP7DIqIdoSQtgjG8bfB7cCVZFzr9I6G2OGaEvr9cyIb4lv0XeLZQToE80bCPlvqPpQ/kg87sh7ubtDvt5tEKgfRxMCm7wfydaGkGaP1ZiGoJtZ5JlPrmwrd0Xek484H+VeU6nDcHEYkCOCyezUVHzeaaY1Ga0rUthGbfHGxUo0cAdaCBkqe2gY2A6EgMdAVdyvmMW5oC2sDJg5bzYDKLFw2RLJjUHF0zVD5/VvrrGpvbkEsQ/NX5ZOnEEXuGav8SVJT14eyMqIS0/JIOOsLnC3Xv483FNCXwyDsLe3wE82P6yg1l9DnYgHDUgbOCnz/Ah3ebMkrvgwMm83U5fjgNBANefLIG4aDiNAYfC0P2+I7MUd/9IEhiT2HH//fCcfEQ6i8fgULxov9J3pYZAhvEDdBO5/5tN7i5AUxtzwogZsFt4aUAg7kr4Eoqg5qTEMpjMqmCQTe/L2CwlbFDF7mHiIqThSeBgZwHflTe3dfkNpPxIfro1/AiDzI2SoYMIsZlmbtah7Sa3nY25nFdp6P3I/puwc/pMkq8R7d4Q/aLNkce7yVP2SN7TemGoRsTLD0z/g4A+IdCH4furLG5lAjTIuWkYKVjBBfNlehQRM+4HnZWij9/tD4p1KFdCMEqojeufrX7eZye1kERrjFTlE+FahXYvJ854lakWZSFrJ080nzlaHd/Xz2T50UCi6pqTFm7BBv3EOKWim06tgH0b+iKstut8tQ/6H5mBjDCGgFX4qWlyUgl/Kj9wXiWTInHV4ztTqS5J9jf3fMb0RZUfzm8PqBnnzH3PgqSa22ZcpfFzMiWwiHiXgPgJW6qQhoHO18VU+dvAxbnUBS1IcmAwGatZ5wwJRtt8SCMCjHn1oRuKVC7sGMkdf/as8eSk7IvU9L5sMKk5RKzK5PesZWC2/qyXijgjXQIzAFumubNa/9/Xp5qWJoBqP2McNcNT4Iiz7GAJ60aH2gzccRrcHRMoVT9alo5Z9UtrpUmZzubdc36omT02+ohMlsYpjsZcS8398jg1ZbUlffoSSxypms8HNbfWcDYFlD00s5tUzUR00RNE5QI4Pm2AMbpMKBi6r+Rjq4Rl4pQSuUuivBHfK9kPNhU4uCo6L4l+BzAbV3paKSWffk5/WNCO47mbaoVo3a98/sIzgh6BrKIhPFt/mP1+3JN29NYucDlIPVDnmpacb4ctUOGffwFyRoib8HebzW33W9thxEvgkoJ4ICUyO13nOueZ2WS0/zEAkqSXgvoUjXrvcj1n+yoSmiSgLO44v1uGMFLdx45ks9MY2lmoHEEBl9CZbiq6kzrkmHKPdTA4g9d10/mD8jZsH5SKzBWX0igfOm17gW04fCGsJIMp7vB5rgo4AfWq8ptrD4AjiSxAYUuQTkztF3k96Gvf1Of7bH+63Fr6pbl9jx77kajrCrb5KqExWsjcFZQqAdgjEXgGzY4T29nPCCthZOgvKCsr1TZPzjbycTm8H9sxHw8DOnAaO+0lTQ3+yIFym+CSvowyLC9fMaw226FWeCKgDBLTulIpX/kqkZ+TfBROQTvIVFTGAhxUyqfDG3LD/pAuU2CpQNxAgM4jFV2oQ6RarjZymCn6Vuha/dbVYM7rgPJ53z0tFKXM/xqi1BopizkL493PE7yrXdzGzx6bvlCLREbsmDgTg8AeIJL+quOb2M9kow6nkITH9BxMBWPHqxgubkQPJSRq5seM0SHJGrng9wS3mpLuX/qGMFPJpNB09HbmVh6mDWGmrjP0JiVoq75YEovs/PMRtuGG1b7fC7W4FkooRIjMCXuJomKvhcTQAGFZiQcwvUsPNqB6bUfsT8IN+MDWud8oyL5eUn5sL7UvSAi6HpN/js+Vh5/8L0UCllbzHSKCJJF4wmoR/fOabJZNoeiiLf6vehd+ijdFSIXShq+//L2oeIRRyPPu2KyVeR9A5gHEyRCQ9ZEnOqpFVteTl9lfyn+q52dUeZPbdgDFm/ji993UMCVX1ZKxkRRtvkIdklDdKIxXd7PhBnvSc5+E8WcdStycRxEtueEwAtohVINzKrzjqGDcj+Q1dRmZCKCCAHPCXCX8E+LjyfFyhvMPlAfC8CTSf+ZcFm5GZ+5eik5qfZeZWrLkrjOn0IQ6MT++cvIgOc0pj8KcOj0LviQC76E8w2o6sRkKvDq/eHi5o82S0/xmeBMGKdLKUBFf6GjR04a2w7V1qdFcz7anfLYikmoXYAE81DmZZE5tvYcV/Vj3U7Kr7c8tQcijnnnhkZKddsKFsv8uXwjdc5NHShr1LK2YRDNA9T4yhb/iidnQ1EupFDihSXSnxEsJG9a96gMbrXyOYVZIY+LfMcpcO+mSAViMdc934zWC5+GzYn03LpOw4qyvjMOGzebTpR1Mm3qPGtfNe5TUWqk3pN6QFVLyY63VcIq/iWB0TL/abi+kk9T49txF7SmZKkJ35gpUE35XAl/wD9g0jVHGrYls2iqqgkS+Q6jsygxEubXiJBIQWsUjgYRvY3ICbh1D0RHQioy08/8ci4lbU+12ABibeaRXT47o67ZneSatC4QjGCf0uz0HKlw9liazvukC6LL0ffZEy1euFWgRsMDvCnqDqRVeFDwqTBoDTIJY95x0CZ0PfK3BsmCI26eEJDwYDo3v1TxbmfoZ27kRz//aFTRE3D9eUzjvzeEYh3TwHW9vOFxPTQqC2BG0uP7lndweMv38Yute42fq+TDVIqU5wWX4H/gTLj4cUAggFof1bRNU7/+74XURA/M7HGulK66u5sI4c1+CO0TkLgve9UXb7iCCyN+SkjZd7BvnJ0O554sgTaBnDKvO5yICtiZSRjtCPfFSJ2/Zy/kdwH0DsM66JmEkMc1iqhGTBUJQmTpyK/Zz72v8jgRord3f4TrTseavS4YJfas+Zj652WmxL+Eh2kPsQG1AOFDE2flL8fWYy8XGwtJyJIc9tI66naek37m46vhx6UC2/HZRN43PYznWNjiG+Kh6CGXPaCaNqyya/hZhv01L2Y27s6Z0QRa7LF/fK8AXhIHZi0g1rYkiys+2yA+wnZqtuRfEMYRZvJwps7rZeqFHbVQsUaEwY5B+Hx4MQQDAoR7JEW1bCwK76IxE6l5e5fy/84SivZntW6YP8vIrKcLRD/Sfe9mFj097BiqYIeuJJu1uXkq7S+wf3mDVx7aYWr1A/DS8kwgDuswmXrHHPkNEOcEi4lbJaSJNzpe1URf+UOJf2griglYMIjXIWcVQTxhJazA+ctnrlbE+rWWCD7+KL6obMHl3v3T5YVsEpRr25TSIPrnIUKAQuybwL0ZQnV82+KI6aiN6ZNppALKn51pWD2i1azGpslpwQXduDXYpyHiO3kqqsEqcf6FYRNA4HukcpEoHQ+PvFZBE4x0lHyEF68cjlGDSmkO3Ds5YUxk24vzrHve9S1mUc88tnQIcLegLLqnHTtxeGI3NImTVCGkbZ1u83XnT96Hnx+Cz238S3Qj1Go6l+Gno8cd+U5h+FJtYa3HtWAh8yhPE/OJC1xXaZjCjQsE+sjmgEb4kReLusGA19lnBiWhqSHCuSooBtHPv6Skkh5idlwPw/KugA214FrUtw3gyUIFWAKe+SRSa1ixhWPBgJi2Ix7UorXkuOrZp0xWYX68b9JNMGMKWCU03jIZwKvKRyM3bL6fRIJNRUMnJUGhNIA3GnjhPuBVdsbu6KBjfI+xsslzrisQOMh6U/bUSEJQScQl4s2hUoVgZeGwcsnkIlRZ+mAzyPiHBGa8giJknlYzWtM2lHrJpJphV2CdoYRgpZVtXlS7HfazLuPYeRwtOhCOy4IBZRUMLSxUy720Zy7CFQk27Kv250yIZDml7ObtbcjhgBWNsh3aqPYEibgfyWV2tFclvHrhLmW+tbanOGKq0I08grLomfT4B0kb9hH1coYisLDTZVUrQZfR81sc7qtUvrfANyHu1oERR1jouF/rjOW2y5urOyDgs+3sSK+5pyj9heg9a1o1uexVhr7z2WlIPopTxjIVRY2gXsXzzfFc458w9FOCcSNgJQMm7rL2KUkKiv+nXEldsG4WC1Uyx7GJ/DwCVzouyY3E/RXHPBbrcaBeL9XAOvOFbZEbumlRAtDs0sGS0aHj1F3cWeAKqFbYEZLq8nYE/Gb0zgUdJ8ACFxNHvTBqvsmPevMXGgG7WtWE5DkfeA7iPU5Mm7poA8wSYK1pmMJAZf4CNRjJ+M3UepIXLa0Fh7AIXtmkF+FSlZhIQEGVh3c4ArG/EQ7xz7MLeBX5TGaNZHmZ2zW2CBYDJ16Ox8zx2bB0WmBW6AtDVXlmlYwUwcH6LRmuGJWsjqj8fR4Exe+9JZMdyPfd0hjI6WkOCNAIpgGXBzYQ1XlSEREmXDfA9Y2jB7USf2McJUXlxmS/AlmNU6b5JCpe9bpUezbxJ4JbE0CBLFPDYzCIjNxHXZAXzmUapkbwzgQI0ASTRCmaTqCtxPNXuQsx+R07p94UM6pg901zUuNZZVtr6NZ75qkrcFMuuVQ3P9s5sEL7dcblR+BGo+wT3GacSmFQvQHKTaXIxD/aoLpAQDWUZyOvnQILJw8KC3J/3Tw/dR02e+H6waMdjwQnMkrqlBv8dKtjU2THIs3ki7AptYeBWdFi2zAykMV8MWNUMvxqQO5ZD/Xuxmut5H2HoN0mieipRXsOt6AJ4DdxYE2k72vF+I6Fd+imyh6B9iSd3gxueZECOaXPTJL8ibf8UwlrQZIsqhQA5fUaVaEIDraUHtJGoxf6kdENty3iH5iF+pTExBykllLmoEuv30knlbilDFGj/XBJBaWrSeyEBis9PzQHKrPRvtbKDlsrDjrac3hGmZ1COUFen8O7iurXN9oH8IqVNIi2XAe5aYoNsrvUTnwAPRm771TscjI0XgBYij/zh9YFwXmn+PEQK7L6oghljU7dZkk8ZIm/TJieVoxts8J4fDc80CZ6CEjvT6MUQJecmXRVHd2uayWjXc1+FTDtkU+Ca403ZQ2RbO/g3lb+IaTcf0mY5wX6YfZRdNrJ7BpLxC9i1i3O6+mF0Z8uybgm5CqXEkgbTMoWz1sJlhMnlYPSR9hjoFlTnbfdCIikmDC68Qc5kxxntBhKS2vX2PS08YNRSMB3Giqc1DAqXj3eCA0r2qiNZrGVCFZRdWFKHO7BsIpU4bUn5/rffFJQ96mgbv9AMaoM6QGSmq2rWUhdgtQBrFCf/Kms2Dfhx8KkxxcuBBmEx10OlhpVIht/JcTB+J3hFXrOtuRhHn7jLpbHuyj5VK7eVjXACg05nq17O8zfV1tNPp2Z0JkAeJqwUtjjUMeV3D3IEHQElKEhflY2zgtvDz1TFlV9LTGbvUKsomF/1MVTaOzzW8rktzdtFAXYFpfRakuY+CkYEUg22VPIeVAo9UbPXDhOo2LB6IoaKZQcGoQsR/Ja/auweqURo8V6CEdgbFAXhZkVQaSpg42/7/WaTLU/Kq8IOeiZZKejhVpXSehJii5YFTtETV5u5zu3Y0GWHFhHhc/gy9kBap2MRtwQbF4feu0cFYomEJxoaEwT/kHPE8FxdfipXTtOPNBm4QHYy7VSIzzc5srRs4+qJzsu6BzUcE5QUNAR/SK0z/IOqsBuum4AroyE8J9RWltHSeDo8TWHCkY8JK7YEUUUU/bTuAB2f+NKRrLmKIdWEP36ILwDqYWk4yvp0igAMdy8AiqqyML5bAzj+5i8F1YJhQLsQBkMqhe8w9Pm+3j2I6E6FSVb9Ymeirxo7EwKOy9yOJJzRX91vaOND4nBq8Fk7bOOyaMeeREjTe5hxk2c7pCmrXmvVkkTMEleX6ce2q58MyStTiCL9e2QuYulHyp5Z/4My8TKEMifxvBKT7WInrmFqv0EsGDiNqJjoFOWCSS3+oeuyT1bGy2n3QYz/PX/YQJdHWnBZUk4uDibfeO0QlR1GVG1WZRkXymOkat2mC69Xu+bluxWUDqjV+xSB8FuU2olPYyFKT2bAaC0Rsw/NQaBqROaJ4RBOM8rhLJIXIVyAAA",
+    "UklGRqoDAABXRUJQVlA4IJ4DAADQKACdASosASwBPp1OpU0lpKQiJBIoILATiWdu4XVQ9EMfy+HfbNPwel7bb+YDzjtOj9ADyzfaR/1GTA+XemrXL/h1eB+i8g1ioD+1YFr8gKJ8cs3CEezDfk1ktD+9UcP85khLHo+QCCZE/0mcPSv60mQw9RpRYhaqA9MsO5wZzJNkXLDCYljwwMKnsNZvDsaIJpY0Tw3exDZYLQ/7uD3Zj5rMQihwJKFPbWnQAnWvyAUFfYg5YB+QFVAf2q+nuNVxhVQH9qwLKdNgwLX5AVUB/WtyNnbMg9YFr8fErQnKIPWBa/ICjRvLt+QFVAf2q+nuNVxhVQH9qwLKdNgwLX5AVUB/WtyN96wgFVAf2qmtBV2rAtfkBVP/RKWTI5nJc6AWvyAqoD9KMfRLp80y/PK7ZkHrAtfiT8JnrX5AVUB/WAmp6wsVIGFVAf2rAtmEAAD+/yU/k1sf7XAtBZ+XqeaOBaCz8vU8uGh+L2LVmtmJzWFTNenHN44FLKnhXH/mbQG0a4YbC/o68dkLhNUAJWKktBCcQBGYSaZfdQCFmr2WfMHxxoHNoFlJDjU3vhqS9XTENM3rHzH99D4bCrDeT6BEnM1Hyu9imUi1vzXwRnsCNiWP1ibX+/bra6gNTD097hsQioLSoZUodYxaPBuOMh/SKOwQ//+gmrjwAskhQfit92ZC3SGR1zi0x++BAD52UU0NwHQCXX7pFdkMsPURCP3NyoaFvxv+3ISAzbk2ujtTen5KTJgAYrkpvc6kQD1t4mc924m61hEosT+Jx0wtzgQyA/a+u4AQkeEwPk4laeHnMwHugbQpWlPpOR1s/S3+3wyglapgucgWG/bg6JVBKX/zIXKAOuRmk2edw94fkV3pdCblIheAyMNJDOUbTthXzraCagn2A0Tk6FNX+KMcZhBG63halnmw2l2Gu68gRNff90Qh0SsXvuTKG/A19G7nqphlLzJQhDislBIxbNOcEm/PyfU6q6QQDTfrb5xVVOBY4FkgSKyrsYqI30+JSKP1ATZw61eFQW9JH7n0BPaRTNQwNgZwi7Azx4PNQwNgZwi7Azx4PNQwNgZwi7Azx4PNQwFM8qZ/6C3Xnj3wHV6I/cqOmE6DagWeKzO/dcLb1YKOuStCwmjg+q5dYrnVgMmKD0nUjwmf8G5VK43IGZkGCS93UcBxoLwoDCABzBGAk/nVpeTaEbXePA8PLL+Xtsh1uKYKfaZs8QpSKsagAAAAAA==",
+    "UklGRsAXAABXRUJQVlA4ILQXAADQngCdASpfAV8BPp1GnUolpCmiKJM8CTATiWVuvSeJPEiJvfyN/0e9RlH5V/F7tZSTxJ+O8wx8f/retn+4ekp0gudl84DfpPQwsg5Z/vWfOZRW9m6rT/llg8WHIiTHnhWbkWCYWHYNMNnDeOFjocfpgWV/yHwAOg0gsVpAAhCrWQoRRyzgEPycv5BspLHhPc8DfFBqWb66JWi+CAdmydzCZhwFVTWXUwqVvlUO50ywrn1QV/iwnaZPVnxe+z7j836T/jpdNJdG3teThdjyJiqXEvnQIbCXBtStqv4flzxkcT8CozGFTi3sSCTXiYLpRPtctezOGQAzNs1ARECsAL3Xc1y6t0e/N+ee6YGzi6lfzs+Ov5bUrgrdRhd/d6SuJ7hzuX9WGC/Ff2+OYrKmvWggnKpCmqwtmdvaaITiXSXSsSlGM2UmTrMIbxcyjAik6c7t9JK1hSYg7AscogvJXLhEW3Ioyk35LVHgLTyk1t51tpTZUB0z8BVJZry8Jd911eI68VwCy9oEl9LW0nwFsDppoRENsmih900oEpEtKAo0kQ7MQPQ6vIigVM1R3woejRYgNdOYspsJ+plVoNLofkBXtLw+pBPU1DOOzJ34gaXOpHKlK8xNHYNE5rnbOZ2ZRMPDgaDCNcMHsh/KMFf53XOKDGIi8PBxC45HAHc8lug0tFs02Fp+egJ6mWWyH/AA1hXnVKnVkJhUtDehSbpNdKQwUvM9s6PSqODfMQIcdulIo+f3H3xLjgGpzGR/y8P3fQLCT8v3noZHI2wQMvFaqlHGIag/D6sSaIAXwOa8/OmAuu0yDfKtw6hDwppdUJOGdxLxPNP/kjfE8oClL3c1WOPV26E1/5q6SxK55FmxJkkb4Bpybff97x1oASvszeLnoKuHMqPIhtAB7jn+pwUPcZPCKRB35CdLJIY254+NhLlsQBdSgz0rTOcl2ZWiPf6YXUegfqkaTeKoz0kFFrRthy0MbSX3nef5sHeiSblMVzone4I37LIHiZ+4MT/I0lKwy3qU+pOryMVaZhAjHA9qiAqWVG4cVqzB2lJpKzGFswvuKF4HM29Lcuay8Oy3g41h49d+gPfkTjtIHp9/A3qGgq8sjG55OXDqCpIa67X/W25xn1Czhtg+EOPw5oiGa3yT1qu5wdl+8eVG5XV8eSBB9jyco8+6a/F9brmgsRrP02MZ5JrCCp7kWHZUGHafVkMbMzloLtMN+B/aQ3ldYG6F+W5EFmQvxsyaabrC0ij7Ss882ODLi3xLMGgu4gKWk26iPABHBatOXs9wcFiolSop1XwMC/Cy4rOXwLHF/58TVoZj9WD5YXbNIAm37ZHyjBkDcVQ9utpzaM18AhbkvIcPvwqvEx3u6H42frNRnfoccLfEs4Ga6BrdMnvl0lA/h84GwT63tqdITveX7vdqERWcft0VXSHX7D+9FRsKmXeS96yKXhcSdqxEg9eWbfdOBRtd2MFmJKGQM1F7jEEtlEe4QFJq5EcSseTtDd2x83BU9GqWXERTO0hkrVBX04VNdrRqhmXB10LOmJHkYrVc5i/P1cyQAfjD8YfAD4CCAPyvRAszxRfmEoRLRB6xQc6GY24PRw8Pt1vmeVMBznbM8qXws5K2zpQOlpsVJsuNelcIIUv1n1JWdC6dw7aZkPII+/y4KqZ7AwqGBI+o5wUH9L7Q/O4KNBRoKNBRoKGRPwYWJ9yAAP7leakDhX53RozAqZPXRcj0BMwQRxuNg3kqdwAZ9s18gEqsdZNs2sunrF55yTt/ByhxTtGW0b4oCwZQCHAAa1pfFh3YDrww7v5jDm7pewKAhguk3AkqrKaEjKmc7KVCcsc7IDWmwKgkWPd3cd7ILP/DpmKO72EcMPsXrJD3/3cLLpmIFR2OK4Uuv2AtPPIOl30srYif9WKQJ+4njU23yMd04Dp1h+MMF7hIsTe2rCeeOb+k9hbkDCP6HvO4kqN0jHuUJFagg1aGZG4TwvZsGi7GKglY3TQ6OtbueWwYCKRQ5/QPTzHIiRpgm4qeUDG8W16loauOB3qm55fgyUfcmIpn9qDfw8F+aqcZRJBAYDE4bBZdFhocNvNfSK/T6YW6LDJVmcsOPJwhHVWx21oV+uZ76nhNR9KAkLzC51clN+yw1uJEDhwAYAXhQyIlhoJ7VZIvJJavq1LaWcOazn39GuxDYT8Ns4ZpAqF8ANU3pLmW77WKihJLCHELFuim1YRqgCRoyLzRjVvGy4HAksBEBGHl/SyvTqMh/B5Rj+JUCPEmcIYSeLDc24GdFfs9p/WnzDGve6WDl1KYNGkPzx45Dp/9LPfExuaR+7WIdRaDxat8vEJ8RxKYH8Ui8Rb6JVdWjSyiOaOlj1ZGNnO3/bF+tsZFt3yhfvUab5p0VD8uGW7gEuAIB6st0oADYXl5tZC69QwAhdv1tz0iEf+wut7DhO8hBNjviehu6bz5PzWPctAnvXE4a460qtOlaq+0UOs3WZB9AP7jM1YCYX7MDzSsuf/Hd6uhjUE+WUl2awmOg66YbfU174HC2yJ/EvzPREkRpwvPdsPTxIpkYouQvY5J7djgUJzE+AzgPHA35maD2apGZjVxXlt0ni0ChfCt6Oum4OMVDXsktlWqPfkVv2B78AakXI7NH2xVRZt6YC0Qa+Fe32p4ZbGvHaI5ho9Wu6bkOJMbIU4FCEiAK7gKqDjJwciVRaGMiPbz+wO5g5NoukkIcRgNlCAAoNfzRyqRsg48ez5sqz3IwQfbQPsvpUDGITxtFVwvZ7qF+PIRkzL8xFeL1T1jAmjDv9gtXXBPhtfnzM/8n9cJ6AcAAAyQDS3NDo3v+AvJ22DTe4VAPtg93QH6DZeeHG8jdCAuCTg2loznyT81MHEwLmRNVKpoXYNM2gTg1tqs4hr5Sfit+7tzYXxItj0K7hJvFi4+9trC/zY0ifla0ozJFSWeyZ/e7mvubopEsp1UGrUf6vFlOVjBiCoAAcyMA0vJs+Bb5656zY7uiuEPjodww4Ad5ICha6yfi5UNOoEFivGr7rPAFoAFAM8SNoEUda/bNIp72xRIufOx3hW0o4HXfQI5/dnoZVMW/aoAedHuL85374QWiWVy0ynE/SDBirqxv2u/kf1tMRgdXMql9ufP6P5Ng/01LVOQ9J+aTJHVqNLjn9Qq2gj+wvvRpqnN1rIdkcxWZdIgI17qipzTzZwzy+F6HLqNmWaczHsBgl6c5wInShZ+Xb88yXxzNxLVcbOgEbtDJIVK3/a6d849oUJ0TvSlhJLoKBqJKTn6EC/fsWBduE6tjhqWQ7jHkfTLphq/cgwaGOSF3famz31BQ4Wiu7WsW0hiRwkga4xDDnBHk8qaz5fJBP56lv9TAwqIz+bcOYaoSikLU8BzHZEYc6+ZXSgaupeljGl2CWQVlHQ5HazXz8cBNKNZywuPRW+jQNu+Kvc9lZTJK4CyOK/LVa8dCYX7TUN71Fw5L1IwElIo0C34wYSz2a2nSBJ2Vj03HW6bohIHin5fnVEwAzc0RNHUr53/wdGDM+gM4+EsEURmeifjNzZ6h+Fl4mDWsF/pKGFDf946562lN0GPIJLRRSP6CsJOWquejbQ3Azdu3wc1OvaOCP1Qni8xna6Nz0nYbBYlc88A7/cp0MOGcukJCs9JSD/l8iKY5ExSQGoK7+rvbDHZLiBIVZIP0CG8r4pWFpxbbyrd7DJgXXuBhBMoLsyNWWxymq6fpDtWBiiXjb+u4AEuveuu/oOJNsoI0eo7WyPkjDbTyB0oUKwIDk9pAmdLxob15SOc8nxJLgMM2xLexsmoblp+GuFlOnhKqHgOrni0AoMZ2EluQckIv1Nu5Ak7esxoK9upAMe80E2eUPZaespZe8nYCaeZuDUNNaqUz1J2iiW3bqk7K+c32A4VDSxrz8kz3Vvf8x0BskNENPjs5Fo6dV9sFHALGAEYqJExs9LJkW8g+pJvhZdvw/igbtoJggjdHEoktqOKHqaW5rPXwdCukqoxfVwaUYMR/fgf0+PPsBATTpq+5YHHVpFBw72MxfbTpisEy/8w47E2c7hUdeNtxS8C1mYSDrAb+Qgc/7VRPT2M4+4iQNl/tJIjaHLag9338iSGwNTcIHXY1c9SZvsBiL9J4Aa5E3GM7c+aNpwTWhgotWXmhICQUf9+4bcEUXudZEkj2sNV2BZBukpSgERiboVFYle+D9/XoJKu8PDPVUsm4DymIaaOYxyv78PuS9YeYA6lkEXD0BKHNmT2niQXyQBuiufQVfeXmzkPXeZq9N4Q6BbK3RdKUnEZEErvUGhhvBEKzhhjTQjKIdtEWATOrwprFTX21GUPNEfKHLKf4U86Shcea1PpylntU2NYFrOVDbUyL51fFUISx0GlEnLI6laV8tZ7hwk18J2vU583qifazBeBLteQ1dgwF/tQYeo2NAso7BfCDjjE5HsfmoD8UyVGdZF8qEQtrbpIlJnywWsLeHtoGSyH4Th2ZSCxncSNJkOFmhRsqhtD+JoFYXo6uRVUg20z9RyPfU+vZ5+UsPpkjXJHx1N6/l9VQ+M7gpRVg/sPrv2/S90l86BbMpNSs9nZ/rTL28mR4n5/oI4Aj7McsoyUAuaeJ3MynzBqCsgBpOAIAUwTDJh3X6pezYIKauq0KC3h6TBxBaelpvlAccK4eic2eYVUm8VlFPnIebnNgL43VuHiimdMuLP+QbMG9v93RIde1oUZVEn+biPXtn/qhJCybg/v8XVnu4A1D3wfttFiDAdBYTesganDYxmP2cgB363Ra2xkcptoPWNQEt4oyfZ7KHrgjpMWGrZGD6Xb6NrSSv33Mb8vNpnNKC7lFzNNWTzvtQYCX6i6e9NOs1dglARXw4C9cnX5yVtFOTnUQoo09zh3UapdF7FPyHi5kN96CWFT0WX5/44dwRUeA76+vSr9P8sAW/ni2V9D9vDFUaLPpO1il10ucslxieWIQwgQxGdXDHsGh2s6mUgWEGqd4zLPTCSAhe4HeNedy4E3vNIDw5uYrXVPsTiYlFuvbzS/qkEGPfiRnFzTTARRoil+SVWIcA345JTS3HhM4OOHKagzBwTDPTel0u5nyHz6Y84ie7/+HCImcAGQMM7k8k2D4FzxLPym03Y2QtiyWSczTYZ4USVmnAXZbkef1rXHk+3mhMUmbinluAFs/FwFwubPL+jenBOU6nxoe/K2dctU8cj4ZFBv/o01s066d75S53Erz9+GdQR9Rn0+moWHwmuuwBNRO3H0DKIPG+OnASY+YDKA4Co+GqybPpDaMbyCXZPuaKy4I8qaYmChmRuoVKkfOxgvpWFIGdNi/+9jJ+FuD/yFH9+BTjMe9Hkc+Z4KuZfmIICyRJHKbZvseodA69GwpfyWpBf1NEnhZGFwU0DR+r9xrx1rr6IOtTpJ/m1b2wtK2+mZERNi9JU+7V/U5hvNTekYPWq7RFLHPNn5zny1/R1hUxJTLFW9Vfo5sfxPRldU54nvskYJKkQkn4m0LnLYLn0/IcEN5RaNYKA4vLcMoi4M55kINuOQ96JQWql7VPiue9hFdiXfsqUEQlegUoaQ1jj3FznwByF/pPCoYUpmg7OXd+pB4FKMNY90GAvzOmp999R3SMARq4kTmxBaBxr/WFzOasQULskCEEIoNtAmBX0zgeke1xItD7TOK+pi7CYFIcFv25Ht8pqux42Buj1mQHUaLSepbUtW4nqOEkPT1MAD5qy5Mt76F6a5n03RQ/y5GBJZ27B3rfSY5j89FWgnoVE9GU7p3WgQpgZW557Lp6mhmBN6EiiweFgIQdZFcEHsc1Ci4BTNjGfLk6w9sa6wcDkremfKR2sFNArvWLO9qs210qCCgv6er9E83IHtPH5MWO0QvA1snXUT5sp+ueO+AZldteF3sAL++iD+5VZ7kK6SQQjojZDkuGwAYzza8cKxMXFCFapsPjIAxffQ5iacm/Zw/7YxeJh5i/cSddGpxSi0EcmwAw7DE70WxW9NHIBN8N9xdr/xnICgyut2PiQP5TA3NOHjQY3PUvWw4wBAgMR5G6PVFmqM6t9umUf35plrXyams/evBDxSq/mfnBrvrlZgHOfhi0/CPFBPITXbazQP37vepTf/TlKV3YUfFwUuIPd0/Ti9P35ZBzwSVltSJts/km2/eIalNW1OIl8umhrpmMi56gBJaGPJQ6atLT4Hxf++Jj6Jf/2KQZkKEdwOSZogSmmmgDV8LUofzZ8lGp3BMxQ/o9fr/oRlckDEMgPqaZgvXoSJAJDI1bKb9pckTi+JeQxMVQpwVWY6EC9ua2xnWlyfKtrW4BLgaBe9iGB7HMrO99YjYQVDzWvmVmX7tdcPdo8ADeMlU9vY7o+UfYK5LRtW/vDz1+VNwNmYcewbN5ELlwB3Sgscab/Xmc6ypvftCnFfjVrXDCnKmd2zT2scHa4f74n+DRti0Dpv5jZUxb2SAsb5VdSXzzlUmC0nSplLHi8YZZ3xgdNqTh27yibPurDDcT5HuYVC/5pLsyS8NsI0/AJ2xtmaexC2z2xDFHaR+mLeQvZb5yEQSuEfJ26oomI2Tz5M94VYKXzvSm/ROKKAshVRbzqkyj+JF94VUSWNMVETEYyR3RaEkAbrRNbfV6+YEf2V2Zf5v6oRMbBBfapydqQXam7pdCM3Oc1vrKWwm/rkib1F7TxsMg19AZdbK7IjTwCDdxv06j62Shl8XSuf3g/y5X8DjnMwFzt3Zurq3ATtkXtvxZu8Xblh8tA76sWrs0b5bsLYwl4YxzmSZYbyBRHUzVAsvD06X0mPuK3Zurrno3S58wLk+5qUkXXHVN/s148TY2fJ6sxLv3XZIGWetpBnSS9nCHfmD0LGb0S8J3L4s17HEzNGPYtjVVTz+mfDPFS+te0wyihhdWr9R9n8LIZjynRIEAWX5LmLh7j+VIvnHLBGniLBTuTpSM19uwS2G95kdIh9acYt4VBhNGh7x75cXI6KHWDSlpYf/wS/1q7mMiGQA3WZzv0XzS/9Rxs0f37RUy6evNFo8WEB5qQhVOQ0JJUx1rAzRu3Z6LXD3aNlZR0lmhx2WO6ejuWOBhVThqP5hdPsE0REY3alBv3sVoBWuPZkDvsBUU6obbFvaA0Ok2tEfnAC4E6DZ63p1TOFBZrnUSznIPiWh6Wy7qUfkXzVLl/HrFwqJdl2pumLAm/X506H/SCPXj7gD2+lS1aKOPFTGy9YA2Q7xMAHcyE8zMWK5PRMGJUgwShXs4cJsEmjU7JLEX0rbz62AGTxrPchFLy+sb6BFO2JGSxHPVQrl6B0CYDXMJsXPyPDXMG56NSz+lGs5guhr4U2IGZEJO+FGACDL2LzAKhhNzgI65obs0ylti7k0vN0TrIWVqalJ2sjI48qk3ZKrR07Jc4Cyodt2UHspabgnWwvMgY2toYsDWMzIsULMawEIDn1NwIv/VDerliCMfkcVD+hIZmeQi+HSKhzJsC1XR8vCrgosDKf6gF7Sec9wfxfL8U58ECMFuDMl+5I41kQghZ50RdEXHEGK6ug6K+laCi/WBOarUvNrlT7AEKqmE9QuTg4i6OtyfOCbJbIgwx2dInq7yi7uQLPCZ0DAzmApkPscJLhD5tsU8d4Vb9RPDl4h7H/C8HuvZS/QvagTJQtYnIqokSzFLFXTSxkFJbs/0NKhM+84PeSnObj2qAEAAABywACj9vOuYfVTHrVVwRUeg1/H8F/TFVqyV1k39Xos4WjO6630HjEQE2AZjoPPCTexWoA8j06Ik/tk52xeswYO3fqfyNLh81J3fztEfiOBGr0OcXN1jmIhlrtp8yRoHHs6bQkuA7u6PltgvtL590kzA6tqVuFuzk0JYnmhR72cX7u59+9rpeM9FBHZQ3hnPJheGXBgVHWZD7T6d9LYJ/zkzKSYACr6K9ouEhy6dZC+4AX5TtS7PvCGG+Tzxv1FnU2mP/fn2A8TsIchOF/ZjskVuzKXxHd6knm/j4SQTWk21SisegDuKzUGUGDE9EdI0zZYB54fjkXKuf+LfUU5bOtctZqbo2XdpiSZr2kZ0/gZsz48rseP/onekyYU5Q7TxgCNkL9eYtFf5NGrOt1f1QOKADMQ8aZ51RTBp49TT48Q6f84SfwTaO1a8IYo5AAAA==",
+    "UklGRmQaAABXRUJQVlA4IFgaAABQdQCdASpfAV8BPp1KoEylpCMoI/gJQQATiU3cAPg/i7rZw1Jl65H22L8ju1tyPt/TlzPums7+zz/z/U75gHPR8zPnWek//R+ln1J3oAec16qf+A6QD0AOoP7E/q73U+BvnXFbtT/mv6ax9Mlnyr+Q9AL8h8SV8J1I+89Av3CzE8JNK5O0/5/ke1EemN6NwuT9/zWc6OcnOTnJzk5yc5OcnOTepkDX7amREvtUFJ//lxFoFP4Ex/6KThEYtC1a9jFnK7jV42vi8TJhaohvVdctqTrHfZtSH/cHOS4/7Uu+Hkyq51b50i6rRxTtr6xYZYRzBt+i/HhvIfJ68CTIS5eSBRcyesoVVXPQBxa8qZ9wNGwT0sV26LFEhwRfEEIBNfRWwjKJD+HbkjiMcHTHMxuMWTSePc6DRN38vUzQ7psN6zKp/O6St3fthRsCnv/cHnQKP3OdXcddPR32HupbmxvZ+bqsKMfvKapBHnQsMkH84Vuat61ciM4cKlpwgQhfH5GHqk08d2oWI+VAoc7/zMqhwRp+2dDptCdjcu9US/QK0ayesBe6/oY3AzToFYDfOqzNvVSKTNNIborZp5DfuOyzdo7p9xltxpTiJaUgn/Z56UNqbnKDsa5ssTZzPQMt/1xgPHN4Ux8aeN0pDkwaeipVpoWAwusF1NZb8ghGAZ3wbhhZ653MVwAlbT5Qq4Y0vWNr+ISgvxEuREf81z9qqy5l1ccAoxSdRuuKXgHDGZQOoFiKxFYisQ7JIK85Sf2aBiUCj8ecu9iLo9jtuIJIwWkbT+OmCzZpZYudiKxDwiXrNeBE6VA+rfhsYlGhE3dKQTHXEViHgNk4vJMMIjA68FFT4b9jZcAVA6hrimoQbRaT9J93B1yK1QS8iTtqhC+hAZWVp3lDYzudFLM6Wf4IfHsUwmr05YtAnmiOISwvPyTyw1FOdbJE2Otji7cFw4TQl5msGz82Q+DnpKgWyuto0HizXs/BU/k5F/LIVvrs2RrdvjHqpQEq5Pv/7WP2cgDmQORzp6y4jjqu+aF3MwUREqaBV8yx3mVE6VGiPZVESCDwhOYp8idS+VUgG2z//x1f///0rogT0jqcjFVYPdR+yQZ3ggBaKTfj/Dvn+28z5PJYXkBbquxaVthVmDJ8b5XmUg7yJj+v/t0Qmm69eTTx3Szc+yehtEdjtRf/50rf3gwiOCwvAd4H/VM5vUVjDt1D8/9zjv/2QUpZma/ZJehDwZnju1Y7+Xu35P7ySj4gfjsIMj/hAAD+0n/q16mCHRg2dhX/82QW6R+2azx/pxoe+P/+JP1e+Sof+Eg300AUyetc8o2iIwsLdPMqIj+y8rPIN/hJr3IXbtqfCBY1UT/4vA126mYFXpcje/tNT26BHpKG5mHwf1LNL0DeIk1MOoENitWME7zud7pmBYnpoWdyXB1q++6xOV5vJdYDQ5l3IE4+dkIYD1p3aG/hyAuGowfQ99ii1iqeIkvzBq/jKwO5c605RKoGja4i/fWuuQmFdhfrmkQIVATA2lPFmEhb3a6bhvEvL6xtWRXOsiY0JeRKIwstNAGnEsqz1ryoeyuKVgXYNLboVM4Bj/pUL26WrYK1S00Hpt55+/jymvfek2Dg2BtUYO7vjPMZcKFg3FWAyV7O7JA08bcsGfwLul9+nbT/h3CdnFkr9xk1Gi1gYn4tr2pPX+AWdTUkeFVeuiUGLsqoyy7K0vUxmO/9LJr8C6g930lQNJP3SLrU1HGZwZeie+WQluxFWwHnntQJOnzIqFqYCB5C7WvNUIk5ZEtSs4sRrhgs7RfinxyBnDiG4C92sYdUSqN/Qpsm2wZ4TPw77ll5QakrZbTVJ8Uwol7KONZOhfznQ3lBRHNypp1C4enhnr1QTIwICV1AkunQF/1oZ51eANoSRNfjJ6BEQkjYS0IHqGQlK4ucrtog5ArzAoVpIwX72EjOm14/D0ZzauHUzWEXvCumDn6ry4oWSG+++14EAUZIYOYHZN+JDIaHjku2BdLVgYHUATSeEabNgqVFB+eRi5ReHlZyJzW1c8iR7ZtfAF3jHVfvNzeNXfE+Wdo6MarWb0DTl2s2szPoVbgb+7ReWeXPCH3UhW6hAI2LoDmItDLzT+Lqpxk/9tMUrNBFmXdDyFzNVtAPHQgICw3CrlTV8nqbG+TLnm7pxi8cCwzD+mJj6m6wlD7vCFZkdnyLndcSNn3h8C2sizx88mnf2Ga4p+n2OpNdUSYEYyt8z5jUuihqFUhLC4ogqbO8ZORu/vNYn5F6N7d3C5cLTdlK5lEIEcyw7rF4FzzOmTvkFYVyLX7fs+4uO+vS9rIr2JDNSm3SrcvsFbDjuGdkHRnPOxlRYT8XGlzFmEmaKzCDcqNZXNZiUfliq3qxOVYoavVAWrz+4dL9T4O1wrJ+OV6COO+NGO1YGXGq+A71gY1sbKl/34l2q2q56Bp07Y2Aawr0SS6IQD0P3bnF4Iljp03yatBXaz89EFTlqiRNLsZiQvsNVf53NAOq+QCLfaPwBNS2E8BJ4ubyymimnnYVJR+cITKGs9FFtMht6AvBM/YAK/q4ru803V0XXKBrvKn2pET6UUta8QVDovWstzlShfR1p9O4mZ70lrc7vnIEkXnY+PbgBJkka3X7WDO4TzAkqf43CaHz5FwNQHe1Ts9pWGdQ5QSOGXwzOSjDQlUn6+O+KrOEUNFBlom4HVc/KhrQphkoqb1ZASkEyxi3MVbto/bE+TQN7ee3tv/b/ChHfaoRuAvyLWQIosjbB1vvXFM8SVz+z4VOzbna4gHHkWB/MQMJqhGrcJztHXvmdBQc9xvmHkNsry4WGdhrz0LsIDAIMPEJQdGg7tHYKTKoihZUaz+rkLG+eGOWTFXvwd7oFEc5W7DasqvcYVYP08SeR+6YXxYsnmKZp3VsTTatQJ6EUd7rj7awvBxlb4BV3MHyqsp0AF/AZIhxHeE96/jbWGcW9M55pEwJ+Z259geXKS1gdU1Hgd8QU9iAraGS4TKHEnnR1A9Ap5EKreqU9dSig66GtvQk6iVeCVz3kO/vN7TCOOZzFe1icocbSC/SCRWo8M3WisKtlEJOmbIf9OQ26Ig/mlyaKK0ryPZEnpr1Ud4G1Qj4cUUFezkOXs5ZN/JPvhUKzxmDqHxdfTyNAVN5G3PmYoCnHs312I/50eGnAI5G3+/3G5aBVVBj+6pjl8ToWAbmJ8SfQgGRrsGZjy+kpiXWn5JXXBnflEfyE8VP+h+AJnFxbXqhrbU9ur6h/GcR6B2u8zij9/QZv+kTyKIfAgjJVUnK1eEiRxOCZmZjhPX44VSeUT3OGziv8l6QffQOmWTNmlcicYaqqt3i7xmeILGmeiqeqhmBr5mqomC6eMMfy6eKy/2wD+utNQPEejXPWAfDsK/rWJr8sj9CjLEYE10PP9h+lVjopYypAQOhJNzQdCzXzBtqWFykHHglbzOpXiZr5r0EvIcELnah+dzEBRkhU3s1Gd6g5905SdxxK8HK0wXi7GiHf/J1SFIlkB2BaUkuLQEYhv4sWirouYG7+QU+tlM5DmcE+iYvFsXoOt436GnptbxM/JRXKmxmUN4KR1jV+YfoLXTTUcvOHwX47vHpmM9NsMLBD11eGC9sNG/zrd4/z5Y+M3fhx0hFM9p08kr5DXQdSfFNyVUdOM1D1eHRt7fW2gxAejAZUlwtWoL+kwnI3K2H+J9fBbapB1vP1KBcFv+Cj/XJiKx/2iaBVzKzk+z9FjY9IfwG3Mm3yoEqv+B3gpJhrQGNnilzPxmoC8nRLyclHm1WxqJSGec19S0CDo0KHMdPl5MU4rG2CfX+xOr81BTOtIJFHngboiPc8Qd1DFDHOH6PnUrX0jwUJzM9lPSXKGZzbLyb7iW9sDVGVAdzhf6JXGSBdlKmz4j8Cvk9o5wigDKL0RcUou0uhIOnjemn11YLTgB6cvFfQV67TUEl4D9hPIW9Ph2pvO2i18V1EhUrLdyAtQa3C4wLS25DbjohzV/1zfIazHbSUQ3PG+G9bzszDbO3EVv2JEt+ypoTqglhdkA0EAURkNC+alZPxIBbvc8nVnwHg/NscZ7QhPAnrBrQvL8t7h1kS8xh/fBZr5HZ95mhzVw4IMpe5pNDD2o6Nv97d/QQ+87V1zobHCc8IfFDd7AHejZOnawfD5h133fZ2A+uojyIPScvCtC9IKjPVEQd1FUjDkMt2vaDluEPkI9kRUz2IH+fwpaoIMatqTb2/quDsMlqXMWUqoQGLC2gJMHvp7/uhY4PK4ZntHR3LHiQTXe67h+aVDw58AQZ0iL6xyxF1nt/xYx9IyBBpPnM0pITrTGPyZwymfLuQt3LXdVsy7aajZcz5rC7p4j/8PZpg4BRytKpsaW9K5hmZkZ51coPyRJ91G0alL9hcp3CvDda8AWJZxRcFWdD2vTK7Vzyi2ygkve77rZnLXKUdgHaNE1/858H1U3gKMYNqZQiRl1CD8Am/kwS5qRR8vZZj7nptok6LP58gwW6RkDr+UVH3koCE3ksR0wqHG1Cq5RSaeBN+/XUrzmd0JnafHBhmGNXP4jnQaLgnh/73g6FtMFRoDbj4onBXfr3ilcIX1UC3BV3WXzGwJoo8P8DnX8wyXZHULdIMF4xgQo3WFaLn2pjE6m99u6zcNybxol/zN/jWIKPaR2X/MZiUN7qP1PNusA70ou8a2McQq3ILeN1XvRhnilHE1FW+PXkYOzH3107RIMgl1S8YKymAflsfNbmQG4izR6DqilcjAIwiaWwRxhsTf6OE+aiRxrAT4SBiFosHguIB2Bm3otU4GW+PSmTDxlq3Q088CyjHHYJo2pLVMzdjH+LbzDd6+o+S6JF6fsQ3HuMiETVdLwcMC8UQLOfPEfMHdeJk5O96Zb3d+/auousMg0PaJH7IkPFVLOUVzEqgC+5gnG/SX6EIiDVXRD2hN9BXfc9MkA+NkLZNDfvkszfwYrTAyXmvB4tcaNehH15yo0R0rRrvWQqz4H1ibRoYLsRM3xcV6YP5lHhODKN1EsJ+Nom1LJJfP+qI11PYHoK59s8lL+fFTIZEms+YbVwVwOW/X76WKOIEiyblB+n6QIDZ1ClVuX7Sa3tN+M/SBPd0t5Ir+V2ytBfOsH+SrgHgHYa9pafXlzLa691KMDuc8SPlioajEJQc3bD7S4aJa3ZahsKO9dIn0CMj9j5K9UUp13naFONNbK19igUnHK9ZvvYe9ESJv24k/ZVEL76GjZTGSInI3TzmdYiRFu2EfLUILnBcqyleqSuW/GQEFHflV54S6J/5EW39nH8vIH9FdqwuW8UtOZ43v+KHmuJRlaFHSvm60JasXEb3xvGjiYZFBHrjaBvWMIgs9IZ+Em3OZdNgsA7m3Fqdu+gYpVcy8/g66RwGdJWXfb+Z5BLzK0w6kz1rHYlSAg5CfZKUMt0duNRVpd59dCAg35wJkDYJWAqimaYM4gBWQZLj2uPUptRx+W/yD9OGiuEPYcvtpIqBDWTUnGpbLY3O63UcGC6x+nchzyAhmdSHD19k2gDLhpfz7G+X0m35DB7PwMyxINW4S0o7VzrD5OWuLkl/JQyCOnIznzSdpA11mY/fkKrYez7/ObpsRb4qwWZbLt1gKtSbmTnzSMNgAoL5WWm6pxcdkevdEtGp0r5LWM2r8Gw4qg0bptLsYoy/jfPhX0ebVgkKFnSnqOl/ujAE/Zzd4alxMDgxWANpHraz5OSErScE4ZzHPqT4vrDyelXAE68fMrubGM9pLdr2tpkgPG9bpk4FYwLvjhKyMRBgktz+Edi/AUI+af8fvmh1UVxigTzp4DKlicfcQpWcggW2wq8sAoO/hARPITqZuS9LjylQjp3yUAskhLXqoV7ehx9VDqDnXozif6L+c3Yje/6PAS9jk5KUdrYCD00gWactmD+Kr58po+EVkWhGd1iGJs5XtzgKDiNmg3X83y+OLjPyS+lI1S2X5NIjbn7MWkqEwDWS46PIKlqHSYN3LvhvDTqGvNVy5PTuPEGFYCMt0L07s7RkLmsBw1w5zQlMUvgp5HPb3YzeVPks6S2qL2omJtfgDJWacp3s35Wl/YKerFsh8JIfczPgGhmhnZWRnNrtlRYmAd7tYRnPD1IOVuPXJ00GQAk1rshRgSOVXfnK1rvjTLYp5Jl+xuYNv3znv3KI6TLM5D+VDYkSeiZhX4WNmR2z8n/tRi7oPktWLhhfavdS8C3G5B8/2PY5GUTTbjs52nZwzxYtS1SjldlC62tXbtYLIoFLB0VbJttRIh10YvJ1jCteVM0mqaVMJFe+Avm0Vb0C3FWM2ImoecQ3oN2TXyMhPC3dpP7cJcOFe13nz+53ZCvZGbCi45de0I4gdgp8FjUt4N+yNeKVd74Dgq41kvHx0BQPVNi7BTTomQCbXbRl7/QOjQGWlHgG8wO3AXQY0p8cOfoh9YalDWWv3xC1NjnY3ApRR6NrttrdcpDBjs46rPFDidE3wQT9daSNMuN+D8bmEtnyJ1poj9ZobWHHnVVt/pbPqbDBqwOrN5jaJuGTvZCP+94krk2atuwBdLWFAFF7nXNlFc3SG1QstZYbs+qPU9dG7OH6qDX5yExJP1Izyv/atWGnRlRK2vQ+oeh1UZ5+Bt4LlavjNnE+6dpBgyt70z213KsSXpo0a4PaN6SsyKvdx0wC367BAtnXil3+5cWlMlmgYnF1my7ir1ZUCUKAShEBQJJriWjzvJQ0choRFA9MBRy7hG7ebnIDiLIPRWcQf0lcW4p+7h8dE6UcSksUqwRlC/huozEXwbsezFTqUgThUALlodq4vJ9cFoqXICQy8YDhFa/yc3kSNGKhkewduTuzguKod7yHTJSMart1bNvHMqs9kFkplAa1SvAIJh3fC3mzPgx5BSbQEF0rgOPOuSSpiAA8hzZbEziLv7jQZzVf8on+Jlht4xAxwnV5ezBNZXLC4i/yguRszQ7Lb/pytcE0utzgVQL/Gp6/rTvVCVAKSRjOv8kXqmMxUfAABv7em0YpSheuEHjPHDygLSbs8syAqfzjEbBlfyd4kF+Ev9fZzLhWtxQTl8C/wOdJuNcUIKglF3DbJZlWIOLhTzvFtgqSnGAQG5Y4lFAJ0/Cszx1HVlEwCusYoRP1bNGifqB7k/PoXBjKuvEHx2mTFijkQVfIGq76dxT7uNAbbNQUDK1p+LPQXQW2QryOdZ5T5zwrfOoBMk91IJlGrSSP7KfJSzJJUAMheAtVAqdDp/4ZftTZRVqCi9VjAARqQ/RBdsvXeYaF18VAa+ZuzCVCMdDN2SdDdI5omPaDl/WwTF4RBOXmF2M6TrQSrJqsrtx3iDyxwAQlNTADrGoQ1UG2/RpfyhX8XGYzwljjgVuKqUAO12KcEB1vqrE+sp5vWQ934QAu79+/zntI/RXMzzZojM3ZcsL/s9FDArWUV+TXrYYKacECMlzuiLlvKPq2IB8jIseZLNRpowRvlOOR/za73ajGVvktooVPuD2sgk9u2l4PuBrOD8eEvGZRQ6b7gfDglFmFnj2NWQ2bhpYc6OfQ3tcal1U+AUYmXVuGiCpSWhpX49vqmKBucPpYbZQKsVRwwvx9Kxh01C2phMGKcpxc2lKkC1PGMHSlrXpvRf6VPuedraUWXl2/ZfaY5N/x4LhcW/tGXGv+7lcolIQ2iHVDP2p6sB1u10gFX8y6YI7mC3kiflSi7eQ67EN2o1ghcwLyYNwIcfVNIfi4ej4BTEuo8F/sjArXflzY5UZakLqoXXKa/C9CMfPgJbQM1ZwOB1lF5R8v0j1l2runnWFmYzb9RVh8a5PJAiWhQ+6lLczDlsQoxj9XNZPxleRsCr2Vt2kT/oTwZYTzUmn6IT03FYSh75NaMselWc4388Gk9mvJuXDnciwdEhw+nQJOnCRIycDkO+gO0n5TAgjYpne0DUorPJnvUCnwys7cuuo3pYdKCVfEsPmzfYujQo3hm45teduAVqITJmJpamsWdtaK/E6LhZwGRA1DV450s0WMQnxQnpyZmC5zpVYKACBq6dNKCx/hWjrwUf6VeqG+Ic+vkArJgHTkjbQR5wNDGkhOmFTPp88IoCk0n4C9V/AIhe6NRQg+tgWac+xm6KvwdelVrmSl4jNbZKcoLyd+5l3LktlgO/9Zc0xa1ULuCzo8Z4u2NhrVUlJrnfa1eZ+JTmCqOcfs8dFtu77BvjnevovgzTRwxCnHWvN8YtTwR6p7m7DsXoma8ozLQA5nHVZuQt6qFGNb5KVMYbunteK9bq25DDzqMs5hW7YqFYhtCOeJ23u12h1pQuryblMkpWo1/29Hk2ir8CiwmS459beAYapONE9iC79oeHxwRujuWJdxRLNfEsywcggJDXjRVPhrVjKASjqJl0/pl4wkxGp0j5Kam7nhfFgKhA1u8Nb0jBHNMUf8llEa5GITLfHxnxAnIYOrD3jDVrrBsSIgOi6c9H2COYB0kTvvewgY7KqMrzPKE0jw5+tSZs0x/L+lERlbUk0kCMRVvnlJzpYsIUQ+tQ4nFj44cLUlelDz20NJGzDC4qcwC8WWdfBLTmsBnodoT+HesUc/hfWt4Q11gDCBszyjriRGauo5lZdwPqRMEFdWDOps9/CIQDVhqb06fRtae9yR01KDP/DptJVP8/jORhbUtVvB+wM47Vu4jRa/3917j8zV/GoRhDaABAV9RU+T4JMlDeqwAZ/nv7gRpwqwKU6uB9MmYx1T64QU6AufwXNoxA24N1eTdiDbZTW1H8zCd4fdtugiESkqsWUgnJR505mSd8WczyQdJXdKOaFRt+KXUYKXDzesNotVvhzy42/WUTAu0FHigtbV9MuAd8w1d1oyhMMgUDBKxPOP1BdnWuRoZV3GpBLYTSdIODrWmY+mfaDyhdBlZdS1QH85iWd9yqMRG9StuqBzrG5vtSdd2taBFhXtAMlpfvnDXTSqshyP8eSxQIfrmmxv2WjIUax7olJILwJa/Z2OQNX6aQAAAAAAAiB9ErXTO3IC6G/pF6Oxc39kOSfNs4DOAFhwAA=",
+    "UklGRpICAABXRUJQVlA4IIYCAACQLgCdASosASwBPp1Ook0lpCMiIpPo+LATiWlu62AzQM3qu7zJgFDcYyTLYcyKIcWd+fYz9L/oYpe4s78+1jFgf9y7/tURMM4uzc7j9Sx359kA80cBqD5q+fA3mE0ZVqm0xXgXf9KDuR9xg4mmRuDjbu2aGpVjMJptMV4F36KLa58xd9rsIpE2x+qmXYR+jePaXiOoV4F7uSZkeb6P8q7nnPp7kJATmDqQ3Tc2KkgmX579GUJqbISNTYGT4pcHxB0PXEbcYzGPkDcyFASyvoJ35puKYcZfNQgciUHPpDHA5SXpqhnr7dgMMCuy64MiHFclX9U4YUpfs5vNtEvqay10SMrODyqrO2Z1QTMnTVhd/S6cS3uUw5vv0hNZVPsMJdm0ETZ9rl5bibCx7g9uO5d/3Fnfn2uZl6uXf9xZ359srOFhra8C9rl3/cWeZJwmXvRLv+4s78+1zIEWTK2vYHSZFL3Fnfn2sDag/hjQKIcWd+fa5d/3Fnfn2uXf8IAA/vpPf+uLmyIo//8bx/ku/t32klT32q/YMICFmNbsQRCK0agIQ7BQdx+N3PVeF5m4GnSMW/HAWynRlPxJiEMVmh+M0QGnXCA4mKBApniGSIXGa5hdQpQOjOSa03S/mHyW2ISpWH7kGf+kjaqPRvWYhMxSZFo35mQ0hdKfYkZb87nsWAt9xI4fzpetTpfu/q9D1dS4qLtVM6dBz7Sa5eRH90dCBxGtoF8Ee2oeB5MxoCQ7SqcXGDdTLQXK1MRzCMNqKikyLAYpABqG8nUH+dW2weUi/2en+G22Fi4gC3UiHABm31axb/kAiJEliZIcPI/i8pVtOEGQ4nIHcgGGWuJb8Qr2sUOAAAAA",
+    "UklGRk4DAABXRUJQVlA4IEIDAABwMQCdASosASwBPp1Oo0ylpKMiJDYoqLATiWlu4XNA/3NkFGoCqZwMWgjEEYgjEEXTUFWrgwvIPCMQRiCMQRdY3We0z2lpVAOl7V4//pUVJomNr3iLqihhxmTD7rRa9qmr6N5uXhghjbD0FbS9nYcXdYKqAO9xm81V+HSv1iWHro1ntM1TUCd+sOhlMn64Ncdia1Fiy07KEfQOyYzOReeCSmYDuN/eXnbBC5SWThUDi8omy3SwbFTW4++krYeiIuFvdhKQY+71xvblHAFYH0CB735Daf8cBn/8C/hpouv4GWUnyLwXRh+wg811JcUHO6dW6UkkMbAyahomTh/Z6e1/oktm6qV7SB9Nb+X3LSx4lPw/mp+co9mL3qpA77xuNsPRKMLBhkE1o5VaFGA1cDTq0mKhfJQ7yIv8DuMdyGWnIJLl/gdxjuMdx5MgTG2HtM9pn1h/tjAJjbD2me0z6rWsMmNozz0ndpntM9pntP2nK6zZXLLCpeUzAdxjt/BNRRkMfeYx3GO4x3GO4x3GO4x3GO3wAAD61f7ec2dwB9/lmcK+pxvIRCeGlGDIkRnhN/JLIWlxNrzIPohC8bnjyd48aROs5993Db5Ha1RDVndLWmNbonhsezBSs8cMcG+M+yri7j/yaT9jDxZ2VuE4UMhr7O/oSVmuiR6YEi+O1ECRc1b9N0UxYnXy1pxiSZgZ3IxMc7GycCAt7cataWh8Xsn8PzO+UVG60v31Zkirbvv1rLpIxMmG7LOHrPooz0sEdZQQXqUKUNc+B+MIt/7154K98quVeWEVQWGBvwYIc/nBKr8q58ZltQwddpxsHPDcMANGw9FEi6p/BRzD02YgKQ9Zj/gf5gXjMBIUlJtaGJQzDgzjDh/5zGIpsyOh8RW+0z6S9BgLC+0Wi6fcMhP0KIwyjO3Z+H/v3qMfHT4kQv5hvdig2J+7i1QxmkyveVorf2ZvLzSBiK1/bNGyW0C+7HCAXAq4dNlK/u6hoH6Y8dHiA4jRsdFp4APrsWQAABlqrvql6DDcmBJBYbJ/NDR4IYQcih1XPVek6D1inZ8a4C3au8QzinmwgHCAbeBqqMBwg2rWwf8XFN0PhsqNg2upMAAAAAA=",
+    "UklGRmIWAABXRUJQVlA4IFYWAABwyQCdASosASwBPp1GnUslo6KhpVPcgLATiWVr00q8p/SMSbzOjNw50Slj8YrfRHl8nZWUw/zala/oPfHPrvVi/bnxs4PpNudeLYwHnbFr7nf2XjaYR3pwtloMKHnRGf//rj+/yduMdHnNue4ZvRUSNOnAgF7g6t8aRXCEYKCXxHAdfDh6rEesqQ0a57TFkWswpvvoJqaguHRHnC5asPOUuS/m1dHE5d7i1ovqZqqi1GHZlDFP0UFc22X67Ug88F6iWwM4XZ2p28MGmSMJU5YKjup68fJCBpP0zFTzOK/i/1J8cSm+7/4jo6HKhmJ34yiBVvbdkDjWavXbpnZx152ZsSNyoAVZde7P5PzLOjGp+OoBgFcJcomwFOvI6I6g0xRDIqYOt4DHbBC8CZywnUpZcEwJFr8LoeGGwAo5OHimZSQII1QD1oRJvgfsMVDPThHJxqwyAmF9meNF0rVls8YydM7012cjItEj8X9rAAvTmxv3qKVG2N3C0NtG7H1n5gQeoIpHiwJN4fueTGILuxy4Gj+eQthYlE09j8n3qoKKgJi/JvlCVee3Dl8zwROn1C8eUG72fzL1AnWtiP3MWktx8v1VKfoRTn7E7NyPucWkxc7w31sbwaCG9LQLG2cBrmu/8eEzZ2XLEDC/O8svwfDNIP3DD4+omYLkO+xhcuBWnxig86m5uABqn7FZdJH4sktNqS+rHf1Vo542ozuJI7FnrbYqP4eWws75gpP/RjLE1aoW6/7oUXRa52J60T3PnS2tJ2TeDK7LoDdar/Rbr3Kx5xGblqzZe1Up0MMhyDKde6lxpUMHPomQZiPQXKkOvEXNOYj/ovSO1cQtAUc4lrIs4QMSRz82hCdy0o5kGFhSweMK6L0f/CnEVGC3iNNcQX/vSxOKBNBXxSteps5c5r+OynxmkYi25KFutk+rvAG4RTbQskcq4UYRq/lot/G2C9HqBfDjQG3D3RfYNW+m50A2C1LVi3rKWMV0k92fKmGN0YMWifROQ43sb1yGt2FWoP3Om4NItQ0aqC8L2Rx95MqLrfZyn8624mmtadZ7O7wRLdHccWn2o64NZ/PxZ8wvjsADZMYvRFTcdH65BV9qNbkADjsRItvKiv9PoUf6Hl2bHOAcQ15varzR/JpQcuMGhqerVS5honQsRQUC0fT0df7QRRp8vD+wiKMU9dp1oE4Bp5kiRzIIAkGcBDmKw46IQtRZoEmZDOH3Eya74XBdofPfbRNtO/q2Gcd/onyWcavItv/yzXufKltzpJ03h9/+I0SOBI9P/MkeZSg5qRv2q1JovV0/+yMR6Vy+28Hj/NO0BOWWU1JHf3Z79qG6ha2F21hf/7Cf38Ogj/5enDoRvcnoEwb6o+Yvf/2O5qemFB9QhybvFjjbWqlO7mHCYRSKAyFc/uCglfS3DYx4w5eWEKHHzVmT9Rry47p7BqXlIVsvhcq8DwPe8rj8FTfsMgorMo3OKY82Rn1Ji0zi3ApcH7GGHMNgwN9xsS1dHqG30VwMeCJJJtetrSIV1gv/m4lfgpyTo6aClkpbt1oiiH0tcV830loAPlHzSMqZb68q4SnOtOTpx0801fY04rQdCWvz6Di+rHzEy8Dh/+Yxv7ZTDSHtEFEQPA2ugnBudMKBanez5I1h+dl6GDXZR8G6bSiX099VGAiI8lyuPl3Rkff6z78lW9jwJnsZ8/z5/+u01fZH+n0JOX02yPBUAlwsDFpdGbzbhzS8D8Re8/nEMpTMCGNesKeEv2Ouh+XD417e+GWX4H41D/5iGo5lmNeCBpOkmlV8dI4rZVr/5H+mtfLxvVDlF//ipWye5HhbRc9iwESvPalxCQznZwHuFMS/RllWxfgjd+My/XAubGtN5zVKF+0NpS5FDB4h4dxkbsvg1gkd1QPPssq373iCFjq4QGw54mdnZzZ/KgBpZpkh6ulLmtiuyNTPxneZmN3+UEae9d82fUyhpj3myU7JJC0VXIpUtuI4fYc7ePWWklscbAYu6jRkcwiVF1v1uFQ8S6u6lubX0WBoNhXIzXuHSVQLiYbiCjYEvQEFFoug6iKjakcoMZ4TpFNJTW4pSue2cMbJ2cEKqnaoMCzCJRsg7u24p/uhUFFDJzbmvHPJEWW5UlpVYFSeScaXnY2FtpwCgdLLsueJEDsB+AAA/rRTr5jp/fiPegsb96NSwkonliyWd4amPAiCoV4nhhACKGz208shnvxsgWzBeF8OcrOe/k8q5ZnKompI6eHM+fbAh3LBdh5v1sI7rxBtNAPhN4Mf3hQpU7pCzf9Y6SknZhrirauONkGRw5cCatM9Cs+dhwZlPw6LJoSo7UiVAi2a6EeHNIXPA+LMp6ZF/YwCKu7sQEJPKtNw58KCIcPslwFigSFRsID91K6WpE9xOCSvTKnNqFYnNboVTpUVMUmygh01F5mcA59LYYLkRZzoS//5MrN+oZDR6o9UtD9rp6wELNxkbxkqIog3HIehVOoRZtSYTfkgPmZcYmrXSxYp/GLXNX2iWmo8JBurDJmgCdxx2Cr79paifaNCi0sfxpCflxX1B4HMSgHtA7OTe04oPIXQl7TEmczqYL26FIdj1+N4I663us4D98geq4kfAlHEoag9CiJdSxXip3iBVhOlq6Thk2tRPdl3Nf6nxGvPAV3S7XdL93oERJGy1lgDTCMJ+YZkbFoAG+CtIulpix8DzT1FKfh9fI2I1Qb9VFO2Z4/uVuwgzE0PbakAXpzBzJUME31DjEpuNOMUS5BWL3xsTTyGq2Ljp/3xXcfm3mHxFnY01j3z2/mI6da1JsgH1y0axLPTKTHB119wzyobWtUyMgWcIf8BkwbtLQ8ORzKYuzKxEMBkKokCVmZnRa16G6NyF3Y0WAwxtbFpDZcuTMFIBkWgvvOhKMs1UNE/h9akL7oiJ9OrEg1fB95ZzZtmLg1GPDGxt/j57JyOc2uFjZd7Yr6vRIJ7IDMa6+cJez5oCp/PIwFw2trawxPR5lK88DYtlbWkcxRxBljioCnhKvhTY9cyIARJHgkvt+JOC1fqDsddlq02ifLbfP0ud1NKAB6aThDl8YUwzddg2B7ayFvntA8x9O99xtipdogJ3ztyc1ZAs4syYKdzzniKAA9JkmQAyboCBWmIOmEEoOdZ8QLfuL4E/3PMW13SH0P6a2d18J9HYu0iA+ZwyzmK2CA7YxoYUYI15dsn27eI8OhGIEw17uJqr+gjOS2PQ9Pdhl0oRGmEnEcMPIMHqaFg69eaRShuRR2Hrx+d9KNvL327NUO1WkscS+qpv5XXIuH53Q3D5P0v4HN+0Cse+nybr4NSjA/fDtZUzlYHCsSZMO6au5QPvYW4D3KPHwV7VxSiJDALJtIhpI6+ICrGjF4JnTUV4Ld+KbA/FpmZcIOwhwWuAH2M/fdUa7jjwGJiKsxdMM/oilfy+9N17iHQooGFHRMOCVMrd45IGRZM7NHJ+QD5YW3SswqyNBS8Nf07Hui0piM/dGf4VUvu0vKf3iVPMHgyrQE2Gj/EkpXJ5hINiFOrS0/BdiwOgZ2qc+36o++EzIVLs/edA0vzJ58pm07GzpGgWnnvzk7TzOSfDtAS8FiJI1LFpuZ4pgm8DVaBTww+E/ZCmVq9ex5sYeiIPdVcYdqN1b4gFM9ayYWYJOGl23cu3NlHsKokuxNgqjNQhMcbys20TJ9PjtokzXqtEUBWGl0XG6CyXMfOKqT/05lpPprU+LRuR/p/ygFchp+RzJHoULWiXciLFBV+zxrmqTagTwfK79MEfbV49Xw0NpXEEHjjWOtTKXUgJC/HCBYputMQLDl7ZX7JzBJj3YUAcZxNNHWPLYegNwFY8lkFt50FvBPcfOWRTJBMkxA6M3QCPzzaZ6u71YtnhTd4pTAvPGOsWUQCxUIrQCnyRiyup2SZl+oUuUKMvHl8hld+clrs0iBRJCEEV6YM6APSdQsj5Wh0pDHzS9DrAlNCiXRYvckBD1UtJuihhmBc5kdc9gPYbTSYmYykt2jQIADFJGW7lQ/esT3Ky0pUdj4sfbouFRpm2XDj/5QaY0QJ6ceGS9ttvN0S9q6a91pp4v2wnQQJCytnKdEFfkXaABU/Pd4G4PPIe9xgPj7V0rGbddtGpv04JJlHqEe9uvXrZIJ+wePJ+KGPeNf6PJYQouQEks8LapHvstawRBUxZAwmNJ72MzOxe59AwoEW04tisz28TRNSG8XbL4TBBw/LJDv7muaslYtlbxcFJYJ3R1GN5oGyMiW7usxlL1lMDO8e7I8fN1u6O4zKN7OLEct3lfCHvcfzQIKYT1VlH56yrpkQ+nZRfiZAJ+cEfmWuKvz3J/dC1uCRV6HM1zhV4bMVx2Xajiu62yxyVN2ahXQr/6TTi4LPESWECwRNv/3qkOoWZRz8w5A3cyUpFbUjLkiONibD84uMYan8H7VpHpSxq/1jbzxAk8uHgMV13bNbn/iHw9zN7c4HVBueEmNnRtiLupbV0EUkko8HmBFWQivVzJeFX2OjZELNPVeglYuIuQk9n5sa9NAxtxkN473sLyP0kphORANBJnEujQQJGQFOQ83rX8VnUtlFyJLCot015nLp6KbE7P/eo32LopUaC51BFpwbHqKzPpHF5QmGS+tHEmulnMGXN0glAELDjXVwibxO1rBglnr4GXU4YMilUTSXGKgar3lS/UreqoZGO75SsGtBsrLBslBxS7lSHMYS9K9efqufcUcOdYp3eQhN5vEGyWllx7xZ8BOoXtZutjlhJFb17/mHFDmbBqA55QiEXbUhHvoJMPIN+3S/bsfHW3IF4HlZ2iK4NSBWeFLNURcZqZaOlseCCWdq2LV8FrPKiiAIsWkz6a+eAYkELWBOHe0ZLHV0WwxZyH11tk03HEnl8fH0qMqfxdT8+ZujO7q+A/KhGyo3NdDb5hVOfMrQGaq1TeUY5yfzHQfSjTy9HDcqjpSK6X+sKd7RjfkRTMNVqdSfe3FgwQD9v/vkvdyFQt3XKbqgMDhcY4aE1gVQo4SPSau4HYNp+US3+Ioqv8/HtbgjIeZ27ijPm0rnjhQM8OFQTKeaEHwRARckKXxR8QDIb2ddlbF1CCZC/WPG+jyBUKUT/l+4TFOQKrwMihIP3uV2LG+uIk09bxHDZ9s3Q7aRtrcSaqiQc11syvojzqAdRJUb5x2+x3ISn1NHDep+3qP5Jv5ZwqI45g1UqRVbBwg02ffxNkaQNI8rjU8/yNrKV7N4eP1FPLJuT/CUcyrGEWqS+8x9B4/QruoowyM0SDsNNpodlQH3sxfhYCkGSHrkjC7G0PwdqZVodqc1/BCoxeHIgYwwWv94WScec/aP+FX99H8ynlo3z1vJJ/AbnG6SBYYraNkPT8GcQ2s87kFjvVrwmwdxpCj6r7AWQKCD8UnqRO5yGWnHPkP6qDV91DFNvAwUBBRXi/IoRHb2bzseWKp7XcwvMMq1DCwpEtRW6K+yCJ9RZaK7IuNghZ0en4Tpc/l22PYVzC9U10MJF0G5CWDP/Q+Vbt5XgG8FEdvIolpuxF+z4p4kivsPf1stSDZNANwLiGLEs4wGD2ypgA89XvVjapoNcao+D6jnhEgJVTFcqawkqmf/6KsLZXoMn+PiiO/7s9YJhNpM2tl7m70va7nLIPpfoyQ6AvY5jFdUB0rW2UM5jCZpPNM13hF5P0JSIQ7kWxD+y/xOCF4VxwHv1wpYZGpseeSlRz+8C7kQlgGV5u5hkmXXX9jJ77HYuiahqjdCHPL+giMiN1602tRr6biL0Ihw3S1eewEzVQbtX1JyA3Rut0xk7h9CUVMY0d/QZtJ5Tkc217nthK71vJ9kK5FKZcKVk0yhEpSF1vvDV4PwmJasXIZAqA8x4+Di9v6kvPXBJiTn32IZnkirlpC4GGG6eoyH+jrGrgFnffNdpsoV3TkQBDaK4S/5Fo/RhLtoZfyzqdw+9CgFWjtb54rtFMxvj7QikmBkwcAFFDzms1fVrwRPknrEV7ZCdwsN0osMwlEPkoXTnlwTXD2nRt/MHS7Fg5a502yVqRRPnHzCKDm0MFG3ZBjA6RNnRGyBLDTk1jtNFZtVBZl3Hue6ZMQuaUuNRjNsduJXHpfykg1+bZJwqDY9/8ZTwFBAM/Hm/Hep4OjwubS6HwWkUKPumDBYLk63Rqyi+8awAwN4ZgNMhkA/HOOfMGXASYjf0kLlBhu5y8i4pWxpb6SWOJLjSDfVc7ZVE8lL1KVwDMsN5hi3j9fQ/AVf1Njcn2ku16oIjZo/gob0N8JZ+92cJ+zwcf54FjI1O1pWlcPRC2BSmcmfMVO6WZWnsUnjxONgr+BClZRUlZ6awjAr0ygJEXfoZ7CONXczAvfAFiFx+93wMPwe0AXfnfbLkA+vnbvDMdRRQosUMuiO9eLBuRNg1oOkIYrkRGzdl920W4HdLDv1lpot1SxP2fNOg1+X5tXwgbUFJDEVTegVW1r7Xg+NSr07wtJFEDriCocB4ryYLU7rlN1XxDCmSZtP6IrATUoN0JCj7vyCSzA+bYYThEnoeR/HGMhd4Bs1Fm3eyZ8x6+zsSYwj65vnTAa8/VrF44xkd0A9cWqa29QLJXsJsNExRhgeIsnMOSZOdjpclC7D2O11O/hIZTijuhv7BbKf23ef9ksF52bvk93KkRY/DaD5GhBMX1Huqo3s/RKdNfMH/+nQGF6X0lKKk+zNgICmF2uACtsI/M4oBnzV5e4MdaRksfpu/w8a2wh5EOcVfDi9KeVuciqcDq/LabMzhmRIZnoGVDQ0TmTdg5lM4+OyMn8HdTAaIvxJijFladZmfkwrh7rDpbIBvE7qgvOsdVxyEWOpt6bGva4bw2IKU85pKrQwQTGzYZ1WnC65P+F//3an7Dk64mJndyxO37kEGbOAM84hUXkNYOgBm0UPSJHxss0N1NFVEfo/2SnAeZIEXbzKCaosieuLXH6HD4ubpkSDjsf/TorQY6Fxw83H8KrlZfnh/LAYS2VEsGMZ3tuxyg1a+JW1g4KAJF1N+FrpKdSGaS1ag/NpeEDQLkQFxzJRu+TZ91REG3gDA6arEcoeyBEUCaaTX6l0btk7eUv0mKw/FpbaNtP9+EToZIP/HBpN+Q0KTJraruS1aRPaIMBaO8316g5kIhzyBU4DNbUFUPV+gDd/kazxoJ5TP5c51fDhkg52W9+Jwca6Yvpz/NgZo8adV/v6giKR7DtmiARMlK4D0yfhfNWkdYZG4l5pO4cT75WHMWHF3gKGDyrUptMIAicCytsbWRfp/Ex+36GVRX+wj1k/4LPXA4D1+g/gJvkQi3MjhQtpxZifNIW/BoPptaKwkyJWZUUZUFon+Cb3aWlVm9jRtN8rIQ6HqVw+O3pb8zIIh9HoejVSPiugWjeALzfYE1PRZcyDZ0khDPon/LKF5hi1PGBbRMAWDu20efH6sTa6LGsWwD9MNBZ4m7L4u8pGTtbTKD5J9km+IL9oYaJBuWLm92xPMI1uDZ1n4ENEgb40wwfkhvs19xbjNk5T7R+xs9P4JiDtZu9KcUwNhqwLMBFgyv9d8e6JCDdYIiG4t2zRCk5HjzI/87nPp7m2hwhExGAyRjL1Ufbpdc7Ic1mgBwPe65UUm2dx8UrjOXvU4UqYDHAhr8HviFvW0LSNzpqjs5YDsemF3wkGUAA=",
+    "UklGRsgUAABXRUJQVlA4ILwUAABwywCdASosASwBPp1InUwlpCKiI7JcILATiWczkH7lUAU3ixR0MTXgSHj8rJZNoBsAn8eoDvHJ5Zvf1x9pVzrw3Fr84cgPPR3S/svHDwzXBGDXlhopfRF///rg++04R/FbT+OwUw1XTMDrJl0lah0WOP3giIXmh5X68b8JSkDifpNIOPl3VfqeKwV0zQhz09Ucm/5IUTe8wHhQtQPswE4qwtVXxHXZ5v8quEuqL0xkH9UTQ01Qa5p5sjH498NJ2aPiBZZN/hgamyyJb4MH1qx2Y6hSG/PzHhOl2AeJy00bEog3t70xdqaJQVTcXxrHbH6YYrcirOfEcNfu8tyY3QDjMPBdm6Wd3TWDPEDTEJ3aQ+UdmCNNG5gayl6JXkkzZhqpa8C1U2RC5jIfbdTw8rNVkFipVgy82i3vf4zsi5DXsA48IpqMoDWltw8HOo9eWjTuLI+jF8GcpxGS19iVlEdbAiACJudTwCpMOB2FMoHXemJ8knCefGIOpvTiI0Z9GXtIwfKcoolbin5fsf16soGbhNh60qDAwk9t/2gdq1woJ9pN8D5or8P89zSSrt73bLwZAF5n6x5noYup7GUerQdZsyd1sAfgTDNlulgMHFjNsMz2/uUsNRF9TtQWbd5rBriXkfJWHAkOTYPS5sNqNnvjBdDvbnNNwg/U6Xsb5QudI2EQKyYutbxN2UOoCGC4AHJxUI0J2JF63cUeU1zZRwBDeF/9xCz3hnQpDKDdvdOvtuL6kUGiSex2sf3WMf7TIwLnwoGcxPYfQTiEdMBGh2MSIE+CAC9iRqrtMi5oZoDOmlhOUaQhcBN6xJvfpK5ZJuMAYHAm8ukyH4gN4jiGjFNMDKli9mlpjROJ2woxNrSSWfEFGedMytQzk9D9x0cT3nV5TqnncOG/2K5iaQysu0fLeVugREUBmWzuRaIx52uk9rDSiuDtJmoe/l9f6jF2VDBkmkpaH5whufTPzWaNg23+/ZIrWR7zAl7pe5Du+q8L8Ke0VLnI5vDON2GqNae3CIkgh7zzl41WnVWxBWrygvVjk/dZjJ48ZI6nYX9jHmgy8fiRiNZBHMXL0z3cnLKe/it3Phbzmtq7gcO+Dd5ZI8dnEC5In9u3fuCPnCH4H9aZyq5okWkFi+h/7C7+hLyCM6UHW0Kq2Xf0dLxykN7fO62cWJNqGFhzcjV3aXW8CUQRLT+2QwNGJlWbC4jpVk7A8Yq6Rtg3Ie/ePz11XbSAwWywFyaeFmKNLr04iaHWg2+2fvFmRZ7TtiiEZ+2xT0NFbEwEYypnws39neFMrH60xMPNY7F7plDX0ZImCg3z6oh532OBledcxOECOl0EaodkHCxZ5YMIjdtYX/+wm8+UsL/ubTjmab8uk5Y+1zXW4irOa/QjxLxMwgoX+OKuphZLSBFzgbq576sI0lbfy0apFAx/W4amHXtYvbn/cYmL/059o4/DFKrv8d2/8fHN+b/AkYYNm9/n4ly/l0RIKuq+VNQ7iPfnV4feP+h6ERnuXWFGtIRYbPsZ8bqPf8ItUGn4f7Qu7regcB0xwYNuZvocpDJ9pf7q1wwgdd3nFTmeWR0Vv1g3+LX6aa7TAHcdKSiWjY/3eUd2/kkVrU4wqE/0/+hYI+V8IohfzEm7XK0bdQBQKBBYePLYx+UjWXGV6XYinlGQS6G/LqSYVJYAfn8iqNAKh55ZsY4b/javyVbtvlkzRsRr1zf/1gqO/+kGVaoprunv3ZClwEOgFmgwGz7LwAZ42xYwKkiB0AJDj5SrKeZa1NiS2SidltPPEoI512nzGYrfa+/Cj+jOatz/6p2ZfwPv/6EDMSfTx4fL8I/9jqvml1pJs14t/H3zzwrm+2v4HzjCJlclyXHHoiizc4kYvR+5/aQiifWwYiyIEC/b8yXlVQNIhPXuUKUIDjExmkBh6C0jmXCCs58M23EeXkyp/td474BvMjlh/8+ObeqgaK0FuM+oS1Lo3mwQfT1r1rLFD5PJlw/dzbn9/V6Rd3YZw/Ws8j+9EyBsG8R/v+LVL/n2mB60n+uRbosdxtKK5GiTE8xwJFMXH/snb3ipgAwckgINwxmcCm0mo6NRvFfi3ikOabl6l3u9bisEvgor7cd2nh7W8R02BaBu5lcK20nmR7vg8VNYnJKAdhNQio7tSDFgeXd52vxGO0esL/glkhA0dXVMVCNAAP0nwuID3NdP+N/6K/uv8Hv6+/FHm/r1wduhtkyc4apZ2DkAWBojlUOEpk7IoPOGPuE2EoEgqjQrvcKxmipVaW9s/Iwf3Tac6f91vD5bEZzeFR2QJhh1e3otDsVWYc52cDOUUtpYZjjWPCmwLAOWjeHt7n25IFdkZlneK8d1Ma1He3d56pHxRoWNCLeK3wUjPdIBPS+jDkpahiWKtR2/T/IXU6/1mu9TsHsCpRDITUkEpzJXxRp6AqAO2D2r5pSPMipYVcVaiMByl9fr3tphnGgu2Ruy0ewPwe5AtNjRQWCJkiCXuD/TzponuNg8srK3OdpP0yC4BOlY/oNIqstdONPb2ybyr1M2b94Xm6oM4B12VdMoPdqVzIe4YE30Y4cBAkhzCg+3v1bGYQIA0uCzmZki5CTN/U0z2t50YVBC5T4UQ5iISfBChQ73L8oRJIfNmGUmZ3eY5pzXXjuc7/0LGB118eRW+amELyILbejWa0oZevcE9EUA473bR0SMHxgK1w14dli3zf0Pr5dzsE2Ka08bJ3IqkOoIAPCeFGqeHHyJfz8WMRlwyAuxNYE0NdJe7FFaY0bzBFtsFL6AmtHfcfJNFlBFYwS61ijwx8+QnIqo5m3BN7t2xH1X517+e0SJKHXI8LXK5glzZm5Xgx2GBS7LPV5MNubuGcPVUwMi4eFXryQRZILBlm0SrGcBNFdmOGIbwzWi/4PcJKKdYBiM9FL1u1S8UVzswjOTiRIgqIXdD3/W3UPmbMvc8h6j49bFeGI64Co45V9XmQSTvhTgp4LSlmsVlGXycM8d6eZBVPP6j8Tz+Vl1abTLMBf9jG6CDi7ArxbDjFxlHHYofuJOypSgvpTUyodDk9WRGUOIAtuEX5SOWtkIm08EBTZ2Gm/tNKZ1+yrn1ZMKsv2UWtiOPPhr864P7rSEhjUl5zk44KOJKm5v9DS/Tfdm7f21GXTNvLvYxmjQ1D9kKt/LzEiYUB5g+78YS7E0umV1ldIu/eewPECoxrPWUiJp4sRFPyhYDDnJ5dzCx0zlIE52NQb1A7CDVz4VoZY+Toa5GCHknJwxzaE+DOrkL0wkIgoaUcwJYTD4482gdmxuyqe9O3rZHRBE8SW4IdtZFd80x/lC7dMgerysmR73hASIpXvmBEo6KU4K8/+kukqupngHlk6lBs4emMJzLQDGpGrnAF8F6l34hQyvT8ZyiXJmxfsNNhxSEEdhD0RwgDAR9WEwl84l7M0emPxzu7zl2kiu2AzHCZiHKZoO4W+Oikweh/eHAgLAaFL+9E0+eA3/fXbx0JGLRUJnunZ6P04mdo46oeKU+lUdEJMdfuYB77GPec5QBx+9GQMOazOkswM0E1Q0C6ZBBjIlvHGCxhJ6I109JPlSEBELewpCavnJY+9xsu5TlCuLAI63dnON0MS6Q86t0/1fzeseeA6WPk/WvSPnJByxyknYIVuVbKPsCkHVLB6//0QlfFRzIUKaXMbLX8tt3mda6xxy55VPn/be6VWezf71JwkHKXfng1Vx9Yw2NxXO2xBQIPbK8gzuCDdY7hsKIN/Xs3EuzFmONZp96raE8dWuf03Whu/jdNFZdlyToimuVvzZG0nrGE+JhO7HWuDL4spwbIpMdFQGKo6DSeawWCmXQDZFluEwLzuumN9gB/D2JV/MxvPU59mpY6Xjaz8kC0UQrMJ8pN20qVWnslYlheVFgD6kPiAOzIBevFYYRjzNhMftvTS7+sIHSbC6naK04O5vXn1SA5dSuYoeT8nIgVathfSTmIR8ydwfhLr1khHXG0yHW9YvTBCoQpseEPFE69FB8r9pPX7YFwGjDOQAUm1RFloXqLIZouN8Sg2neI7Vj6UqCI1bizFzRXP8B3WhCacxPiFRqZnrMK9mp8A44OE8dUIl3810h1qlSqUeIb0YWRhUYATXNKQ4silyWlWI7VO1INucOnYbS6nCNvIcL114PWgdZTz6fi4jDTfvzpn9/I1O/FIPx5sn2ptLTOagn12UVDltd5RjGiHloCO/9zHo0it2RDq3J68B37rGuRgeVTiC78+2iMrL2+TI14Tj4+fObAyc50V+/ZZEMvxP0o4d1gr6kGHB8O03MaeD0y7NuQqL8lQMqbWs1XvZ69F0uyFBxUb+nP2d6xiFZgNv8SXaHKn7Vxqjv6kvzJrejHOzujHGLHjKdLv8lqdLMQQjSI8aXTLLjA922uioGwUdrOiaqt1pLSTufSer2KxFsYkPEOFMYZVUFhM/8u0e5P3G0sw89gjyYLkMe0qGsWMu0l87k3gsd6bpsbEeL+2dg80Ba1qXkeG3JN+pZoT+1ngyjPjv4fpLx2u2Lm4DtCSOpgHqJK0k8IQBrJYE0DX37Z0Monm0peewBAQVx5eGRnSJwsSAA1FX9EvpKZ79wLqxFPNw67zdSs9+CKlldUZi6Yr8d+5FrkkWzSoAcGN0zIq5nImxEF11ZuPHjoSwosN7glJnGkRoF5LXFxu0fwcOH05KPdf/Yv6a9mgtrKeGlkYSSr5j51/iB1au+e6HD+2fKWO0I/Qnk1GR0nIBP3acFCy8SrejvAcnLiHPlqncJn82z4v0gZ6QBjqMfKuRFAAhL0P6K4h+vq297bvnDCKGXL/c38RukOW1CA+Iek/egFenQoWl/h8TkPEOWS0PJ7Y55ZcvMzcxOCKVEMg6PIsgSm7NHo9dK8tZScSptjPQUXvyNwjLVWRRdL91g/CY4DqMLfBR3cPX2On14W2JUAeAphgz4kK4ekG9nuYE97qR7xCyvXKJKkVe2+6CyBoU2iQyNn39h0iwf5y6BDDrm3Pr6YBwrAFU3+8rUeEG+q2myGphh9vBPtl0Ds3KupXn70oTcO/GSSHnBbYLFzS/6pwpggXkOQgcZQ3bHkdpGoR75HqGFgncvmoTKskaS9b9J62ReoWKGElucdRTXP4wZgY9r8UdoIhc9OAO0h1/zuBdV99vRekBv243YsH9ChlP7wYlkYVr8Szf6TgKbZJdDeYt/tcoqS/1hfgNVOQc/g+ExBAX6sXNFdzZtMP0g0DkIpFxPMui4MLXCkCkIpqNquOLedb/QRPvg8XkXWhPABchAGYzRswrM3M4Ch/OKmLqtP+Kf/3Vza/bPMef8wen4RqpGhkoA+P3o87/qMC8S1goyuMoTZoGrsV5r6KBfsWFN40jtYqQK6r7l5zlBrfu9CSKFzMNnL7QvCji5/FzhN9+wY4iRyfUm8BqAALiBcY1cfdi+FDcefip0Rd//L0cYuYpuJaArGdbq8yQmt831VWZQpV1GfuCpXY/ckApoJdKlx70G/AFT9p3vRPBZwyGmlIXFzVBmixb3dPxFRqOBhfNCcNzWJ9vsFExm0PNRu1liIWRn68GYK98SunhJKFQ1GPx7H9S9Bk5JFONE7q5y+vJYedZWzEYwSakLcLMp3iulgbnQj/9i5IKZ/ZM6WBpMoJE6kSxXafEM2MIk+WA9mICPCnN05jgqqeGrrWYgiGX3QucWIuC/eLY4x+Bw4m382mJ3+B+TL1zQXCWva2zvAX2fTnu/rF3lh/trcocVCFfHMX7CdI2ClDeGqFDiBra1LY4OAHg0OTB05rLEzMF/j8aNFK3G5Jfi3e+tUgIZDwD1rXEHcc1eXMoWvmtaz6h7Y92vxRSqLCbEm2tqgUvdTDLm1HwdS/P1k8qaXD88Zub1Zknz9sAwSbMcnlOeWtHDAlZqgeJjtl+IEC/FJJE0e2pvpXJ0LGlT8To3xRvUtSEQNiQDMQtc58/Mx+poOKgiI/rLxyxNhsLEsUp60zmB7aAZbiA54IvVt20EuM670HzLYJYCryRkTJwtU+FhvrRWoKCuP5nGbJBFudEWaS/bPzkJpj/i+Giv4crJDtjYepRu1MMGTe++yw6icCoL5T2kTwGdvzOv1SFvNxgwaMbZBzA1ROR34fc1We1E0Pl5J5mQlFUNtdCF6cYJnA7q0EvXJzOibYiC3VQgtmCRToTnFhTWg/flk/5D09k81jefFJMv7Da4rRkEtPIXVhAEw8lpWXLqqKrtKz4hjdNoyg//OFrQa/UtvTwcHMQRvJR+qexjjDNvblT8nfmm7i9P+jgOB0X4X892L7d3H6ETVU+qh0hdgLXR/VNgdJz3aVh8HtIwP/2en/xiRiKkYSnQkqNa3n4jEU0kvguz5PcsQ/QQNKsuKvyl5WNZgDAVBDk2HDK3N4g5iocCUcK7LVb/fQ25MVXumSRQPbZ/nj0Y7rrpciyM8Vk+Z4RcQNhtNjVXqjPJ1bBRaKeeYU2vCCcwhVrVqzYuNap+L6F1sU2jMEXvGNstEMfXt8nV1F6lI6DcqUvNQ8a99gxLUcuQzTmCRXj1BzI0Hxd8B1Tynyc50RVhLAIjU2PAGP6HaPMiqXOx6Iq91nJaQJYLQ5IAYWTjrJhX1uXPtdFmQpQ1hMSQjUyIfz+Cgbd8QIgL83zkHLDw6o65FE2/vUK/7Mv0V6avTyigew1T1kM9LHcpxusLE96Y1meXHyMJWe2DuFXdJHzXuBVVa2CGINh9C1Ui+1ZV7zqfczsliShGoaK9TnPnLL3E+4Sxm16ECmdT/4PD2LQINbgM5FU19K5myUwlTeHCeS8momrvfjVTv7jux7M/NBwIb+a5Ung/6njV59lJ1zkhLsfCyt3v2AF+v2Pl/55IvrrBE/DPemsrEGfchkvtjW8RPJ0HMvlJzJYISukZryOTvqK84EaCSBPUYHgMvvSQDXYLl2RJ2vcv4OqbPAGkcFjaBPUK9D/pXrpI/m7XEjjjgv1nWc5SGUzJpnBxJnNmDT6VlQAh98XjO+326lT0k13Nc4R0Hlpnrq4/bzpjvJ2nrWToAf/pikFNiofElYa8ppaJyI/bIl3QK0FF6a+5OMm7s5865Tr5DTYwO0VGmJLwDgA",
+    "UklGRiIMAABXRUJQVlA4IBYMAABwmACdASosASwBPp1Cn0yoKqoipFBbCVATiWVrIKGbySx9AYX8CAbQAzPv2X8zdIefQ5H+xOZ/Wvz1CpXf/b9uf3E3+A+jzj69s/v94k0frQH//7Q0/YAmkBdRGNZ7V9VPBgtK3OmRWtoXVOvzIO3sjrACjohv52z1amxYt/YjSuL60SZDhsr4jkHU3NUKXqh84oJmNMRtEQzlFzQyw/JyYSoDnF4hQ01YJYpZhynyhUT0Q8y6Pr25izK677W4ZHTLXGcr6rEXgHURPHQ2D8H5F+hn+tlCzXoiP4koGfH4AJzYYXrxGhQpar1DieTi75fAipEVqCgsKjxvRHW5RwseKCeMirKkhOIadk2CUas5wAKZuJB+msntn+pcSlNdcfRADBqBmsuU2hwjlJLCbO6chaci6FmdZckU3R7szu+2wwcxeuxz0Hmyaj7ZzIMduphobBlfMjQBpWS11sCiUbEg80Nexb0DSgqEt+jqun5ybofC7Tm6Pm2i/Joc0UktlV1FmF/D9emt4wCJNyQxa174wydTGzSUIpbclYgTNaXg4lZEJmKPSdJqcnbd7spQwwVcuidVOzrVyIa7qj6FIWotpAeAzfEjuzjNa/FTWelHg6eTBO+T4ybIau922RK+mgvpwa41DXb5qyknb4dKWF/jJq1HOTdLa11kZ+z1vCEc31GXsJJCqoXD2INJUZfvAw/S0VL/se6EsPXmRD66E6azfcAeqPz1Ct3gnSrR+8QdmDa4oeSzdZNkuSs4LkkzyKUA53lX4Qh8Ivbl7WuJ/isx34m4pqRD09fEfaqu7bHKyHkM4w86r1iIO704CKKyAHnGhEadAqXJ2iyNcPAYacsdyKerIDtdn1HbY3g3OR/6a9YtwqRmmtScs1kc1mzcDwFghXEKtBUfg9M8an1ZR6Bz/zODmXMHkNRtPlg5+D56OetGdeOTZUA8NBqjzP2FY2EjFMOTqOfcrAUoxAR+twDCx5kxv4E+H/Ob+hC51WowLLVBWGa0XQ2xhBbhcT8j0qzg0K8GgWmtMnz0vSron3PVfLnqtlYndpdNcPPXuvhhj7jv2vS5hnY9dFeCIfH3/V2hlTPPZ+4US+wL6Y3yVv1/SF82ZRAXgOOtBBQp3g0VlFMfysASe/Uz5uH5+6Pqkm+9umb/8/P3+/T6hBdSurj6sCdRbVn3YS8HMa9FN21BhAaFfnydhh2NgH8jUWFS1Xom6B6AskIB3LXgDi6QYkeFONxZXC/XBkHZjEJwO+anpNcsgMw5HN5uNVAJApWeNu4D/4RbQgcATpOEd8NPPdORwitJx3FKL+U7pytQVwZcPnVk0KQOWuxWWkAlqvwbWwGVhndFjicwe/kOiV+U3Bs148eTHjOaQJaRCl2SoUnjzvbp/321SEJK7JwCN0DlEoKc9weP2/mmVtCC1NYtxqqC55PyhNmlkauUdHiE0iOZcKR0Eu+LjN7ii8mzvlxbzfeXTahpP3xaqr1hSJ/rTc5Ixx5kZcgomXgmepbqGpPjYlhlrBuvyj8boIOWZDtOf7BPlUG9MH7XQ2z3btdYLyR2ZWc/ah1UMpn6MnlaitI3fHqaaY4BWuDv8E+AaBijbjPRXocj1Ge9l5jgm6hizFoYWFGSHYwARmk/6KAAAP78oxkdTZH/BPgT4fbbSVhsbDS7rJn75jeoIt24+73ydQNV/91a15k+twRQmbs7NTms3z2KV6lLJ3eGPKgFy7n2sE0idZKpgMctvUqP6Fm+KybvREJdCTz/Nx526OFar22JV5bU6Xex90VnJ4FlIH6gaOqQ8rfE0s1D7BOBWXObdwN0M6vQaQ1R0s35TXeMGItINxxsCGz5rUnK3P3/KUQaSCY8P5Y92F+QxbhKoFXnHOrRb6IcV3rC8IuBUMQaZXY+n9k5yjI5drn9XaYQY4TaGJR4d2g+AJ9EvBg5kCI4kak3jfYbX4lh+qovVbH/Ffp5uy5v74IMHX3Vx8k1fjNCbF+uqcsh+A0W4/DxXEuaJ65Y3Wy7+o2jGXdK+m8oXYNsAVqAcHW97AzHx41tvCkasBlvrnnqEzHUuiuIn0w9O0F8Hc9dRjlKwc57Geh+CI9ILIHpI8+FxSJJFfOW6Q4X38ZuZ21VXfGBvIUMOKMjnk9a7aVpto1/4NSfYPElipXkSdPaR4/lt18YrRA2MhW/8TIPuqstR8k6kZVtrOFd/lD/8Ett18A/87vS3BzlcMrxUdzmgLLbvGahM+SDqUt8dVjy4Qj4/yejaWHMHjMWaL/axFELJlIoi394aQynuwwnlyRyJEP1P55GDDaj6+mQ4pPelz73H10B9iGnIUfiFFUg7za+7GWVM6zrFgI9Di8rqYcFzkMwtDOtDcK9tmy9l1iuAnmArreRoLQBVGA8VPG+OHiJT2EXhIRHWLPDzchAOn344EwDD4ewLLCshAjoSW+3AQ9hK10zBTRh15atfNwni1kX0fCCeEKydpCasYdd/PwjwrYKi4MI/Acja1zlOfrOCsPZIZ0Sf/QPv2Wa+w++gjmwYC0zD35kfSAcIA+oMIsmlp0yDMYsRC15xkgwk50rjTAJxZeppxflczDbidKrgLSv3vpsK4P81TfLO5b9hKQu3orba5oIfSgBbCo/kbmrjqVbSIRwWuvUiFC8YCfBpjMVkUVnlmC3TF7SOJfT79jo85o31C0oIj1u4dYox2+uJBI51sBTJd3tbinTvnL16So+kS9NfoKUV4IJ2fNvEJ3g34JVitBniVUsiKXN3EGgoISa7yKsjWJD11mhaOdqysOaytKNImYMOSpWrz/B0FZE6ZIRbjP5FZR7sTYaNNvM2vq28fajImYUue2yXphzfAtn2gGtMxlAADglSmmlakatRNHrefrcmb1q0jbJkKvVY2kT+bwQHoU3jZ2vWyfu+7stlSn9jtOVGKm3wDkNm8nLOd1mqpNevbe220IPj5M0+OlIKR1CSCgEi2TgnbDrjlRn+j0ctjxgRMeqlDgFw51dXxA7ywhpl4ICP+ZqlRKBmALmjcAByPBAEAch3vkxEcQaOdl8lHdZEQCWnQQCrbcRwuc06dxl+bVIigZPhfUtAOIUCRl5f0dh2aHPqX5NCAlcic6vr69BxIDKieEPAFZFrkLlr4mQRcPMp9AGdZxm1VwhsFGTjgm5/+v4gOYqBVKgS+jVlIlq2EtAZGVX19L18ymuQ+31ACopbZCJrFV0/Hc7EsrYk85wHANRA/YB6wDt50e5kzCdMhFGtr37oJRRmrmYeTn8if5EIZxHuaDDpYEhVuHRb5GOUNUduy1AY8yJ+rjXGwJ9Uy+FDDjiImB+N0HWCwL1gYQOedQDcXXVpRFYqBYSUthmy8wcyS+/0ARgA4q92laUSATHI7/FWtOJftQ2MifN5SKn1Pau7OzV8w9bacL6eGFqTvFfpITyOSF1+IOtUsCRLhbSD5LELfEx4asayZ6cGeGqTwgIv88oObhGXHjnYpXIFRWrpetcyB8JpCbdiCg8kCnhWDjrFCmI2c0alGCb+iaYFQ7azwDB/uVlIat55dtYtNt/XBzJR6/EHKi+zScczKAbd5C6cpe/v87kgVEuIeT2j55/6OAYjp6vbKm6DGuiZVfzZ/ezXcbRSqfLflueGBaFDBoRk24fNCQ5xc3RdTFBvDe+ztTzej55F6CyftTXv8TF5mHVQeO/EP0BlnhE2R2bFaRSs1sPJkYJcbj9qyUh9BvwE2I+QsoVLen1X5n5VmpACF7Sd1Gc93fI36wQ1Z3vd7JGl+u1kdePt+heJO58MgFnBwh/wv4a3KMNqBFuS8T9Z97Y2KNLHo4Wt5PC+LNC3qD9v8N3pngyNyBcA5tfd3aHjmhg5ktwNXSlTbwLNzOomMafExPEB7oyge+gxdR0UDmvllDEL/wBFXua/a5ssaalVvJxLusXHy9JCydBzAPFInkjZ3cSXsA1HT5DH6woqLR+E4xiddBc1kl1mcibbJz9FP8oKZuIYN0Glh3ltpUK8CPa6IWettyh6FOOzu+eigNWJe5AXZhd9FTzGASOG3OhPL6kqrTgR7lyNXcNtG+GyyDh1gSibBRsETuUjVNG1VW8tnvGEPp8+pxZn3B0KkZNe+pUzHI14tytp3VEAvNAtBROhTWwwQAA",
+    "UklGRs4cAABXRUJQVlA4IMIcAABQ0gCdASosASwBPp1Em0olo6IhqrLsmLATiWQzkH7rT6VBjICDEa3eU1plPvFv9Usz6v+HnyfOk5f8f+AvW7vBswdP/2z/8/1o/1X0lvSv6fPOD5yWnpf1PU5svA/Wr2rfG+6H4Kh19+0WjuXxQUOn33QL3cdP1RC6I//+9cf3yZ9KzyY/J4i3mMAuPJP+zyTc1Hoe8P2eeogxquzCuxX66AwZA7P69zOZP5wAMnosL5FsY4w/+F5b4Je3O5IQUT6u/jXEgZMYohWj4Hg4bEz5M+LbwAhdrVgLevxbVj8fYPf6IJUomtEVnblCCoqO9DLtfSX3W1FOMM1jlE6w5mnoJ2oFPhV9czy8p6kqh7UgTKx+uaomcBY914dulsrRzWKltDAqaHdzeh1FgOJLVybCAKRPDPi+3uYGsrjchGKVoiT5QfAzTaz8sW/FyOmqm5vdc7MhajnrhKTJqLJhI96bz7joZWXx/2HgyfCcSposk73ud+Ww7mO4En31jcFV3bf7xZVs4oDJaR3FJmfzWQI8Yny5HxF2SaoApfO7+iUl7RdXqQ3pZIDvnoFv4EonL/0rk/eum60duem2SLVTlvpv6H9ZCPYdkCbSQFcsjwgWjT6StzecxDq9sqWg8+4sTZKAa7sZcxqUiPsOYxlHJQXxT7NVdGjj4LvL1vOQ8+oi2AKRMUYJeu/3ZWlOWyk1Mw0CUjkQfdbwPsEiHOzf43fR+WlC50JfiNzSrMaPLJpCb4ET0jwD5Ts/098a8MIoj/6V+L1d4ppMVrFqNPUcbcXCdolXkVYN01phAbSXdAn2IvvOa9TcsTVqhb0SAUXSPMInxmvbUxMEVtV3jzWeuugB6p+R6wY+Pfoiwz378VG0q58TGrS98SslUQ8V+qpLeiQLdBi5+Je8nXI+G9uIOQBWGBB1SkowUk8H/bh0lG+6GWPSQ/jPqIF6LxVAGi8Hm5IsIdg+5UnN/68CV+cW9iZi1iDKoYq0ZBWtg2CoID6/0yneD7S2VdLvfET7FMQ5+BQGdbYjFh6kFjE0NeuvZG7bcvBhFSWLEf21DjeOpUJIAl9qfJOSFKyOhrrmZfHR7Fgn/vuyqOBWVCc7rJFY77G1CG0kUVDCJ8Mco5gBJFMrn+eph7fuNa0+tHn57ce/aP2cfhEDM4PQX1ipgrRemzNh6yxgZHV+rUDy3An8ABfiaygtLRrfmJWvVVRL+qLQuFNY0Ov3ll5tzG9lj/Wg9F/Kk9gDTKX6UkbdiuLxLt04AYRIlKK+X06GCebZ4wTz42GQJyS7OTxB1nPLlnQu7WZWJQ4bnLhbUWilnkQSo0tOZmHZEZu7KWVZX1C63vhHh5jq/w65X3IGVZ5nQjQ8m/kyDZVh/sPaiIvYVV0elS0ivRIslWVHz8KO7CbKLBldGhfstDmcbYXny4zjDX6B7f5Xpp/zQIwpOyZ4iQVd2I+OiQWbRLk6GsWw69tZ9FEnE4+6uBP4K6wKuz2R8d84WpAIU53N++tjwiEUtP0jbWpeHtOF4nixBEe03hPIH7+1T/Dh9n4SnYyu/54aFOgh5EcvwjkDqo/1A0Oy2fCVveLtjVgyl4vezSt9s8/lMyxnz6l6m/NJo8HO9R+zICaahAYj+r3oufOfR1CN2dggkwfadssedlRd9PfbtGjEI+w3vR4DGtvPOicmidekiM/R9tRp+2eoZWqbBr/T9A8bU1SeBeJKVxFmVqa+iFWqyqt5ocMDglcgF0ioTKMZI+S+nVvkus3uKxkBFlRgWEaD3btOBMbgg7T+7gW+n76cfGfnDM4v6f/xNtf+DB4nEb9j45pf5nbboqArqMPM+Lv/asrLq4LlDXpfFdvEXVE1EnlRmrgENB7fNkLttQ9EsME8dcuJk6Kg2IwDG7vcj//5mKF9etkuLUNffPG3DIZ3tvbQM58TBCFKSD06jOabvDF9C7gVgqy9cx2NHMcvtucSN9fABsa6C8tCWLT2XziHuoZJ1El8qxkZlsTOGEDkmQrkQMvOHB/6aAnuU0nPx8qKek2Aqm8nC1r4lL2pnNEdnnoFhLi4c6aUkZcE5e/A8RdoNH4qsqElYq7maqgZ8QD1JGrCdmVKG6EIcBJ7+To4Rsi8468npfELTufm3YEDJVRDXMZClJL46YmP3X2HoHbR2yu6CFFEJRy3s8PzSe5rrdRi5KZusQH2MoCS/LnM5CM+appQ7Cmbh/9MZU6eyLlcXSk1hzF65KzpFFL3+I/fkyh76lrXk+5Qq2ffzc+uAAD+6JXlhsohe6KKBUH/8mIFG3f+DoE2DN1i5UXoO+UjsxWd3ZC8lQlLtYxmYU7AImUCp6n7+/55JDx6kHS6Znj1glaISrt6xSDS/a60jMt6UfFtgnnL92G8zLbKXfWTQ/DCH3hs5DqDkdz4+QMyrcFOz+Cf7pWDWBei+pgJAbp4A3BTQeJ76PpctQPuhWyXOO3ZpQZHU4kAO3LWnGBe1bKNa+qDfLv6EBrNRWWApEls01kd0DOCmnqiCGGfygDcrPZ5tKm2G73rd5xkxilfLFmwFwx6Oh8IcCtSiQDTTt6ZXu7JrYSouFzNVldOtY7PbUPCGulcM60QRCaKJ2Tg/BAy3pMw9aZw19RTuXxsvcv8mrYc//imhfpqxHbuTQWxpMqXSyFq83daFcSbjuaPSL6htNgyYYF5TlowDdJadkiFmOniZUy8V6hA07i+bSHk65rfgM0tyfW6/lY/uPuxUxSObWC9HZeLEAqXznfjARWYh9HkbWbX3Hh9deAbPW4YKSlfExGbF+1rbahZpgaI05jetRFkntp8r8roof/SNBiubX4yDlDWDcWjRaQJh8cL6uOOYKBLn5ObqQpswQxWYahIOhHJxlSPJaIfuBfgzdp8HMJP/ibrRyjE6vMGiOQs10f341T9KRrx8lHVrSRkjliT2LhX7hvx4pEzY/8fNflb0/g+QHcVx8NN9v2qjLDJ+mX/SReAnyU/zRo/iSITsoNOoSqr3+MmOKzf+htbrKy4Eh66hDy2klkKAEEZclzzep3Xgh8G9gGdZnEU2i8FBhqby64QoOeUSSROxz41cNld4HwoeM3EmF7h9OF06pAmsCUO70r4K8328twoatrvkHWO51+lq8k/bgrVbVDn+0UoArjYPIR19Bs9cmIgszEefYGQzIFRseR5Meh0bZMdivbblqpsoHr9yuUDFhyDgNBREQLpXVLuO2rQMjNv0GhY4HxynUIpNqvLn2hUIRzBTU73L0LUDM+wCmEubDmZnaLmxKGwlKFppLTaUfXkbWdp0oRqbXUoUsxfo+upVQehJaEFdgVAk6FBWKcXij1nBSOG28qEYF8reFUQxQcRpmfKB0QsEXlYm3d6hqq8ZUwBKgOZWz/juKWH5c/QstxAiA9I2lVe2GahOZ5dTvhzmAPtAwIgFh118NdthoJ/UUl+1VizAqK+mEKVmd7yET7YU77q7y5ayu+Zi9YATkeDFUFRwvryit9KbGleE1jJTu+oP+FSfA14DzDHyXEvdnj+EcgClf6nzkTDROTQOvCvzepFw3iJoDBDZGGljwLF9lkp+UzBtc6D5zSTn4wdwAniLwIHpbT48vSyvvs6xqZkhQ2VjpbO6aoSgBGy0NX3v4fB59cvsLtNSjAiYGs+/AeImCnnDXR/txBvai4/+NQmxYDzRKdrQQCKQOguuo80zpGDevyArVTlDNAUPrVGhJi6WrziHt1Oy41QPRZSZlveQeJX/dZqBTEbt5/zYXWrgRB2EbVabkswxTpBPW1/qrOGODaAiSO+RN7/28X7p9T0qqlU6OrW/Pt0FpY6x7+lOisHa+LUzqfj6Cn6Xubbc3vzbk2hUoM8NJJlolx5EmhLNDn/KkPBXqwzH4I0IbOeMFpm0RKqk9hBQSn3h/cprhQTT2mvTa2d0NxpV47P67qJL2wNvNgBXhd8uZJWDG6HPJRi3WuDPgXR8Mwn36R4XHBwKl4WLKQs314Bs6j7FEVh5zMlSy/66v/spxc5AUdKl2CSR+M+ET2MV5OKaIgG8tW80SdXIPoygaFbvpebQSA/zWTCVIG/03AzlpSZqcy5RR7w8+sWuB8qutcIRmMwMEp7I+k4VybSwhXrG4LOdzQI5uHg2EzBIjxu/rvtlVVWg6FVmDmlercO0+gv07AuMYaPFSLX7mqNqQK5nxbAmFeyUQGAnaRK6fLNbpZjTVErJSuc+ssjhW6VC3gFGVfq40XplZOjH2rE4K6jBcHruOTwJxwk73jv38F6a9E1Hve97sRr4FOTI/i7HlMvDhlm+e0HEeuprZIjj9pqAPe0FAlBrQsMadMMkjErzG96uFgmLO0162irZ+V1LaFjqJdpLeJMMIzFuZd7qTARWRBBn7Psxtwr5mD5SwIHNQqZ1to6kSZhy9Thfoo37FcwdVD7so7tAKVec7zDwWUAdbDhwr8jtsUybA7/BGj5ZmiRvjEuC/CrVsoJvH1SAKS0H+TEc8N1rUWouTCbU4KO9Zm8OyaVHRosEvyQqT12u0fcfeTTQIX+5cL04gMyKIc0KrFhfoRsRxXQqUD+mPV6hgFpEfLDz8H03MnBOQBnlCiKus1FB2iekqLxiOTuWLBIuKWCZJ59igQ8/uh1P+Inss1GQbvOtaIEbDvQWkTXoFiC8Sg1L3wzZuKfCxqB1TBgUJ7O0tOnxml+9ZADYokZ54okMvfwC1VTMD9fVbEx6+Z62AFEVKYeP6b3gPn3nuEOHCxXtdUwQKKMP3Rc4wP8PUMgOPiSJMbCDhLCeW34x14J2oAJIyL8k85SM2lhCj8xVvBqb8eD0swNHs9FM1EHpuEYTo/Fve80WQNflPRKO57xNaeIqcFGKCuIFjSDFN38gZSwWRTu47jtR7OUyljeRlK0SzuAR97fHX2at5urfdE4/+bcbb+mtduNTt+ZBt3uj1Z7VUDOEApr04g8St1tT6NlT5M4+x2FhQ4gi/ug0k1IDofNvwvBfZ8A1g8PrmnNFFbd3ooIz9s2NPHO5++4rCLM3hplEl0FsIQRkjbRe1onydo+Nl/HYU2rF9z3rtFsnoJk/bZWsmc72Yc8H4/Wpr2cUtb4rKbtZ2kCUos0hnjS4udAD4UAJqnTh20oY4E5yZ2KAvutACCMEfDNVABHmny7YRjK5UjjwRkS/NoL7O0T6BVcKpnx3RWp4GxbDndpIX+2JlzH8aiWr0sP83JcS9BaUx8GdW/sZnbALb7QQu4PvoFy3XgfnFgVV9oFJk5/PdDQ6cf14yxOb+6m7MZbO5LLdGxqx8AcRmYYNDFmruXYR5bDrWUg4ExxvUTKr0EhSuwiGFBXwbmE749XUggjIjjLTXfH4K/gtIcEGAiIndXUsrJrw4AgTrnPG8ueFZ6g2569fXlP1QHa3zCjPFDKFLzlB7lzfYOZLAAknf5C1zvQP5UBdHeYCAkFSWCBHatb9LPfBKcoRZYGwcR4B9pQ5fXUNSLkxyet0JXoBQp+drYimF/qGCdmgJjJSelhOY4Vkje43r/SdzeDTm4/OHkqBlRRVTaDY/qpA+ovmJRk2EtSGsQlGlD1Du8kUUuh+BsKFk7Ggyp3GuSXGZLZWKW46oZzkOj/WJydbqSm2oqHNUXWqrwBwEeMjoE2qjchqIqwfKqvI8ewrle9Cu9lOIBkV1nR5jzRPQSQ7ZVXeckGM6uQJLc3qEHIAB9sZjYLSALlPnKDuZGG0zMpNOzsTsXbyRUF59efP7Yk1guoKMreV1d+sHjClEit10GxdUd1yJOVKKbaX67cV1zJ0UO2Zbo2a+nWbfiQ6rgFwXJMqzaBdwkTc8cqGW27uc1CIxx22BkJ8HJZslPBxiqaY71tN0MLg0FMEnef1DqfSXA19Ycx7wpDxX0GgJXCza60Ed5FeU/op3ICvbbrIDd2Q0xE2ZyFjvFXWdKDGn9V+MF98+6qID50Tjiqle8UE4e43WgYk0SBFGvSlBOWvtJNQu5hlqu/+G42byS82+RSFeVboIFrUYTIGKwm/rvWrixY1Fy34vatsAmM9LDkgGr/RXa8yLYHfJnyrmo9jyZcaLFchCTIGxDVqjU5+rhTmx2i6WVp9T1wiwg0JfMYBRZB2z6qlqRtmqtgp+c39RIDrVGD6/l5U7oPvfRT3O5rxv8rDQnqvyQQyVXBJmeqXjhLSb0DyCqeUnZCkdakPxJjx2ZIJJoR9ZKd0wLfl/X1ujFuGrerC/AbuqJP76TltcF4c+420YNDTwW9atnhoTlnllTaERT+Op8SruOyemAfw9/VPQb5TIynDQO/S3qD73jTvyIyPwSWl0umUjxy3ezuKT5PS88snWlpayX9/RGvSeCAekYS4GULb6StZEmIS74Ye5mVVI98kYkBsPIhwdyTE/0S3qZljdiREPvmBJL01PkkMd3WBe+zw668ETgvvWzH90LlKUuO/0z2L8fj5oms9T6Aj6HbSswPBspw0sky64b40zlPm+cydS/BUiU4S0FJjRFbrr3MQ0GwSz+1YJQaRaxUorVJqcDffLM3Nb/Q3KY7TVdH844AKhI0sGYYwwanSuvknbozXLEra9OyIQrXU6QTVxkaO5lxxBMg1Hb+p92GePbAmjb8nEULvprifva2iIvmwc6ELz2HI89nlB5peyoZA7RsWIvfll2hZfl9vr+Id3EDbDwKsFpqrGMrsRHvf/O6Yx7x5RJQI9dwJUgutZRWTOmP2KFzsoZ97BWCd1vJQAnbCkRBVAwECC0JXBLbHrA1/rJl+VugkUSEhschVvH8/WGlW0gbxzpbJixIMFbGe+6Ql71SZ+6LDnx9/gtBHjYbl+KxMQVjEtq05/WXrKW8fr1cCaYsvX4xOAK0viQotRnI0jwf3tKg8u58igMpK7i2dyFAfFpLL+dODxBaALLW3DYP7rTxw5L+SJw9r4EN6a69eodHS3/cRVd7DyGAMH2KpBSSacOQqpnxJ1GNOeYDtMq1LBPQyanRXxIqzlCz8let3hN3DEeM6mpRDTHQnjhPUYuugwZ3xU2r6lofDwB6UVL81xv4xo0RCyeCaX7MCn+qCdgm95aDNWwVFM0SoUmj48NIipqF34phVRUcuTSDqag4OU+VXC+JYasjMW1ZrsbD8gXW5h0Hv/36QV1X89mDpcd/xQVlm6dPqnO4MSL52e7PVV6mRXTgUFiRf8npXS9hyei7HCOocpSgI1xWyAoukRy8Jsdt4jmPD902P5/TyeH224o2njPhdPFg4ZdYQzCrNFX8OMWx1eq1L/MtHyOIrbSjtztpxmXKeAV4LMsGeqMxGVRFSNxkn8oSs4Z2s8BVMLBGyx5D57ef4m/7grmYll/PnXjZqz8+qGz1M8GUBFrU+B0ryzWJu2p8K3i7ixpI3Zyk2Mz3cclX62DohVvaZS9is76tEJblXsHx/8r5pILiTAvP/OquVxvkoQnpbCv5nGPH3LwlRFHNZdfxvt1tc+z4dzwhOv9ZWbtcyZkObEjYHzpHuiN6b8mp8HIaZ3kiK0Ffcb2KS4NzPyNMilg6Exs1ZmhQZVfIqQBdjT+mgYCrt7XJKMZtbfgFbRGJ0fTRYMrebqZImevc1EIwnky8XJ3OBw5MC4bEATC9yjUmuJcsxoP6WdIlYGfk7rtkhqoFBy95BNjgelpZ0VByLmKpEuPRSwugD31JXqTBlFxmxXklnirawE3Hobvq/E7EDq1CFxpBT50NFPedtHNduNlJ05WBT/aXQpC3gDnOlWH+z0y/lo9MwVIDG1cyztvq1Ty7xPed7g2nZZKNrigPufNs2xNM0V+uM4FYctqS359DSKasQssvVDN2yMX/rQQsP7XgGP/xob2Z+P29uGoD4SeS6tk7m17WdUQJTPaB843ig0Ew3YMNSqj4XWg0ewy14JaWK2wqbqgMKAY4B58SNmf0vd8UaG9Zdt3repa9307iK8r8IIeatpVusfSq/D0iNgJ5t+zv8h03zFitLdF7hVBbJ4vSIRQQbBNf0Uclod/TK47Ngr4q28iTqMwBp5YQIlGX+y2NbhDvOVX04EeDpr+/z54hCLRk0sVEEYDN/a4k3RIi2zz8+4edlfStzyXCdgn1uXVnhdGfIWE9zwy7MHigEAgfMDIKcvdo2fhqHQTE2Sch6KcXRH8ooVti+DINzToxVbB2YdDrByRv2vfqEM4lsp0+zadEPdDgtYkKD4ySPuZgeQOt4G8XKyrXC1nrcDU+zl18s5OU/N59v2aMhMRkkeYfYyh/eDYeHSfdaD6eADqC7eSznLOQrCcwWDwUbxMHo0jTMM+Lymu8F9aC8kcrD26Mm1nDuwq9tLaTrmQ5gdwhP/4qrLTkBH+xgiknA9toWKw1/uzP3VItZAf/fkABvAcWaWUoQPiQuHlaQY86Gk6TTjay46FMcBdCfs1oiXPvtFw7sZXBTVbWqEjGYAQVmUJchtFG11GmrtIeINbRQsGmZHL7ez8SwHTpG9HDeEQ7LAHGZtBan27U7Nrera4osqeUR/Q3LqA/qpI/eisM9UxsHDmKvOwd1JcwJqc4pCYsBX5JtneWnylD+KJdBv2o0w8Dmeyu7YbvsfjmVN3Y8ynlAyFMTgetRGVXS3ZidfL6pGtP3tP7wODW8bFfZXrY9EBdXMWkF0NiBCNZyOztNrtUxRTVkUfxni5hudpLaGiONzoqVyszg4tLRrydPQjqMuUSQ8Tj0l6xTFYdeqDCdyODrxll7puFXS/aosGlizNarDItlEe51bSGxZqc+L3Zv41+XAeFuTal9knDYJLWAzJiyX2qlhnN0LZOOFtlUMBPLJz0PWYLnplvtPJdnE35E4NfNmvkJqjNp3v59NM9Sbvmdb1XN+fRW8YUCAhDHAGVGUC28Rq6LrpWI7Ap481w3yl0+PIxIfCApNp7W7Xkq2Bvz459NHahVpFwRgY3YWtu09g59tXHT4Gx1PPBLegYct0/UyStY47EjhG4CZCxs9iu9t0RuVUja8M27ZLE7x/aVevc5uFfvgjruVE6ooP3A3NrgVRtFZoNJmQNXe+lFkvMdcE+ZHtd6QxJs2VIs6w39Pyx+PLA8SxsBzx/cgOp36qZSLVDpU2ejlvmTAdI7nSHzxww4us6w0wF42SGR1h+k/hNW0vDzlAveBqrJwtomviXSasL8EYD/d5ZSAhUvsdafeH7dacn9tGnDP5pE13w9s9IP8X+7sdmhyhFgJ/7eKvTSZlRGn4twuSqELA/cNzKVRjnQ8LAEnwyCItxvA40DwqrxsFErzsp/D8+2MhfEIegW9UlM0rq+gZt0987AWkULbCapzYJpwSKVlE3v2Q4U7JE9QHo0676tND4JRJW+hZYDO+ZR3i+bn2ZQ5JRNkFhU7b23mrZqIoI5cHVrgbbza9inU6Pm7rC0GY1qe7eqGfZbJY50wec8ZBPEzzcD6LpoNVeCpjg6TfJKYS3/smYNAR1iWoiqtaJnKVgdYSrhbOKTZU3P+flyJFW9F141p6Cz+lRbbtLSrTqAL0AKLFfb5m95lSh406WcfFsyH/cx663Diygto4T5M1r4Dus3I0Tprj79CGgezpPpSzM0TNvoEICrWp2kkI63/9oV7Zf9L1hS5P9RvO4BNXKEG0yi1ZgQVeSUpsRkDZWCLRxpRpbmhJoQ9mBVOFa3dBpu9GjQYJbzj/g93P6HmHEQKyz7QrMD1XMTLVzvxQdU0gDE8hZSeF5E+o701U2pPOBrTHVXSJiKVj5BEahYjP/pYjJewIlvbFjNoXPCyD4DuDWEK3ExasB9dqgMdu99lO8TKcBzC/IQG+1xaVjSLqb/x+18JqrECgVivE7W4Sh5VZAh6ZSAIAiv9C+YH9pHHuj2TjFy4NCKxk2Sz4WZA7I6qgd8GNAAAA=",
+    "UklGRjADAABXRUJQVlA4ICQDAACwMQCdASosASwBPp1Oo0ylpKOiJHkooLATiWlu4XHhCPN1odgCg60zMLaOI63SM4kps6pJYn8f5sVvDEd2CgbNxth7ZgDucVqPoT+QU9JJAHYxr+ixFRnH7Wy/3xbSiQ+aHKrrrCKrrJjEznKMRifxaIJJIkW8W5tPDI/KP7q/vguUErJ80VGZrZgDrL9BN+scQ3yI6pP3kwdv9psjR5uCUCKh+ncwRKbOqSagN7+9FNtoJkZIQVgiEfdqViBcR++mBGIaK+W2sCojRNhpj8igszJb1Ii/b0jJjWnzc7/YiW6g5htsPTjuEiXN2krVMutEfWzIOSIEp8manvGYZnkTfkkC62zO1sjxtJn/AgKadwaHmDKP3hCOyu0DfUafi84ZGViIn8gtW4INj79pnIREWNVSXiyLURU3l5cJJYn8tuifxySS5f4Hc6pJYoBeNUksT+QU9sxoyNiOjuL/A7nVJLGQmbngQf5KE7G42w9swB3PmDGXUSZ/gdzqklifqwk3ZgrCOwbD2zAHc6pJYn8gp7ZgDuNAAP77BP/8O3NyJy/0l0uM6bbygBOW+tn9PJ7vCoPOEx2z64mXogAF6vKdXcmsS01Hf6P4YxsqqUGOsXz+yeOy7B/7TZGAQiNxgEZj2MO08u5MSyKa93yGKTIlETRmVY7NQx0YLx7QX9OjSIlyZ+8R3ifAa5BJwo0d4sCftV3Bvk++NW03qm5Ii4Sx1jsEU7360JjWpfKJJUfLzHh7IB8Td+VYuLqM54HwXpmqG962mP0E4H0iuKpEm3sxYmZ65ZzxjPaQ8MbaP4eqKFqszaXGW5MkwW33ny6J8dSky+LY628tSihKAzNF6xvsbCIQ913npEu0pg4eFPkFxxolfjx3AaFRn+PG2M1t3dt0jANwqcxLoA4qKCrQ0QhSTrH25avcC6zKTuy6kIgYoBA1tIADrllCrB3TB+27GsuQ9WIihs+wAOeg4AAGjGrZyxgWWZ/w7vz/7PYZ60MIO+ZXGle0KOjuWWh7vXOC+BNL9kN2aJjy86XL/KvlYm2A43oGgm30vslXVBEpWl5PP4AAAAA=",
 ];
@@ -0,0 +1,157 @@
+//! Renders many robots in parallel on the current rayon thread pool. Use
+//! `rayon::ThreadPool::install` to run a batch on another pool.
+
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::cache::CachedParts;
+use crate::error::Error;
+use crate::provider::EmbeddedParts;
+use crate::{OutputFormat, RgbaImage, RoboHash, RoboHashBuilder};
+
+/// Renders a robot for each input, built by `configure` from `RoboHashBuilder::new`
+/// of the input. Results are in the order of the inputs.
+pub fn render<I, C>(inputs: I, configure: C) -> Vec<Result<RgbaImage, Error>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+    C: for<'a> Fn(RoboHashBuilder<'a>) -> RoboHashBuilder<'a> + Sync,
+{
+    run(inputs, configure, |robo_hash| robo_hash.render())
+}
+
+/// Encodes a robot for each input, see `render`.
+pub fn assemble<I, C>(inputs: I, format: OutputFormat, configure: C) -> Vec<Result<Vec<u8>, Error>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+    C: for<'a> Fn(RoboHashBuilder<'a>) -> RoboHashBuilder<'a> + Sync,
+{
+    run(inputs, configure, |robo_hash| robo_hash.assemble(format))
+}
+
+/// Base64 PNG of a robot for each input, like `RoboHash::assemble_base64`.
+pub fn assemble_base64<I, C>(inputs: I, configure: C) -> Vec<Result<String, Error>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+    C: for<'a> Fn(RoboHashBuilder<'a>) -> RoboHashBuilder<'a> + Sync,
+{
+    run(inputs, configure, |robo_hash| robo_hash.assemble_base64())
+}
+
+/// The builders start with the embedded parts behind a `CachedParts` shared by the
+/// batch, `configure` may set another provider, wrapped in a `CachedParts` to reuse
+/// the decoded parts between robots.
+fn run<I, C, T, F>(inputs: I, configure: C, output: F) -> Vec<Result<T, Error>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+    C: for<'a> Fn(RoboHashBuilder<'a>) -> RoboHashBuilder<'a> + Sync,
+    T: Send,
+    F: Fn(&RoboHash) -> Result<T, Error> + Sync,
+{
+    let parts_provider = Arc::new(CachedParts::new(EmbeddedParts::new()));
+    inputs
+        .into_iter()
+        .collect::<Vec<I::Item>>()
+        .into_par_iter()
+        .map(|text| {
+            let builder =
+                RoboHashBuilder::new(text.as_ref()).with_parts_provider(parts_provider.clone());
+            let robo_hash = configure(builder).build()?;
+            output(&robo_hash)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recolor::{Palette, Recoloring};
+    use crate::RoboSet;
+
+    #[test]
+    fn assemble_base64_returns_the_robots_in_the_order_of_the_inputs() {
+        // arrange
+        let inputs = ["alice", "bob", "carol", "dave", "erin", "frank"];
+        // act
+        let robots = assemble_base64(inputs, |builder| builder.with_size(32, 32));
+        // assert
+        let expected = inputs
+            .iter()
+            .map(|text| {
+                RoboHashBuilder::new(text)
+                    .with_size(32, 32)
+                    .build()
+                    .unwrap()
+                    .assemble_base64()
+                    .unwrap()
+            })
+            .collect::<Vec<String>>();
+        let robots = robots
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<String>>();
+        assert_eq!(robots, expected);
+    }
+
+    #[test]
+    fn render_matches_the_robots_of_the_same_builder() {
+        // arrange
+        fn configure(builder: RoboHashBuilder<'_>) -> RoboHashBuilder<'_> {
+            builder
+                .with_size(32, 32)
+                .with_hue(120)
+                .with_recoloring(Recoloring::Palette(Palette::default()))
+        }
+        // act
+        let robots = render(["alice", "bob"], configure);
+        // assert
+        let expected = ["alice", "bob"]
+            .iter()
+            .map(|text| {
+                configure(RoboHashBuilder::new(text))
+                    .build()
+                    .unwrap()
+                    .render()
+                    .unwrap()
+            })
+            .collect::<Vec<RgbaImage>>();
+        let robots = robots
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<RgbaImage>>();
+        assert_eq!(robots, expected);
+    }
+
+    #[test]
+    fn render_returns_an_error_for_each_failing_input() {
+        // arrange
+        let inputs = vec![String::from("alice"), String::from("bob")];
+        // act
+        let robots = render(inputs, |builder| builder.with_set(RoboSet::Set3));
+        // assert
+        assert_eq!(robots.len(), 2);
+        assert!(robots
+            .iter()
+            .all(|robot| robot.as_ref().err().unwrap().to_string()
+                == Error::RoboSetUnavailable(String::from("set3")).to_string()));
+    }
+
+    #[test]
+    fn assemble_shares_the_parts_provider_between_robots() {
+        // arrange
+        let parts = Arc::new(CachedParts::new(EmbeddedParts::new()));
+        // act
+        let robots = assemble(["alice", "alice"], OutputFormat::Png, |builder| {
+            builder.with_size(16, 16).with_parts_provider(parts.clone())
+        });
+        // assert
+        assert!(robots.iter().all(|robot| robot.is_ok()));
+        let stats = parts.stats();
+        assert_eq!(stats.resized_hits + stats.resized_misses, 12);
+        assert!(stats.resized_hits > 0);
+    }
+}
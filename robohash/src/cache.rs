@@ -0,0 +1,321 @@
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use image::{DynamicImage, RgbaImage};
+use lru::LruCache;
+
+use crate::catalog::{RoboSet, SetCatalog};
+use crate::describe::Description;
+use crate::error::Error;
+use crate::image::resize;
+use crate::provider::PartsProvider;
+
+/// A decoded part takes `width * height * 4` bytes, 360 KB for the 300x300 set1 art.
+pub const DEFAULT_DECODED_CAPACITY: usize = 64;
+pub const DEFAULT_RESIZED_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Image {
+    Part {
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    },
+    Background(usize),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub decoded_hits: u64,
+    pub decoded_misses: u64,
+    pub resized_hits: u64,
+    pub resized_misses: u64,
+}
+
+/// Wraps a provider with two bounded LRU caches: decoded images and images resized
+/// to the requested size. Share it between builders to share the caches:
+///
+/// ```
+/// use std::sync::Arc;
+/// use robohash::cache::CachedParts;
+/// use robohash::provider::EmbeddedParts;
+/// use robohash::RoboHashBuilder;
+///
+/// let parts = Arc::new(CachedParts::new(EmbeddedParts::new()));
+/// for _ in 0..2 {
+///     let robo_hash = RoboHashBuilder::new("reckless")
+///         .with_parts_provider(parts.clone())
+///         .build()
+///         .unwrap();
+///     robo_hash.assemble_base64().unwrap();
+/// }
+/// // 5 parts and a background
+/// assert_eq!(parts.stats().resized_hits, 6);
+/// ```
+pub struct CachedParts<P> {
+    provider: P,
+    decoded: Lru<Image, DynamicImage>,
+    resized: Lru<(Image, u32, u32), RgbaImage>,
+}
+
+impl<P: PartsProvider> CachedParts<P> {
+    pub fn new(provider: P) -> Self {
+        Self::with_capacity(provider, DEFAULT_DECODED_CAPACITY, DEFAULT_RESIZED_CAPACITY)
+    }
+
+    /// Capacities are numbers of images, a capacity of 0 is raised to 1.
+    pub fn with_capacity(provider: P, decoded: usize, resized: usize) -> Self {
+        Self {
+            provider,
+            decoded: Lru::new(decoded),
+            resized: Lru::new(resized),
+        }
+    }
+
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            decoded_hits: self.decoded.hits.load(Ordering::Relaxed),
+            decoded_misses: self.decoded.misses.load(Ordering::Relaxed),
+            resized_hits: self.resized.hits.load(Ordering::Relaxed),
+            resized_misses: self.resized.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Empties both caches, the statistics are kept.
+    pub fn clear(&self) {
+        self.decoded.lock().clear();
+        self.resized.lock().clear();
+    }
+
+    fn decoded(&self, image: Image) -> Result<Arc<DynamicImage>, Error> {
+        self.decoded.get_or_insert(image, || {
+            let decoded = match image {
+                Image::Part {
+                    set,
+                    color,
+                    layer,
+                    index,
+                } => self.provider.part(set, color, layer, index)?,
+                Image::Background(index) => self.provider.background(index)?,
+            };
+            Ok(Arc::new(decoded))
+        })
+    }
+
+    fn resized(&self, image: Image, width: u32, height: u32) -> Result<Arc<RgbaImage>, Error> {
+        let key = (image, width, height);
+        self.resized.get_or_insert(key, || {
+            let decoded = self.decoded(image)?;
+            Ok(Arc::new(resize(decoded.as_ref(), width, height)))
+        })
+    }
+}
+
+struct Lru<K: Hash + Eq, V> {
+    entries: Mutex<LruCache<K, Arc<V>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K: Hash + Eq, V> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity.max(1)).unwrap();
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// The lock is released while the value is loaded, so threads missing the same
+    /// key may both load it.
+    fn get_or_insert(
+        &self,
+        key: K,
+        load: impl FnOnce() -> Result<Arc<V>, Error>,
+    ) -> Result<Arc<V>, Error> {
+        if let Some(value) = self.lock().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(value.clone());
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = load()?;
+        self.lock().put(key, value.clone());
+        Ok(value)
+    }
+
+    // A panic while holding the lock cannot leave the cache half updated
+    fn lock(&self) -> MutexGuard<'_, LruCache<K, Arc<V>>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<P: Debug> Debug for CachedParts<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedParts")
+            .field("provider", &self.provider)
+            .field("decoded", &self.decoded.lock().len())
+            .field("resized", &self.resized.lock().len())
+            .finish()
+    }
+}
+
+impl<P: PartsProvider> PartsProvider for CachedParts<P> {
+    fn sets(&self) -> &[SetCatalog] {
+        self.provider.sets()
+    }
+
+    fn backgrounds(&self) -> usize {
+        self.provider.backgrounds()
+    }
+
+    fn background_sets(&self) -> Vec<usize> {
+        self.provider.background_sets()
+    }
+
+    fn color_description(&self, set: RoboSet, color: usize) -> Option<Description> {
+        self.provider.color_description(set, color)
+    }
+
+    fn part_description(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Option<Description> {
+        self.provider.part_description(set, color, layer, index)
+    }
+
+    fn background_description(&self, index: usize) -> Option<Description> {
+        self.provider.background_description(index)
+    }
+
+    fn part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Result<DynamicImage, Error> {
+        let image = Image::Part {
+            set,
+            color,
+            layer,
+            index,
+        };
+        Ok(self.decoded(image)?.as_ref().clone())
+    }
+
+    fn background(&self, index: usize) -> Result<DynamicImage, Error> {
+        Ok(self.decoded(Image::Background(index))?.as_ref().clone())
+    }
+
+    fn resized_part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+        width: u32,
+        height: u32,
+    ) -> Result<Arc<RgbaImage>, Error> {
+        let image = Image::Part {
+            set,
+            color,
+            layer,
+            index,
+        };
+        self.resized(image, width, height)
+    }
+
+    fn resized_background(
+        &self,
+        index: usize,
+        width: u32,
+        height: u32,
+    ) -> Result<Arc<RgbaImage>, Error> {
+        self.resized(Image::Background(index), width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::provider::EmbeddedParts;
+
+    use super::*;
+
+    #[test]
+    fn resized_parts_are_decoded_once_per_image_and_resized_once_per_size() {
+        // arrange
+        let parts = CachedParts::new(EmbeddedParts::new());
+        // act
+        for size in [16, 32, 16, 32] {
+            parts
+                .resized_part(RoboSet::Set1, 0, 0, 0, size, size)
+                .unwrap();
+        }
+        // assert
+        assert_eq!(
+            parts.stats(),
+            CacheStats {
+                decoded_hits: 1,
+                decoded_misses: 1,
+                resized_hits: 2,
+                resized_misses: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn least_recently_used_images_are_evicted() {
+        // arrange
+        let parts = CachedParts::with_capacity(EmbeddedParts::new(), 1, 1);
+        // act
+        parts.resized_background(0, 8, 8).unwrap();
+        parts.resized_background(1, 8, 8).unwrap();
+        parts.resized_background(0, 8, 8).unwrap();
+        // assert
+        let stats = parts.stats();
+        assert_eq!(stats.resized_misses, 3);
+        assert_eq!(stats.decoded_misses, 3);
+    }
+
+    #[test]
+    fn cached_images_match_the_provider_images() {
+        // arrange
+        let provider = EmbeddedParts::new();
+        let parts = CachedParts::new(provider.clone());
+        // act
+        let cached = parts.resized_part(RoboSet::Set1, 1, 2, 3, 24, 24).unwrap();
+        let cached_again = parts.resized_part(RoboSet::Set1, 1, 2, 3, 24, 24).unwrap();
+        // assert
+        let expected = provider
+            .resized_part(RoboSet::Set1, 1, 2, 3, 24, 24)
+            .unwrap();
+        assert_eq!(cached, expected);
+        assert!(Arc::ptr_eq(&cached, &cached_again));
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        // arrange
+        let parts = CachedParts::new(EmbeddedParts::new());
+        // act
+        let first = parts.resized_background(1000, 8, 8);
+        let second = parts.resized_background(1000, 8, 8);
+        // assert
+        assert!(first.is_err() && second.is_err());
+        assert_eq!(parts.stats().decoded_misses, 2);
+    }
+}
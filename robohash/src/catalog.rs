@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+use crate::error::Error;
+use crate::robot_parts;
+use crate::MAX_LAYERS;
+
+/// Robot families, named after the upstream Robohash sets.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RoboSet {
+    /// Robots
+    #[default]
+    Set1,
+    /// Monsters
+    Set2,
+    /// Robot heads
+    Set3,
+    /// Cats
+    Set4,
+    /// Humans
+    Set5,
+    /// Let the hash pick one of the available sets
+    Any,
+}
+
+/// Describes the parts available for a robot set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetCatalog {
+    pub set: RoboSet,
+    /// Color variants of the set. Sets without variants have a single color.
+    pub colors: Vec<String>,
+    /// Layer categories in stacking order (first one is drawn at the bottom), at most 16.
+    pub layers: Vec<LayerCatalog>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerCatalog {
+    pub name: String,
+    /// Number of parts of this layer for each color, in the order of `SetCatalog::colors`.
+    pub counts: Vec<usize>,
+    /// Position of the layer when upstream Robohash picks the parts, the `003` of
+    /// `003#01Body`. Only `Compatibility::Upstream` uses it, defaults to the stacking order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<u32>,
+}
+
+impl SetCatalog {
+    pub fn layer_count(&self, layer: usize, color: usize) -> usize {
+        self.layers[layer].counts[color]
+    }
+
+    /// Fails unless every color has parts in every layer, the picks of the
+    /// selection being taken among them, and the layers fit in the hash array.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.colors.is_empty() {
+            return Err(Error::InvalidCatalog(format!("{} has no colors", self.set)));
+        }
+        if self.layers.len() > MAX_LAYERS {
+            return Err(Error::InvalidCatalog(format!(
+                "{} has {} layers, at most {} are supported",
+                self.set,
+                self.layers.len(),
+                MAX_LAYERS
+            )));
+        }
+        for layer in &self.layers {
+            for (c, color) in self.colors.iter().enumerate() {
+                if layer.counts.get(c).copied().unwrap_or(0) == 0 {
+                    return Err(Error::InvalidCatalog(format!(
+                        "{}/{} has no parts in {}",
+                        self.set, layer.name, color
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A robot set generated into `robot_parts.rs` by `cargo xtask assets`.
+pub(crate) struct EmbeddedSet {
+    pub(crate) name: &'static str,
+    pub(crate) colors: &'static [&'static str],
+    pub(crate) layers: &'static [&'static str],
+    /// Order in which upstream picks the layers, see `LayerCatalog::sort_order`.
+    pub(crate) sort_orders: &'static [u32],
+    /// color -> layer -> base64 WebP parts
+    pub(crate) parts: &'static [&'static [&'static [&'static str]]],
+}
+
+impl EmbeddedSet {
+    pub(crate) fn set(&self) -> RoboSet {
+        RoboSet::from_str(self.name).expect("Generated set names should be valid")
+    }
+
+    fn catalog(&self) -> SetCatalog {
+        let colors = self.colors.iter().map(|color| color.to_string()).collect();
+        let layers = self
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(layer, name)| LayerCatalog {
+                name: name.to_string(),
+                counts: self.parts.iter().map(|parts| parts[layer].len()).collect(),
+                sort_order: self.sort_orders.get(layer).copied(),
+            })
+            .collect();
+
+        SetCatalog {
+            set: self.set(),
+            colors,
+            layers,
+        }
+    }
+}
+
+/// Returns the catalog of every robot set embedded in the binary.
+pub fn embedded() -> Vec<SetCatalog> {
+    robot_parts::SETS
+        .iter()
+        .map(|embedded_set| embedded_set.catalog())
+        .collect()
+}
+
+pub(crate) fn find(sets: &[SetCatalog], set: RoboSet) -> Result<&SetCatalog, Error> {
+    sets.iter()
+        .find(|catalog| catalog.set == set)
+        .ok_or_else(|| Error::RoboSetUnavailable(set.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn robo_set_parses_upstream_set_names() {
+        // arrange
+        let names = ["set1", "set2", "set3", "set4", "set5", "any"];
+        // act
+        let sets = names
+            .iter()
+            .map(|name| RoboSet::from_str(name).unwrap())
+            .collect::<Vec<RoboSet>>();
+        // assert
+        assert_eq!(
+            sets,
+            vec![
+                RoboSet::Set1,
+                RoboSet::Set2,
+                RoboSet::Set3,
+                RoboSet::Set4,
+                RoboSet::Set5,
+                RoboSet::Any
+            ]
+        );
+        assert_eq!(RoboSet::Set4.to_string(), "set4");
+    }
+
+    #[test]
+    fn embedded_catalog_describes_set1_layers_for_every_color() {
+        // act
+        let sets = embedded();
+        let set1 = find(&sets, RoboSet::Set1).unwrap();
+        // assert
+        assert_eq!(set1.colors.len(), 10);
+        assert_eq!(set1.layers.len(), 5);
+        assert_eq!(set1.layers[0].name, "Body");
+        assert!(set1
+            .layers
+            .iter()
+            .all(|layer| layer.counts.len() == set1.colors.len()));
+    }
+
+    #[test]
+    fn find_returns_error_for_sets_without_parts() {
+        // act
+        let sets = embedded();
+        let result = find(&sets, RoboSet::Set2);
+        // assert
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            Error::RoboSetUnavailable(String::from("set2")).to_string()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+/// Languages of the robot descriptions.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+/// A phrase describing a color, a part or a background, in every locale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Description {
+    pub en: String,
+    pub es: String,
+}
+
+impl Description {
+    pub fn get(&self, locale: Locale) -> &str {
+        match locale {
+            Locale::En => &self.en,
+            Locale::Es => &self.es,
+        }
+    }
+}
+
+/// Descriptions of a set generated into `descriptions.rs` by `cargo xtask assets`.
+pub(crate) struct EmbeddedSetDescriptions {
+    pub(crate) name: &'static str,
+    pub(crate) colors: &'static [EmbeddedDescription],
+    /// color -> layer -> part
+    pub(crate) parts: &'static [&'static [&'static [EmbeddedDescription]]],
+}
+
+pub(crate) struct EmbeddedDescription {
+    pub(crate) en: &'static str,
+    pub(crate) es: &'static str,
+}
+
+impl EmbeddedDescription {
+    pub(crate) fn description(&self) -> Description {
+        Description {
+            en: self.en.to_string(),
+            es: self.es.to_string(),
+        }
+    }
+}
+
+/// Stands for backgrounds without a description.
+pub(crate) fn background(locale: Locale) -> &'static str {
+    match locale {
+        Locale::En => "a background",
+        Locale::Es => "un fondo",
+    }
+}
+
+pub(crate) fn plain_background(locale: Locale) -> &'static str {
+    match locale {
+        Locale::En => "a plain background",
+        Locale::Es => "un fondo liso",
+    }
+}
+
+/// "a blue robot with a round head and laser eyes, on a starry night sky". The
+/// color is an adjective, `blue` or `azul`. Parts without a description are left
+/// out, like the color of hue rotated robots.
+pub(crate) fn describe(
+    locale: Locale,
+    color: Option<&str>,
+    parts: &[&str],
+    background: Option<&str>,
+) -> String {
+    let (robot, with, and, on) = match locale {
+        Locale::En => match color {
+            Some(color) => (format!("a {} robot", color), "with", "and", "on"),
+            None => (String::from("a robot"), "with", "and", "on"),
+        },
+        Locale::Es => match color {
+            Some(color) => (format!("un robot {}", color), "con", "y", "sobre"),
+            None => (String::from("un robot"), "con", "y", "sobre"),
+        },
+    };
+
+    let mut description = robot;
+    if let Some((last, first)) = parts.split_last() {
+        description.push_str(&format!(" {} ", with));
+        if !first.is_empty() {
+            description.push_str(&first.join(", "));
+            description.push_str(&format!(" {} ", and));
+        }
+        description.push_str(last);
+    }
+    if let Some(background) = background {
+        description.push_str(&format!(", {} {}", on, background));
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_lists_the_parts_and_the_background() {
+        // act
+        let description = describe(
+            Locale::En,
+            Some("teal"),
+            &["round visor eyes", "a grille mouth", "an antenna"],
+            Some("a starry sky"),
+        );
+        // assert
+        assert_eq!(
+            description,
+            "a teal robot with round visor eyes, a grille mouth and an antenna, on a starry sky"
+        );
+    }
+
+    #[test]
+    fn describe_uses_the_spanish_template() {
+        // act
+        let description = describe(Locale::Es, Some("azul"), &["ojos láser"], None);
+        // assert
+        assert_eq!(description, "un robot azul con ojos láser");
+    }
+
+    #[test]
+    fn describe_without_color_nor_parts_still_names_the_robot() {
+        assert_eq!(describe(Locale::En, None, &[], None), "a robot");
+    }
+}
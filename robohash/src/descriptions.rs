@@ -0,0 +1,725 @@
+// @generated by `cargo xtask assets`, do not edit.
+
+use crate::describe::{EmbeddedDescription, EmbeddedSetDescriptions};
+
+pub static SETS: &[EmbeddedSetDescriptions] = &[SET1];
+
+const SET1: EmbeddedSetDescriptions = EmbeddedSetDescriptions {
+    name: "set1",
+    colors: &[
+        EmbeddedDescription { en: "blue", es: "azul" },
+        EmbeddedDescription { en: "brown", es: "marrón" },
+        EmbeddedDescription { en: "green", es: "verde" },
+        EmbeddedDescription { en: "grey", es: "gris" },
+        EmbeddedDescription { en: "orange", es: "naranja" },
+        EmbeddedDescription { en: "pink", es: "rosa" },
+        EmbeddedDescription { en: "purple", es: "morado" },
+        EmbeddedDescription { en: "red", es: "rojo" },
+        EmbeddedDescription { en: "white", es: "blanco" },
+        EmbeddedDescription { en: "yellow", es: "amarillo" },
+    ],
+    parts: &[
+        &[
+            &[
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+            ],
+            &[
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+            ],
+            &[
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+            ],
+            &[
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+            ],
+            &[
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+            ],
+            &[
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+            ],
+            &[
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+            ],
+            &[
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+            ],
+            &[
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+            ],
+            &[
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+            ],
+            &[
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+            ],
+            &[
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+            ],
+            &[
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+            ],
+            &[
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+            ],
+            &[
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+            ],
+            &[
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+            ],
+            &[
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+        &[
+            &[
+                EmbeddedDescription { en: "a funnel-shaped body", es: "un cuerpo en forma de embudo" },
+                EmbeddedDescription { en: "a thin pole body", es: "un cuerpo de poste delgado" },
+                EmbeddedDescription { en: "a barrel body with hose arms", es: "un cuerpo de barril con brazos de manguera" },
+                EmbeddedDescription { en: "a round body with a porthole", es: "un cuerpo redondo con un ojo de buey" },
+                EmbeddedDescription { en: "a body with hose arms", es: "un cuerpo con brazos de manguera" },
+                EmbeddedDescription { en: "a wide body with a round collar", es: "un cuerpo ancho con un collar redondo" },
+                EmbeddedDescription { en: "a small boxy body", es: "un cuerpo pequeño y cuadrado" },
+                EmbeddedDescription { en: "a slim body with round shoulders", es: "un cuerpo delgado de hombros redondos" },
+                EmbeddedDescription { en: "a body with a bent neck", es: "un cuerpo de cuello curvado" },
+                EmbeddedDescription { en: "a bulky body", es: "un cuerpo robusto" },
+                EmbeddedDescription { en: "a body crackling with lightning", es: "un cuerpo cargado de rayos" },
+            ],
+            &[
+                EmbeddedDescription { en: "a round head", es: "una cabeza redonda" },
+                EmbeddedDescription { en: "a hooked head", es: "una cabeza en forma de gancho" },
+                EmbeddedDescription { en: "a can-shaped head", es: "una cabeza en forma de lata" },
+                EmbeddedDescription { en: "an egg-shaped head", es: "una cabeza ovalada" },
+                EmbeddedDescription { en: "a boxy head", es: "una cabeza cuadrada" },
+                EmbeddedDescription { en: "a bulb-shaped head", es: "una cabeza en forma de bombilla" },
+                EmbeddedDescription { en: "a rounded head with a chin plate", es: "una cabeza redondeada con mentonera" },
+                EmbeddedDescription { en: "a pear-shaped head", es: "una cabeza en forma de pera" },
+                EmbeddedDescription { en: "a tall dome head", es: "una cabeza alta en cúpula" },
+                EmbeddedDescription { en: "a shield-shaped head", es: "una cabeza en forma de escudo" },
+            ],
+            &[
+                EmbeddedDescription { en: "a striped grin", es: "una sonrisa a rayas" },
+                EmbeddedDescription { en: "a tiny square mouth", es: "una boca cuadrada diminuta" },
+                EmbeddedDescription { en: "a pill-shaped mouth", es: "una boca en forma de píldora" },
+                EmbeddedDescription { en: "a small slot mouth", es: "una boca de ranura pequeña" },
+                EmbeddedDescription { en: "a striped panel mouth", es: "una boca de panel a rayas" },
+                EmbeddedDescription { en: "a yellow grille mouth", es: "una boca de rejilla amarilla" },
+                EmbeddedDescription { en: "a small two-tone mouth", es: "una boca pequeña de dos colores" },
+                EmbeddedDescription { en: "a thin line mouth", es: "una boca de línea fina" },
+                EmbeddedDescription { en: "a frowning mouth", es: "una boca fruncida" },
+                EmbeddedDescription { en: "a vent mouth", es: "una boca de rejilla de ventilación" },
+            ],
+            &[
+                EmbeddedDescription { en: "a glass visor", es: "un visor de cristal" },
+                EmbeddedDescription { en: "a visor with glowing eyes", es: "un visor con ojos brillantes" },
+                EmbeddedDescription { en: "round red eyes", es: "ojos redondos rojos" },
+                EmbeddedDescription { en: "a single red eye", es: "un único ojo rojo" },
+                EmbeddedDescription { en: "yellow goggle eyes", es: "ojos de gafas amarillas" },
+                EmbeddedDescription { en: "narrow slanted eyes", es: "ojos rasgados" },
+                EmbeddedDescription { en: "a cyclops eye", es: "un ojo de cíclope" },
+                EmbeddedDescription { en: "sleepy line eyes", es: "ojos de línea adormilados" },
+                EmbeddedDescription { en: "a visor with a red slit", es: "un visor con una ranura roja" },
+                EmbeddedDescription { en: "small dot eyes", es: "ojos pequeños como puntos" },
+                EmbeddedDescription { en: "bitcoin goggles", es: "gafas de bitcoin" },
+                EmbeddedDescription { en: "laser eyes", es: "ojos láser" },
+            ],
+            &[
+                EmbeddedDescription { en: "a small hat", es: "un sombrerito" },
+                EmbeddedDescription { en: "a feather plume", es: "un penacho de plumas" },
+                EmbeddedDescription { en: "a spike", es: "un pincho" },
+                EmbeddedDescription { en: "a round disc", es: "un disco redondo" },
+                EmbeddedDescription { en: "a mustache", es: "un bigote" },
+                EmbeddedDescription { en: "an antenna with a red light", es: "una antena con una luz roja" },
+                EmbeddedDescription { en: "a red button", es: "un botón rojo" },
+                EmbeddedDescription { en: "a bolt", es: "un perno" },
+                EmbeddedDescription { en: "a small box", es: "una cajita" },
+                EmbeddedDescription { en: "a TV antenna", es: "una antena de televisión" },
+                EmbeddedDescription { en: "a bitcoin coin on a stand", es: "una moneda de bitcoin en un soporte" },
+                EmbeddedDescription { en: "a bitcoin antenna", es: "una antena de bitcoin" },
+                EmbeddedDescription { en: "a bearded face with glasses", es: "una cara con barba y gafas" },
+            ],
+        ],
+    ],
+};
+
+pub static BACKGROUNDS: &[EmbeddedDescription] = &[
+    EmbeddedDescription { en: "a starry night sky", es: "un cielo nocturno estrellado" },
+    EmbeddedDescription { en: "a cloudy sky", es: "un cielo nublado" },
+    EmbeddedDescription { en: "a city skyline", es: "el perfil de una ciudad" },
+    EmbeddedDescription { en: "a forest", es: "un bosque" },
+    EmbeddedDescription { en: "an island in the ocean", es: "una isla en el océano" },
+    EmbeddedDescription { en: "a moonlit night", es: "una noche de luna" },
+    EmbeddedDescription { en: "a snowy mountain", es: "una montaña nevada" },
+    EmbeddedDescription { en: "desert dunes", es: "dunas del desierto" },
+    EmbeddedDescription { en: "a blue sky with clouds", es: "un cielo azul con nubes" },
+    EmbeddedDescription { en: "a beach", es: "una playa" },
+    EmbeddedDescription { en: "a steam engine room", es: "una sala de máquinas de vapor" },
+    EmbeddedDescription { en: "a computer terminal", es: "una terminal de ordenador" },
+    EmbeddedDescription { en: "a ship model on a bookshelf", es: "un barco en una estantería" },
+    EmbeddedDescription { en: "a bitcoin moon over red hills", es: "una luna de bitcoin sobre colinas rojas" },
+    EmbeddedDescription { en: "a red honeycomb pattern", es: "un patrón de panal rojo" },
+    EmbeddedDescription { en: "a blue honeycomb pattern", es: "un patrón de panal azul" },
+    EmbeddedDescription { en: "blue sunbeams", es: "rayos de sol azules" },
+    EmbeddedDescription { en: "green sunbeams", es: "rayos de sol verdes" },
+    EmbeddedDescription { en: "yellow sunbeams", es: "rayos de sol amarillos" },
+    EmbeddedDescription { en: "red sunbeams", es: "rayos de sol rojos" },
+    EmbeddedDescription { en: "a green honeycomb pattern", es: "un patrón de panal verde" },
+];
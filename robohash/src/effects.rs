@@ -0,0 +1,186 @@
+use std::fmt;
+
+use image::{GrayImage, Luma, Rgba, RgbaImage};
+use imageproc::distance_transform::euclidean_squared_distance_transform;
+use imageproc::filter::gaussian_blur_f32;
+
+/// Post-processing step run, in the order they were added, on the composed parts
+/// before they are drawn over the background.
+pub trait Effect: fmt::Debug + Send + Sync {
+    /// `robot` holds the parts over a transparent canvas of the image size.
+    fn apply(&self, robot: &mut RgbaImage);
+}
+
+/// Stroke around the silhouette of the robot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outline {
+    pub color: [u8; 4],
+    /// Width in pixels.
+    pub width: f32,
+}
+
+/// Blurred copy of the silhouette drawn behind the robot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropShadow {
+    /// Right and down offset in pixels.
+    pub offset: (i32, i32),
+    /// Standard deviation of the blur in pixels, 0 for a hard shadow.
+    pub blur: f32,
+    pub color: [u8; 4],
+}
+
+/// Soft halo fading out `radius` pixels around the silhouette.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glow {
+    pub color: [u8; 4],
+    pub radius: f32,
+}
+
+impl Effect for Outline {
+    fn apply(&self, robot: &mut RgbaImage) {
+        let silhouette = GrayImage::from_fn(robot.width(), robot.height(), |x, y| {
+            let opaque = robot.get_pixel(x, y)[3] >= 128;
+            Luma([if opaque { 255 } else { 0 }])
+        });
+        let distances = euclidean_squared_distance_transform(&silhouette);
+        let coverage = GrayImage::from_fn(robot.width(), robot.height(), |x, y| {
+            let distance = distances.get_pixel(x, y)[0].sqrt() as f32;
+            Luma([((self.width + 0.5 - distance).clamp(0.0, 1.0) * 255.0).round() as u8])
+        });
+        draw_under(robot, self.color, &coverage);
+    }
+}
+
+impl Effect for DropShadow {
+    fn apply(&self, robot: &mut RgbaImage) {
+        let (dx, dy) = self.offset;
+        let shadow = GrayImage::from_fn(robot.width(), robot.height(), |x, y| {
+            let (x, y) = (x as i64 - dx as i64, y as i64 - dy as i64);
+            match robot.width() as i64 > x && x >= 0 && robot.height() as i64 > y && y >= 0 {
+                true => Luma([robot.get_pixel(x as u32, y as u32)[3]]),
+                false => Luma([0]),
+            }
+        });
+        let shadow = match self.blur > 0.0 {
+            true => gaussian_blur_f32(&shadow, self.blur),
+            false => shadow,
+        };
+        draw_under(robot, self.color, &shadow);
+    }
+}
+
+impl Effect for Glow {
+    fn apply(&self, robot: &mut RgbaImage) {
+        let alpha = GrayImage::from_fn(robot.width(), robot.height(), |x, y| {
+            Luma([robot.get_pixel(x, y)[3]])
+        });
+        let mut glow = gaussian_blur_f32(&alpha, (self.radius / 2.0).max(0.5));
+        // Blurring halves the alpha at the edge, bring it back to full strength
+        glow.pixels_mut()
+            .for_each(|pixel| pixel[0] = pixel[0].saturating_mul(2));
+        draw_under(robot, self.color, &glow);
+    }
+}
+
+/// Draws `color` behind the robot, as opaque as `coverage`.
+fn draw_under(robot: &mut RgbaImage, color: [u8; 4], coverage: &GrayImage) {
+    for (pixel, coverage) in robot.pixels_mut().zip(coverage.pixels()) {
+        let alpha = pixel[3] as f32 / 255.0;
+        let below = color[3] as f32 / 255.0 * coverage[0] as f32 / 255.0 * (1.0 - alpha);
+        let out = alpha + below;
+        if out == 0.0 {
+            continue;
+        }
+        let channel =
+            |i: usize| ((pixel[i] as f32 * alpha + color[i] as f32 * below) / out).round() as u8;
+        *pixel = Rgba([
+            channel(0),
+            channel(1),
+            channel(2),
+            (out * 255.0).round() as u8,
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Opaque red square of 10 pixels in the middle of a 30 pixel canvas.
+    fn robot() -> RgbaImage {
+        RgbaImage::from_fn(30, 30, |x, y| {
+            match (10..20).contains(&x) && (10..20).contains(&y) {
+                true => Rgba([255, 0, 0, 255]),
+                false => Rgba([0, 0, 0, 0]),
+            }
+        })
+    }
+
+    #[test]
+    fn outline_strokes_around_the_silhouette() {
+        // arrange
+        let mut robot = robot();
+        // act
+        Outline {
+            color: [0, 0, 0, 255],
+            width: 3.0,
+        }
+        .apply(&mut robot);
+        // assert
+        assert_eq!(*robot.get_pixel(15, 15), Rgba([255, 0, 0, 255]));
+        assert_eq!(*robot.get_pixel(8, 15), Rgba([0, 0, 0, 255]));
+        assert_eq!(robot.get_pixel(5, 15)[3], 0);
+        assert_eq!(robot.get_pixel(0, 0)[3], 0);
+    }
+
+    #[test]
+    fn drop_shadow_is_offset_behind_the_robot() {
+        // arrange
+        let mut robot = robot();
+        // act
+        DropShadow {
+            offset: (4, 4),
+            blur: 0.0,
+            color: [0, 0, 0, 128],
+        }
+        .apply(&mut robot);
+        // assert
+        assert_eq!(*robot.get_pixel(19, 19), Rgba([255, 0, 0, 255]));
+        assert_eq!(*robot.get_pixel(22, 22), Rgba([0, 0, 0, 128]));
+        assert_eq!(robot.get_pixel(8, 8)[3], 0);
+    }
+
+    #[test]
+    fn drop_shadow_blur_softens_its_edges() {
+        // arrange
+        let mut robot = robot();
+        // act
+        DropShadow {
+            offset: (4, 4),
+            blur: 2.0,
+            color: [0, 0, 0, 255],
+        }
+        .apply(&mut robot);
+        // assert
+        let edge = robot.get_pixel(24, 15)[3];
+        assert!(edge > 0 && edge < 255);
+    }
+
+    #[test]
+    fn glow_fades_out_around_the_robot() {
+        // arrange
+        let mut robot = robot();
+        // act
+        Glow {
+            color: [255, 255, 0, 255],
+            radius: 6.0,
+        }
+        .apply(&mut robot);
+        // assert
+        let near = robot.get_pixel(8, 15)[3];
+        let far = robot.get_pixel(4, 15)[3];
+        assert!(near > far && far > 0);
+        assert_eq!(robot.get_pixel(8, 15).0[0..3], [255, 255, 0]);
+        assert_eq!(*robot.get_pixel(15, 15), Rgba([255, 0, 0, 255]));
+    }
+}
@@ -6,12 +6,40 @@ pub enum Error {
     ParseIntError(#[from] std::num::ParseIntError),
     #[error("io error")]
     IoError(#[from] std::io::Error),
+    #[error("formatting error")]
+    FmtError(#[from] std::fmt::Error),
+    #[error("walkdir error")]
+    WalkDirError(#[from] walkdir::Error),
+    #[error("zip error")]
+    ZipError(#[from] zip::result::ZipError),
+    #[error("json error")]
+    JsonError(#[from] serde_json::Error),
     #[error("error processing image")]
     ImageProcessingError(#[from] image::ImageError),
     #[error("failed loading image {0}")]
     ImageOpenFailed(String),
+    #[error("failed encoding {0}: {1}")]
+    EncodingFailed(String, String),
+    #[error("{0} needs the \"{1}\" feature")]
+    FeatureDisabled(String, String),
+    #[error("invalid scale {0}, expected a value greater than 0 and up to 1")]
+    InvalidScale(String),
+    #[error("invalid digest: {0}")]
+    InvalidDigest(String),
+    #[error("invalid trait code: {0}")]
+    InvalidTraitCode(String),
+    #[error("invalid override: {0}")]
+    InvalidOverride(String),
+    #[error("invalid animation: {0}")]
+    InvalidAnimation(String),
     #[error("failed to fetch index {0}[{1}]")]
     InvalidArrayIndex(String, String),
+    #[error("invalid asset pack: {0}")]
+    InvalidPack(String),
+    #[error("invalid catalog: {0}")]
+    InvalidCatalog(String),
+    #[error("robot set {0} is not available")]
+    RoboSetUnavailable(String),
     #[error("Is missing required data. Please use the RoboHashBuilder.")]
     RoboHashMissingRequiredData,
     #[error("unknown error")]
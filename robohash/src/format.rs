@@ -0,0 +1,285 @@
+use std::io::Write;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::{ColorType, ImageEncoder, RgbImage, RgbaImage};
+
+use crate::error::Error;
+
+/// Encoding of an assembled robot.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Png,
+    WebpLossless,
+    /// Lossy WebP, `quality` from 0 to 100. Needs the `webp` feature.
+    Webp {
+        quality: f32,
+    },
+    /// JPEG has no alpha channel, transparent pixels are blended over `background`.
+    Jpeg {
+        quality: u8,
+        background: [u8; 3],
+    },
+    /// `quality` from 1 to 100, `speed` from 1 (slowest, smallest) to 10. Needs the `avif` feature.
+    Avif {
+        quality: f32,
+        speed: u8,
+    },
+}
+
+impl OutputFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            OutputFormat::Png => "image/png",
+            OutputFormat::WebpLossless | OutputFormat::Webp { .. } => "image/webp",
+            OutputFormat::Jpeg { .. } => "image/jpeg",
+            OutputFormat::Avif { .. } => "image/avif",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Png => "png",
+            OutputFormat::WebpLossless | OutputFormat::Webp { .. } => "webp",
+            OutputFormat::Jpeg { .. } => "jpg",
+            OutputFormat::Avif { .. } => "avif",
+        }
+    }
+}
+
+pub(crate) fn encode<W: Write>(
+    image: &RgbaImage,
+    format: &OutputFormat,
+    mut writer: W,
+) -> Result<(), Error> {
+    let (width, height) = image.dimensions();
+    match format {
+        OutputFormat::Png => {
+            PngEncoder::new(writer).write_image(image, width, height, ColorType::Rgba8)?
+        }
+        OutputFormat::WebpLossless => {
+            WebPEncoder::new_lossless(writer).encode(image, width, height, ColorType::Rgba8)?
+        }
+        OutputFormat::Webp { quality } => writer.write_all(&encode_webp(image, *quality)?)?,
+        OutputFormat::Jpeg {
+            quality,
+            background,
+        } => {
+            let image = flatten(image, background);
+            JpegEncoder::new_with_quality(writer, *quality).write_image(
+                &image,
+                width,
+                height,
+                ColorType::Rgb8,
+            )?
+        }
+        OutputFormat::Avif { quality, speed } => {
+            validate_avif(*quality, *speed)?;
+            writer.write_all(&encode_avif(image, *quality, *speed)?)?
+        }
+    }
+    Ok(())
+}
+
+fn flatten(image: &RgbaImage, background: &[u8; 3]) -> RgbImage {
+    RgbImage::from_fn(image.width(), image.height(), |x, y| {
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        let blend = |channel: u8, background: u8| {
+            let alpha = a as u32;
+            ((channel as u32 * alpha + background as u32 * (255 - alpha) + 127) / 255) as u8
+        };
+        image::Rgb([
+            blend(r, background[0]),
+            blend(g, background[1]),
+            blend(b, background[2]),
+        ])
+    })
+}
+
+#[cfg(feature = "webp")]
+fn encode_webp(image: &RgbaImage, quality: f32) -> Result<Vec<u8>, Error> {
+    let encoder = webp::Encoder::from_rgba(image.as_raw(), image.width(), image.height());
+    let webp = encoder
+        .encode_simple(false, quality)
+        .map_err(|err| Error::EncodingFailed(String::from("webp"), format!("{:?}", err)))?;
+    Ok(webp.to_vec())
+}
+
+#[cfg(not(feature = "webp"))]
+fn encode_webp(_image: &RgbaImage, _quality: f32) -> Result<Vec<u8>, Error> {
+    Err(Error::FeatureDisabled(
+        String::from("lossy webp output"),
+        String::from("webp"),
+    ))
+}
+
+/// ravif panics out of these bounds.
+fn validate_avif(quality: f32, speed: u8) -> Result<(), Error> {
+    if !(1.0..=100.0).contains(&quality) {
+        return Err(Error::EncodingFailed(
+            String::from("avif"),
+            format!("quality {} out of 1 to 100", quality),
+        ));
+    }
+    if !(1..=10).contains(&speed) {
+        return Err(Error::EncodingFailed(
+            String::from("avif"),
+            format!("speed {} out of 1 to 10", speed),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "avif")]
+fn encode_avif(image: &RgbaImage, quality: f32, speed: u8) -> Result<Vec<u8>, Error> {
+    use rgb::FromSlice;
+
+    let pixels = ravif::Img::new(
+        image.as_raw().as_rgba(),
+        image.width() as usize,
+        image.height() as usize,
+    );
+    let avif = ravif::Encoder::new()
+        .with_quality(quality)
+        .with_speed(speed)
+        .encode_rgba(pixels)
+        .map_err(|err| Error::EncodingFailed(String::from("avif"), err.to_string()))?;
+    Ok(avif.avif_file)
+}
+
+#[cfg(not(feature = "avif"))]
+fn encode_avif(_image: &RgbaImage, _quality: f32, _speed: u8) -> Result<Vec<u8>, Error> {
+    Err(Error::FeatureDisabled(
+        String::from("avif output"),
+        String::from("avif"),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{ImageFormat, Rgba};
+
+    use super::*;
+
+    fn half_transparent_image() -> RgbaImage {
+        RgbaImage::from_fn(4, 4, |x, _| match x {
+            0 | 1 => Rgba([255, 0, 0, 255]),
+            _ => Rgba([0, 0, 255, 0]),
+        })
+    }
+
+    #[test]
+    fn encode_png_and_lossless_webp_round_trip() {
+        // arrange
+        let image = half_transparent_image();
+        for (format, image_format) in [
+            (OutputFormat::Png, ImageFormat::Png),
+            (OutputFormat::WebpLossless, ImageFormat::WebP),
+        ] {
+            let mut bytes = Vec::new();
+            // act
+            encode(&image, &format, &mut bytes).unwrap();
+            // assert
+            let decoded = image::load_from_memory_with_format(&bytes, image_format).unwrap();
+            assert_eq!(decoded.to_rgba8(), image);
+        }
+    }
+
+    #[test]
+    fn encode_jpeg_blends_transparent_pixels_over_the_background() {
+        // arrange
+        let image = half_transparent_image();
+        let format = OutputFormat::Jpeg {
+            quality: 100,
+            background: [0, 255, 0],
+        };
+        let mut bytes = Vec::new();
+        // act
+        encode(&image, &format, &mut bytes).unwrap();
+        // assert
+        let decoded = image::load_from_memory_with_format(&bytes, ImageFormat::Jpeg)
+            .unwrap()
+            .to_rgb8();
+        let [r, g, b] = decoded.get_pixel(3, 0).0;
+        assert!(r < 16 && g > 240 && b < 16);
+    }
+
+    #[cfg(feature = "webp")]
+    #[test]
+    fn encode_lossy_webp_is_decodable() {
+        // arrange
+        let format = OutputFormat::Webp { quality: 80.0 };
+        let mut bytes = Vec::new();
+        // act
+        encode(&half_transparent_image(), &format, &mut bytes).unwrap();
+        // assert
+        let decoded = image::load_from_memory_with_format(&bytes, ImageFormat::WebP);
+        assert_eq!(decoded.unwrap().width(), 4);
+    }
+
+    #[cfg(feature = "avif")]
+    #[test]
+    fn encode_avif_writes_an_avif_file() {
+        // arrange
+        let format = OutputFormat::Avif {
+            quality: 80.0,
+            speed: 10,
+        };
+        let mut bytes = Vec::new();
+        // act
+        encode(&half_transparent_image(), &format, &mut bytes).unwrap();
+        // assert
+        assert_eq!(&bytes[4..12], b"ftypavif");
+    }
+
+    #[test]
+    fn avif_quality_and_speed_are_checked_against_their_bounds() {
+        // arrange
+        let error = |quality: f32, speed: u8| {
+            let format = OutputFormat::Avif { quality, speed };
+            encode(&half_transparent_image(), &format, Vec::new())
+                .err()
+                .unwrap()
+                .to_string()
+        };
+        let out_of_bounds = |reason: &str| {
+            Error::EncodingFailed(String::from("avif"), String::from(reason)).to_string()
+        };
+        // act
+        let valid = [(1.0, 1), (100.0, 10)]
+            .iter()
+            .all(|(quality, speed)| validate_avif(*quality, *speed).is_ok());
+        // assert
+        assert!(valid);
+        assert_eq!(error(0.0, 6), out_of_bounds("quality 0 out of 1 to 100"));
+        assert_eq!(
+            error(100.5, 6),
+            out_of_bounds("quality 100.5 out of 1 to 100")
+        );
+        assert_eq!(
+            error(f32::NAN, 6),
+            out_of_bounds("quality NaN out of 1 to 100")
+        );
+        assert_eq!(error(80.0, 0), out_of_bounds("speed 0 out of 1 to 10"));
+        assert_eq!(error(80.0, 11), out_of_bounds("speed 11 out of 1 to 10"));
+    }
+
+    #[cfg(not(feature = "avif"))]
+    #[test]
+    fn encode_avif_needs_the_avif_feature() {
+        // arrange
+        let format = OutputFormat::Avif {
+            quality: 80.0,
+            speed: 6,
+        };
+        // act
+        let result = encode(&half_transparent_image(), &format, Vec::new());
+        // assert
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            Error::FeatureDisabled(String::from("avif output"), String::from("avif")).to_string()
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Golden image corpus: robots of many inputs, sizes and options rendered once and
+//! kept as PNG next to a `corpus.json` describing them. Images are compared decoded,
+//! within a tolerance, so encoder updates and the float rounding of other platforms
+//! pass while another part or color fails.
+//!
+//! `cargo xtask bless` renders the goldens again. Each mismatch leaves the rendered
+//! image and a diff, the differing pixels in red, in `diffs/` next to the goldens.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use image::{Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::mask::{Mask, Shape};
+use crate::{AlgorithmVersion, RoboHashBuilder, RoboSet, SizeMode};
+
+/// Corpus of this crate, the one every binding is checked against.
+pub const CORPUS_DIRECTORY: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/test_resources/golden/images");
+
+/// When set, `Corpus::verify` writes the goldens instead of comparing them.
+pub const BLESS_VARIABLE: &str = "ROBOHASH_BLESS";
+
+const MANIFEST: &str = "corpus.json";
+const DIFFS: &str = "diffs";
+
+/// How far a rendered image may be from its golden.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Tolerance {
+    /// Largest difference of any channel for a pixel to still match.
+    pub channel: u8,
+    /// Share of the pixels allowed not to match, from 0 to 1.
+    pub pixels: f32,
+}
+
+/// A robot of the corpus, named after its golden `<name>.png`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenCase {
+    pub name: String,
+    pub input: String,
+    /// Always given, goldens must not follow the default version.
+    pub algorithm: AlgorithmVersion,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub size_mode: SizeMode,
+    #[serde(default = "full_scale")]
+    pub scale: f32,
+    #[serde(default)]
+    pub set: RoboSet,
+    /// Like the builder, robots have a background unless `false`.
+    #[serde(default = "with_background")]
+    pub background: bool,
+    #[serde(default)]
+    pub hue_rotation: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mask: Option<Shape>,
+    /// Replaces the tolerance of the corpus for this case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<Tolerance>,
+}
+
+fn full_scale() -> f32 {
+    1.0
+}
+
+fn with_background() -> bool {
+    true
+}
+
+impl GoldenCase {
+    /// The robot of the case, as `RoboHash::render` draws it.
+    pub fn render(&self) -> Result<RgbaImage, Error> {
+        let mut builder = RoboHashBuilder::new(&self.input)
+            .with_algorithm(self.algorithm)
+            .with_size(self.width, self.height)
+            .with_size_mode(self.size_mode)
+            .with_scale(self.scale)
+            .with_set(self.set)
+            .with_background(&self.background)
+            .with_hue_rotation(&self.hue_rotation);
+        if let Some(shape) = self.mask {
+            builder = builder.with_mask(Mask::new(shape));
+        }
+        builder.build()?.render()
+    }
+}
+
+/// A case whose rendered image is not within the tolerance of its golden.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub name: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.reason)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Corpus {
+    /// Where the manifest, the goldens and the diffs are.
+    #[serde(skip)]
+    pub directory: PathBuf,
+    /// Tolerance of the cases without their own.
+    #[serde(default)]
+    pub tolerance: Tolerance,
+    pub cases: Vec<GoldenCase>,
+}
+
+impl Corpus {
+    /// Reads the `corpus.json` of `directory`.
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let manifest = fs::read_to_string(directory.join(MANIFEST))?;
+        let corpus: Corpus = serde_json::from_str(&manifest)?;
+        Ok(Corpus {
+            directory: directory.to_path_buf(),
+            ..corpus
+        })
+    }
+
+    pub fn golden_path(&self, case: &GoldenCase) -> PathBuf {
+        self.directory.join(format!("{}.png", case.name))
+    }
+
+    pub fn golden(&self, case: &GoldenCase) -> Result<RgbaImage, Error> {
+        Ok(image::open(self.golden_path(case))?.to_rgba8())
+    }
+
+    /// Checks every case drawn by `render`, or blesses them when `ROBOHASH_BLESS`
+    /// is set.
+    pub fn verify<F>(&self, render: F) -> Result<Vec<Mismatch>, Error>
+    where
+        F: Fn(&GoldenCase) -> Result<RgbaImage, Error>,
+    {
+        match std::env::var_os(BLESS_VARIABLE) {
+            Some(_) => self.bless(render).map(|_| Vec::new()),
+            None => self.check(render),
+        }
+    }
+
+    /// Writes the image `render` draws for every case as its golden.
+    pub fn bless<F>(&self, render: F) -> Result<(), Error>
+    where
+        F: Fn(&GoldenCase) -> Result<RgbaImage, Error>,
+    {
+        for case in &self.cases {
+            render(case)?.save(self.golden_path(case))?;
+        }
+        Ok(())
+    }
+
+    /// Compares the image `render` draws for every case with its golden. The
+    /// rendered image and the diff of each mismatch are written to `diffs/`, as
+    /// `<name>.png` and `<name>.diff.png`.
+    pub fn check<F>(&self, render: F) -> Result<Vec<Mismatch>, Error>
+    where
+        F: Fn(&GoldenCase) -> Result<RgbaImage, Error>,
+    {
+        let diffs = self.directory.join(DIFFS);
+        let mut mismatches = Vec::new();
+        for case in &self.cases {
+            let rendered_path = diffs.join(format!("{}.png", case.name));
+            let diff_path = diffs.join(format!("{}.diff.png", case.name));
+            // Left by an earlier run
+            let _ = fs::remove_file(&rendered_path);
+            let _ = fs::remove_file(&diff_path);
+
+            let rendered = render(case)?;
+            let Ok(golden) = self.golden(case) else {
+                mismatches.push(Mismatch {
+                    name: case.name.clone(),
+                    reason: String::from("no golden, bless the corpus"),
+                });
+                continue;
+            };
+            let tolerance = case.tolerance.unwrap_or(self.tolerance);
+            let reason = match compare(&golden, &rendered, tolerance.channel) {
+                Some(comparison) if comparison.share() <= tolerance.pixels => continue,
+                Some(comparison) => format!(
+                    "{} of {} pixels differ, by up to {}",
+                    comparison.differing, comparison.pixels, comparison.max_difference
+                ),
+                None => format!(
+                    "rendered {}x{}, golden is {}x{}",
+                    rendered.width(),
+                    rendered.height(),
+                    golden.width(),
+                    golden.height()
+                ),
+            };
+
+            fs::create_dir_all(&diffs)?;
+            rendered.save(&rendered_path)?;
+            if rendered.dimensions() == golden.dimensions() {
+                diff_image(&golden, &rendered, tolerance.channel).save(&diff_path)?;
+            }
+            mismatches.push(Mismatch {
+                name: case.name.clone(),
+                reason,
+            });
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Pixels of two images of the same size that differ by more than a channel tolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub pixels: usize,
+    pub differing: usize,
+    /// Largest difference of a channel over all the pixels.
+    pub max_difference: u8,
+}
+
+impl Comparison {
+    /// Share of the pixels that differ, from 0 to 1.
+    pub fn share(&self) -> f32 {
+        self.differing as f32 / self.pixels.max(1) as f32
+    }
+}
+
+/// Counts the pixels of `actual` differing from `expected` by more than `channel`,
+/// `None` when the sizes differ. Fully transparent pixels match whatever their color.
+pub fn compare(expected: &RgbaImage, actual: &RgbaImage, channel: u8) -> Option<Comparison> {
+    if expected.dimensions() != actual.dimensions() {
+        return None;
+    }
+    let differences = expected
+        .pixels()
+        .zip(actual.pixels())
+        .map(|(first, second)| difference(first, second))
+        .collect::<Vec<u8>>();
+    Some(Comparison {
+        pixels: differences.len(),
+        differing: differences.iter().filter(|d| **d > channel).count(),
+        max_difference: differences.iter().copied().max().unwrap_or(0),
+    })
+}
+
+/// `expected` faded to light grey, with the pixels differing by more than `channel`
+/// in red.
+pub fn diff_image(expected: &RgbaImage, actual: &RgbaImage, channel: u8) -> RgbaImage {
+    RgbaImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let pixel = expected.get_pixel(x, y);
+        match difference(pixel, actual.get_pixel(x, y)) > channel {
+            true => Rgba([255, 0, 0, 255]),
+            false => {
+                let [r, g, b, a] = pixel.0.map(u32::from);
+                let luma = (299 * r + 587 * g + 114 * b) / 1000 * a / 255;
+                let grey = (191 + luma / 4) as u8;
+                Rgba([grey, grey, grey, 255])
+            }
+        }
+    })
+}
+
+fn difference(first: &Rgba<u8>, second: &Rgba<u8>) -> u8 {
+    if first[3] == 0 && second[3] == 0 {
+        return 0;
+    }
+    first
+        .0
+        .iter()
+        .zip(second.0)
+        .map(|(a, b)| a.abs_diff(b))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus_renders_like_its_goldens() {
+        // arrange
+        let corpus = Corpus::load(CORPUS_DIRECTORY).unwrap();
+        // act
+        let mismatches = corpus.verify(GoldenCase::render).unwrap();
+        // assert
+        assert!(
+            mismatches.is_empty(),
+            "{}\nsee {}/{}, `cargo xtask bless` if the changes are expected",
+            mismatches
+                .iter()
+                .map(Mismatch::to_string)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            CORPUS_DIRECTORY,
+            DIFFS
+        );
+    }
+
+    #[test]
+    fn compare_counts_the_pixels_beyond_the_tolerance() {
+        // arrange
+        let expected = RgbaImage::from_pixel(4, 4, Rgba([100, 100, 100, 255]));
+        let mut actual = expected.clone();
+        actual.put_pixel(0, 0, Rgba([102, 100, 100, 255]));
+        actual.put_pixel(1, 0, Rgba([100, 90, 100, 255]));
+        let mut transparent = RgbaImage::new(4, 4);
+        transparent.put_pixel(0, 0, Rgba([255, 255, 255, 0]));
+        // act
+        let comparison = compare(&expected, &actual, 2).unwrap();
+        // assert
+        assert_eq!(
+            comparison,
+            Comparison {
+                pixels: 16,
+                differing: 1,
+                max_difference: 10,
+            }
+        );
+        assert_eq!(comparison.share(), 1.0 / 16.0);
+        assert_eq!(
+            compare(&transparent, &RgbaImage::new(4, 4), 0)
+                .unwrap()
+                .differing,
+            0
+        );
+        assert_eq!(compare(&expected, &RgbaImage::new(4, 5), 255), None);
+    }
+
+    #[test]
+    fn check_writes_the_rendered_image_and_a_diff_of_mismatches() {
+        // arrange
+        let directory =
+            std::env::temp_dir().join(format!("robohash-golden-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let manifest = r#"{
+            "tolerance": { "channel": 1, "pixels": 0.0 },
+            "cases": [
+                { "name": "test", "input": "test", "algorithm": "v1", "width": 16, "height": 16 },
+                { "name": "missing", "input": "test", "algorithm": "v1", "width": 16, "height": 16 }
+            ]
+        }"#;
+        fs::write(directory.join(MANIFEST), manifest).unwrap();
+        let corpus = Corpus::load(&directory).unwrap();
+        corpus.bless(GoldenCase::render).unwrap();
+        fs::remove_file(directory.join("missing.png")).unwrap();
+        let blank = |_: &GoldenCase| Ok(RgbaImage::from_pixel(16, 16, Rgba([9, 9, 9, 255])));
+        // act
+        let matching = corpus.check(GoldenCase::render).unwrap();
+        let mismatches = corpus.check(blank).unwrap();
+        // assert
+        let diffs = directory.join(DIFFS);
+        let rendered = image::open(diffs.join("test.png")).unwrap().to_rgba8();
+        let diff = image::open(diffs.join("test.diff.png")).unwrap().to_rgba8();
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].name, "missing");
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches[0].reason.contains("pixels differ"));
+        assert_eq!(rendered.get_pixel(0, 0), &Rgba([9, 9, 9, 255]));
+        assert!(diff.pixels().any(|pixel| *pixel == Rgba([255, 0, 0, 255])));
+    }
+}
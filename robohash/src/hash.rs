@@ -1,25 +1,62 @@
-use std::io::{BufReader, Read};
-
-use data_encoding::HEXLOWER;
-use ring::digest::{Context, SHA512};
+use data_encoding::HEXLOWER_PERMISSIVE;
+use ring::digest::{digest as ring_digest, SHA256, SHA512};
 
 use crate::error::Error;
 
-pub(crate) fn sha512_digest(string: &str) -> Result<String, Error> {
-    let mut reader = BufReader::new(string.as_bytes());
-    let mut context = Context::new(&SHA512);
-    let mut buffer = [0; 1024];
-
-    loop {
-        let count = reader.read(&mut buffer)?;
-        if count == 0 {
-            break;
-        }
-        context.update(&buffer[..count]);
+/// Digests longer than SHA-512 would overflow the hash array chunks.
+pub(crate) const MAX_DIGEST_LENGTH: usize = 64;
+/// `V1` and upstream pick each trait from one of the 11 chunks of the hex digest,
+/// which need 3 hex digits to reach the 360 hues.
+pub(crate) const MIN_DIGEST_LENGTH: usize = 17;
+
+/// Hash function applied to texts and bytes before selecting the parts. Robots
+/// only match across implementations using the same digest, SHA-512 by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Digest {
+    #[default]
+    Sha512,
+    Sha256,
+    /// Needs the `blake3` feature.
+    Blake3,
+}
+
+pub(crate) fn digest(bytes: &[u8], algorithm: Digest) -> Result<Vec<u8>, Error> {
+    match algorithm {
+        Digest::Sha512 => Ok(ring_digest(&SHA512, bytes).as_ref().to_vec()),
+        Digest::Sha256 => Ok(ring_digest(&SHA256, bytes).as_ref().to_vec()),
+        Digest::Blake3 => blake3_digest(bytes),
     }
+}
+
+#[cfg(feature = "blake3")]
+fn blake3_digest(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(blake3::hash(bytes).as_bytes().to_vec())
+}
+
+#[cfg(not(feature = "blake3"))]
+fn blake3_digest(_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    Err(Error::FeatureDisabled(
+        String::from("blake3 digest"),
+        String::from("blake3"),
+    ))
+}
+
+pub(crate) fn from_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    HEXLOWER_PERMISSIVE
+        .decode(hex.as_bytes())
+        .map_err(|err| Error::InvalidDigest(err.to_string()))
+}
 
-    let digest = context.finish();
-    Ok(HEXLOWER.encode(digest.as_ref()))
+pub(crate) fn validate_digest(digest: &[u8]) -> Result<(), Error> {
+    if !(MIN_DIGEST_LENGTH..=MAX_DIGEST_LENGTH).contains(&digest.len()) {
+        return Err(Error::InvalidDigest(format!(
+            "expected {} to {} bytes, got {}",
+            MIN_DIGEST_LENGTH,
+            MAX_DIGEST_LENGTH,
+            digest.len()
+        )));
+    }
+    Ok(())
 }
 
 pub(crate) fn split_hash(hash: &str, chunks: usize) -> Result<Vec<i64>, Error> {
@@ -39,17 +76,81 @@ pub(crate) fn split_hash(hash: &str, chunks: usize) -> Result<Vec<i64>, Error> {
 
 #[cfg(test)]
 mod tests {
+    use data_encoding::HEXLOWER;
+
     use super::*;
 
     #[test]
-    fn sha512_digest_returns_hash_of_provided_string() {
+    fn digest_returns_sha512_hash_of_provided_string() {
         // arrange
         let initial_string = "initial_string";
         let expected_hash = "92ba5204aca5e21f60d40dda5b64e0e64e46028da5d33d2b577a0c80b6ed2843b46a458bbb0023d2634ecc7bccb2678e0b33f5ec0144fb124174325113396ef4";
         // act
-        let hash = sha512_digest(initial_string);
+        let hash = digest(initial_string.as_bytes(), Digest::Sha512);
+        // assert
+        assert_eq!(HEXLOWER.encode(&hash.unwrap()), expected_hash)
+    }
+
+    #[test]
+    fn digest_returns_sha256_hash_of_provided_string() {
+        // act
+        let hash = digest(b"initial_string", Digest::Sha256).unwrap();
+        // assert
+        assert_eq!(
+            HEXLOWER.encode(&hash),
+            "7dd6d09fd2da8380624415009db3da1b4bd0c4bac247d992cd77a6297400375d"
+        );
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn digest_returns_blake3_hash_of_provided_string() {
+        // act
+        let hash = digest(b"initial_string", Digest::Blake3).unwrap();
+        // assert
+        assert_eq!(hash, blake3::hash(b"initial_string").as_bytes());
+    }
+
+    #[cfg(not(feature = "blake3"))]
+    #[test]
+    fn digest_needs_the_blake3_feature_for_blake3() {
+        // act
+        let hash = digest(b"initial_string", Digest::Blake3);
+        // assert
+        assert_eq!(
+            hash.unwrap_err().to_string(),
+            "blake3 digest needs the \"blake3\" feature"
+        );
+    }
+
+    #[test]
+    fn from_hex_accepts_uppercase_and_rejects_odd_lengths() {
+        assert_eq!(from_hex("0aFf").unwrap(), vec![0x0a, 0xff]);
+        assert!(from_hex("abc").is_err());
+    }
+
+    #[test]
+    fn validate_digest_rejects_digests_longer_than_sha512() {
+        assert!(validate_digest(&[0; 64]).is_ok());
+        assert_eq!(
+            validate_digest(&[0; 65]).unwrap_err().to_string(),
+            "invalid digest: expected 17 to 64 bytes, got 65"
+        );
+    }
+
+    #[test]
+    fn validate_digest_rejects_digests_too_short_for_every_chunk_to_reach_the_hues() {
+        // arrange
+        let shortest = [0xff; MIN_DIGEST_LENGTH];
+        // act
+        let chunks = split_hash(&HEXLOWER.encode(&shortest), 11).unwrap();
         // assert
-        assert_eq!(hash.unwrap(), expected_hash)
+        assert!(validate_digest(&shortest).is_ok());
+        assert!(chunks.iter().all(|chunk| *chunk >= 359));
+        assert_eq!(
+            validate_digest(&[0xff; 16]).unwrap_err().to_string(),
+            "invalid digest: expected 17 to 64 bytes, got 16"
+        );
     }
 
     #[test]
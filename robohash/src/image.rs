@@ -1,21 +1,27 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
-use image::{imageops, DynamicImage, ImageBuffer, Rgba, RgbaImage};
+use image::{imageops, DynamicImage, GenericImageView, ImageBuffer, Rgba, RgbaImage};
 
 use base64::{engine::general_purpose, Engine as _};
 
+use crate::effects::Effect;
 use crate::error::Error;
+use crate::format::{encode, OutputFormat};
+use crate::layout::{Layout, Placement};
 
+/// Composes layers already resized to their placement in `layout`. The parts are
+/// run through `effects` before being drawn over the background.
 pub(crate) fn build_robo_hash_image(
-    robo_parts: &[String],
-    background: &Option<String>,
-    width: u32,
-    height: u32,
+    robo_parts: &[Arc<RgbaImage>],
+    background: &Option<Arc<RgbaImage>>,
+    layout: &Layout,
     hue_rotation: &Option<i32>,
+    effects: &[Arc<dyn Effect>],
 ) -> Result<RgbaImage, Error> {
-    let mut base_image = image::ImageBuffer::new(width, height);
+    let mut base_image = image::ImageBuffer::new(layout.width, layout.height);
     if let Some(background) = background {
-        append_to_image(&mut base_image, background, width, height, &0)?;
+        append_to_image(&mut base_image, background, &layout.background, &0)?;
     }
 
     let hue = match hue_rotation {
@@ -23,37 +29,55 @@ pub(crate) fn build_robo_hash_image(
         None => &0,
     };
 
-    robo_parts
-        .iter()
-        .try_for_each(|image_path| -> Result<(), Error> {
-            append_to_image(&mut base_image, image_path, width, height, hue)?;
-            Ok(())
-        })?;
+    if effects.is_empty() {
+        robo_parts
+            .iter()
+            .try_for_each(|image| -> Result<(), Error> {
+                append_to_image(&mut base_image, image, &layout.parts, hue)?;
+                Ok(())
+            })?;
+        return Ok(base_image);
+    }
+
+    let mut robot = image::ImageBuffer::new(layout.width, layout.height);
+    for image in robo_parts {
+        append_to_image(&mut robot, image, &layout.parts, hue)?;
+    }
+    apply_effects(&mut robot, effects);
+    imageops::overlay(&mut base_image, &robot, 0, 0);
     Ok(base_image)
 }
 
+pub(crate) fn apply_effects(robot: &mut RgbaImage, effects: &[Arc<dyn Effect>]) {
+    effects.iter().for_each(|effect| effect.apply(robot));
+}
+
 fn append_to_image(
     base_image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>,
-    image_path: &str,
-    width: u32,
-    height: u32,
+    image: &RgbaImage,
+    placement: &Placement,
     hue_rotation: &i32,
 ) -> Result<(), Error> {
-    // let image = try_open_image(image_path)?;
-    let image = from_base64(image_path)?;
-    let mut image = imageops::resize(&image, width, height, imageops::FilterType::Lanczos3);
+    let mut image = image.clone();
     imageops::colorops::huerotate_in_place(&mut image, *hue_rotation);
-    imageops::overlay(base_image, &image, 0, 0);
+    imageops::overlay(base_image, &image, placement.x, placement.y);
     Ok(())
 }
 
+pub(crate) fn resize<I>(image: &I, width: u32, height: u32) -> RgbaImage
+where
+    I: GenericImageView<Pixel = Rgba<u8>>,
+{
+    imageops::resize(image, width, height, imageops::FilterType::Lanczos3)
+}
+
 pub(crate) fn to_base_64(image: &RgbaImage) -> Result<String, Error> {
     let mut bytes: Vec<u8> = Vec::new();
-    image.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)?;
+    encode(image, &OutputFormat::Png, &mut bytes)?;
     Ok(general_purpose::STANDARD.encode(&bytes))
 }
 
-fn from_base64(base64_string: &str) -> Result<DynamicImage, Error> {
+pub(crate) fn from_base64(base64_string: &str) -> Result<DynamicImage, Error> {
     let decoded_bytes = general_purpose::STANDARD
         .decode(base64_string)
         .expect("Hardcoded base_64 strings should be decodable");
@@ -63,28 +87,20 @@ fn from_base64(base64_string: &str) -> Result<DynamicImage, Error> {
 }
 
 #[cfg(test)]
-pub(crate) mod tests {
-    use std::fs::File;
-    use std::io::Read;
-
+mod tests {
     use crate::backgrounds::BACKGROUNDS;
-    use crate::robot_parts::PARTS;
+    use crate::layout::SizeMode;
+    use crate::robot_parts::SETS;
 
     use super::*;
 
     #[test]
     fn build_robo_hash_image_returns_built_image_of_parts() {
         // arrange
-        let robo_parts = vec![
-            String::from(PARTS[0][0]),
-            String::from(PARTS[1][0]),
-            String::from(PARTS[2][0]),
-            String::from(PARTS[3][0]),
-            String::from(PARTS[4][0]),
-        ];
+        let robo_parts = green_parts();
         let hue_rotation = None;
         // act
-        let robo_hash = build_robo_hash_image(&robo_parts, &None, 512, 512, &hue_rotation);
+        let robo_hash = build_robo_hash_image(&robo_parts, &None, &layout(), &hue_rotation, &[]);
         // assert
         assert!(robo_hash.is_ok())
     }
@@ -92,36 +108,42 @@ pub(crate) mod tests {
     #[test]
     fn to_base64_converts_image_to_base64_string() {
         // arrange
-        let robo_parts = vec![
-            String::from(PARTS[0][0]),
-            String::from(PARTS[1][0]),
-            String::from(PARTS[2][0]),
-            String::from(PARTS[3][0]),
-            String::from(PARTS[4][0]),
-        ];
+        let robo_parts = green_parts();
         let hue_rotation = Some(90);
-        let background = Some(String::from(BACKGROUNDS[0]));
-        let expected_base64 = load_base64_string_image_resources("image");
-        let robo_hash = build_robo_hash_image(&robo_parts, &background, 512, 512, &hue_rotation)
-            .expect("Should return an actual ImageBuffer");
+        let background = Some(resized(BACKGROUNDS[0]));
+        let robo_hash =
+            build_robo_hash_image(&robo_parts, &background, &layout(), &hue_rotation, &[])
+                .expect("Should return an actual ImageBuffer");
         // act
         let base64_string = to_base_64(&robo_hash);
         // assert
-        assert!(base64_string.is_ok());
+        let png = general_purpose::STANDARD
+            .decode(base64_string.unwrap())
+            .unwrap();
+        let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(decoded, robo_hash)
+    }
 
-        // // Overwrite test image
-        // use std::io::Write;
-        // let mut output = File::create("./test_resources/image.txt").unwrap();
-        // write!(output, "{}", base64_string.unwrap());
+    fn layout() -> Layout {
+        Layout::new(512, 512, SizeMode::Stretch, 1.0)
+    }
+
+    fn resized(base64_string: &str) -> Arc<RgbaImage> {
+        Arc::new(resize(&from_base64(base64_string).unwrap(), 512, 512))
+    }
 
-        assert_eq!(base64_string.unwrap(), expected_base64)
+    fn green_parts() -> Vec<Arc<RgbaImage>> {
+        let green = green();
+        (0..5)
+            .map(|layer| resized(SETS[0].parts[green][layer][0]))
+            .collect()
     }
 
-    pub(crate) fn load_base64_string_image_resources(filename: &str) -> String {
-        let mut file_contents = String::new();
-        let file_location = format!("./test_resources/{}.txt", filename);
-        let mut file = File::open(file_location).unwrap();
-        file.read_to_string(&mut file_contents).unwrap();
-        file_contents
+    fn green() -> usize {
+        SETS[0]
+            .colors
+            .iter()
+            .position(|color| *color == "green")
+            .unwrap()
     }
 }
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+
+/// How the square art is fitted into the requested image size.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeMode {
+    /// Stretches the art to the image size, distorting it when the size is not square.
+    #[default]
+    Stretch,
+    /// Fits the whole art in the image, centered with transparent padding.
+    Contain,
+    /// Fills the image with the art, centered and cropped.
+    Cover,
+}
+
+/// Where the background and the parts are drawn on a `width`x`height` canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) background: Placement,
+    pub(crate) parts: Placement,
+}
+
+/// Position of a layer on the canvas, it may overflow the canvas and get cropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Placement {
+    pub(crate) x: i64,
+    pub(crate) y: i64,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl Layout {
+    /// `scale` shrinks the parts inside the background, leaving a margin around the robot.
+    pub(crate) fn new(width: u32, height: u32, size_mode: SizeMode, scale: f32) -> Self {
+        let canvas = Placement {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        };
+        let background = match size_mode {
+            SizeMode::Stretch => canvas,
+            SizeMode::Contain => canvas.centered(width.min(height), width.min(height)),
+            SizeMode::Cover => canvas.centered(width.max(height), width.max(height)),
+        };
+        let scaled = |side: u32| ((side as f32 * scale).round() as u32).max(1);
+        let parts = background.centered(scaled(background.width), scaled(background.height));
+
+        Self {
+            width,
+            height,
+            background,
+            parts,
+        }
+    }
+}
+
+impl Layout {
+    /// Placement of a `width`x`height` image fitted to the canvas like the art.
+    pub(crate) fn fit(&self, size_mode: SizeMode, width: u32, height: u32) -> Placement {
+        let canvas = Placement {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        };
+        let width_ratio = self.width as f64 / width as f64;
+        let height_ratio = self.height as f64 / height as f64;
+        let ratio = match size_mode {
+            SizeMode::Stretch => return canvas,
+            SizeMode::Contain => width_ratio.min(height_ratio),
+            SizeMode::Cover => width_ratio.max(height_ratio),
+        };
+        let scaled = |side: u32| ((side as f64 * ratio).round() as u32).max(1);
+        canvas.centered(scaled(width), scaled(height))
+    }
+}
+
+impl Placement {
+    fn centered(&self, width: u32, height: u32) -> Placement {
+        Placement {
+            x: self.x + (self.width as i64 - width as i64) / 2,
+            y: self.y + (self.height as i64 - height as i64) / 2,
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placement(x: i64, y: i64, width: u32, height: u32) -> Placement {
+        Placement {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn stretch_draws_every_layer_over_the_whole_image() {
+        // act
+        let layout = Layout::new(300, 100, SizeMode::Stretch, 1.0);
+        // assert
+        assert_eq!(layout.background, placement(0, 0, 300, 100));
+        assert_eq!(layout.parts, placement(0, 0, 300, 100));
+    }
+
+    #[test]
+    fn contain_centers_the_art_on_the_longest_side() {
+        // act
+        let layout = Layout::new(300, 100, SizeMode::Contain, 1.0);
+        // assert
+        assert_eq!(layout.background, placement(100, 0, 100, 100));
+        assert_eq!(layout.parts, placement(100, 0, 100, 100));
+    }
+
+    #[test]
+    fn cover_crops_the_art_on_the_shortest_side() {
+        // act
+        let layout = Layout::new(400, 300, SizeMode::Cover, 1.0);
+        // assert
+        assert_eq!(layout.background, placement(0, -50, 400, 400));
+    }
+
+    #[test]
+    fn fit_places_other_images_like_the_square_art() {
+        // arrange
+        let contain = Layout::new(300, 100, SizeMode::Contain, 1.0);
+        let cover = Layout::new(400, 300, SizeMode::Cover, 1.0);
+        // act
+        let wide = contain.fit(SizeMode::Contain, 200, 100);
+        let square = cover.fit(SizeMode::Cover, 100, 100);
+        // assert
+        assert_eq!(wide, placement(50, 0, 200, 100));
+        assert_eq!(square, cover.background);
+        assert_eq!(
+            contain.fit(SizeMode::Stretch, 10, 70),
+            placement(0, 0, 300, 100)
+        );
+    }
+
+    #[test]
+    fn scale_leaves_a_margin_around_the_parts() {
+        // act
+        let layout = Layout::new(200, 200, SizeMode::Stretch, 0.8);
+        // assert
+        assert_eq!(layout.background, placement(0, 0, 200, 200));
+        assert_eq!(layout.parts, placement(20, 20, 160, 160));
+    }
+}
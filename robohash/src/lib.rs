@@ -1,30 +1,228 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use ::image::{imageops, Rgba};
+use base64::{engine::general_purpose, Engine as _};
+use data_encoding::HEXLOWER;
+
+use crate::algorithm::Picker;
+use crate::animation::BlinkEyes;
+use crate::catalog::SetCatalog;
+use crate::effects::Effect;
 use crate::error::Error;
+use crate::layout::Layout;
+use crate::mask::Mask;
+use crate::overrides::{Choices, LayerChoices};
+use crate::provider::{EmbeddedParts, PartsProvider};
+use crate::recolor::Recoloring;
+use crate::similarity::PerceptualHash;
+use crate::trait_code::TraitCode;
+use crate::traits::{ColorTrait, PartTrait};
 
+pub use crate::algorithm::AlgorithmVersion;
+pub use crate::animation::{Animation, AnimationFormat, Motion};
+pub use crate::catalog::RoboSet;
+pub use crate::describe::Locale;
+pub use crate::format::OutputFormat;
+pub use crate::hash::Digest;
+pub use crate::layout::SizeMode;
+pub use crate::overrides::{Background, EmbeddedBackground, Layer};
+pub use crate::traits::RoboTraits;
+pub use crate::upstream::Compatibility;
+pub use ::image::RgbaImage;
+
+mod algorithm;
+pub mod animation;
 mod backgrounds;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod cache;
+pub mod catalog;
+pub mod describe;
+mod descriptions;
+pub mod effects;
 pub mod error;
+pub mod format;
+#[cfg(any(test, feature = "golden"))]
+pub mod golden;
 mod hash;
 mod image;
+pub mod layout;
+pub mod mask;
+pub mod overrides;
+pub mod pack;
+pub mod provider;
+pub mod recolor;
 mod robot_parts;
+pub mod similarity;
+mod svg;
+mod trait_code;
+pub mod traits;
+mod upstream;
 
 pub struct RoboHashBuilder<'a> {
-    text: &'a str,
+    input: Input<'a>,
+    digest: Digest,
+    compatibility: Compatibility,
+    algorithm: AlgorithmVersion,
     image_size: ImageSize,
-    use_background: &'a bool,
+    size_mode: SizeMode,
+    scale: f32,
+    background: Background,
+    use_hue_rotation: &'a bool,
+    hue: Option<i32>,
+    part_overrides: Vec<(Layer, usize)>,
+    recoloring: Recoloring,
+    effects: Vec<Arc<dyn Effect>>,
+    mask: Option<Mask>,
+    set: RoboSet,
+    parts_provider: Arc<dyn PartsProvider>,
+}
+
+/// What the hash array is computed from.
+#[derive(Debug, Clone, Copy)]
+enum Input<'a> {
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    Digest(&'a [u8]),
+    Hex(&'a str),
+    TraitCode(&'a str),
 }
 
 impl<'a> RoboHashBuilder<'a> {
     pub fn new(text: &'a str) -> Self {
+        Self::from_input(Input::Text(text))
+    }
+
+    /// Hashes `bytes` instead of a text, `new(text)` is `from_bytes(text.as_bytes())`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self::from_input(Input::Bytes(bytes))
+    }
+
+    /// Uses an already computed digest, 17 to 64 bytes, as the hash. A SHA-512
+    /// digest of a text gives the same robot as `new(text)`.
+    pub fn from_digest(digest: &'a [u8]) -> Self {
+        Self::from_input(Input::Digest(digest))
+    }
+
+    /// Like `from_digest` with a hex encoded digest.
+    pub fn from_hex(hex: &'a str) -> Self {
+        Self::from_input(Input::Hex(hex))
+    }
+
+    /// Robot of a code returned by `RoboHash::trait_code`, with the same traits
+    /// whatever the digest and compatibility. Overrides still apply.
+    pub fn from_trait_code(code: &'a str) -> Self {
+        Self::from_input(Input::TraitCode(code))
+    }
+
+    fn from_input(input: Input<'a>) -> Self {
         let image_size = ImageSize::default();
-        let use_background = &true;
+        let size_mode = SizeMode::default();
+        let scale = 1.0;
+        let background = Background::default();
+        let use_hue_rotation = &false;
+        let set = RoboSet::default();
+        let parts_provider = Arc::new(EmbeddedParts::new());
         Self {
-            text,
+            input,
+            digest: Digest::default(),
+            compatibility: Compatibility::default(),
+            algorithm: AlgorithmVersion::default(),
             image_size,
-            use_background,
+            size_mode,
+            scale,
+            background,
+            use_hue_rotation,
+            hue: None,
+            part_overrides: Vec::new(),
+            recoloring: Recoloring::default(),
+            effects: Vec::new(),
+            mask: None,
+            set,
+            parts_provider,
         }
     }
 
-    pub fn with_background(mut self, use_background: &'a bool) -> RoboHashBuilder<'a> {
-        self.use_background = use_background;
+    /// `&true` (default) picks a background from the hash, `&false` draws none,
+    /// other `Background`s draw a given background, a color or an image.
+    pub fn with_background(mut self, background: impl Into<Background>) -> RoboHashBuilder<'a> {
+        self.background = background.into();
+        self
+    }
+
+    pub fn with_hue_rotation(mut self, use_hue_rotation: &'a bool) -> RoboHashBuilder<'a> {
+        self.use_hue_rotation = use_hue_rotation;
+        self
+    }
+
+    /// Rotates the hue by `degrees` instead of by an angle picked from the hash,
+    /// with or without `with_hue_rotation`.
+    pub fn with_hue(mut self, degrees: i32) -> RoboHashBuilder<'a> {
+        self.hue = Some(degrees.rem_euclid(360));
+        self
+    }
+
+    /// Draws the part at `index` on `layer` instead of the part picked from the
+    /// hash, the other layers are still picked from the hash. `RoboHash::choices`
+    /// lists the valid indices, `build` fails on others.
+    pub fn with_part(mut self, layer: Layer, index: usize) -> RoboHashBuilder<'a> {
+        self.part_overrides.retain(|(other, _)| *other != layer);
+        self.part_overrides.push((layer, index));
+        self
+    }
+
+    /// How the parts of robots with a hue are recolored, by rotating the hue of
+    /// every pixel by default.
+    pub fn with_recoloring(mut self, recoloring: Recoloring) -> RoboHashBuilder<'a> {
+        self.recoloring = recoloring;
+        self
+    }
+
+    /// Adds a post-processing effect, like an `Outline`, run on the robot before it
+    /// is drawn over the background. Effects run in the order they were added.
+    pub fn with_effect<E: Effect + 'static>(mut self, effect: E) -> RoboHashBuilder<'a> {
+        self.effects.push(Arc::new(effect));
+        self
+    }
+
+    /// Cuts the robot to a shape, with an optional border. Applied to every output
+    /// format and animation frame.
+    pub fn with_mask(mut self, mask: Mask) -> RoboHashBuilder<'a> {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Hash function for texts and bytes, ignored for precomputed digests.
+    pub fn with_digest(mut self, digest: Digest) -> RoboHashBuilder<'a> {
+        self.digest = digest;
+        self
+    }
+
+    /// Picks the parts like upstream Robohash instead of like RoboSats.
+    pub fn with_compatibility(mut self, compatibility: Compatibility) -> RoboHashBuilder<'a> {
+        self.compatibility = compatibility;
+        self
+    }
+
+    /// How the traits are picked from the digest, `AlgorithmVersion::LATEST` by
+    /// default. Pin `V1` to keep the traits of avatars already shown. Ignored with
+    /// `Compatibility::Upstream`, which always picks like upstream Robohash.
+    pub fn with_algorithm(mut self, algorithm: AlgorithmVersion) -> RoboHashBuilder<'a> {
+        self.algorithm = algorithm;
+        self
+    }
+
+    pub fn with_set(mut self, set: RoboSet) -> RoboHashBuilder<'a> {
+        self.set = set;
+        self
+    }
+
+    pub fn with_parts_provider(
+        mut self,
+        parts_provider: Arc<dyn PartsProvider>,
+    ) -> RoboHashBuilder<'a> {
+        self.parts_provider = parts_provider;
         self
     }
 
@@ -33,25 +231,173 @@ impl<'a> RoboHashBuilder<'a> {
         self
     }
 
+    /// How the art is fitted when the size is not square, stretched by default.
+    pub fn with_size_mode(mut self, size_mode: SizeMode) -> RoboHashBuilder<'a> {
+        self.size_mode = size_mode;
+        self
+    }
+
+    /// Size of the robot relative to its background, from 0 (excluded) to 1 (default).
+    pub fn with_scale(mut self, scale: f32) -> RoboHashBuilder<'a> {
+        self.scale = scale;
+        self
+    }
+
     pub fn build(&self) -> Result<RoboHash, Error> {
-        let hash_array_chunks = 11;
-        let hash = hash::sha512_digest(self.text)?;
-        let hash_array = hash::split_hash(&hash, hash_array_chunks)?;
-        let use_background = self.use_background.to_owned();
+        if !(self.scale > 0.0 && self.scale <= 1.0) {
+            return Err(Error::InvalidScale(self.scale.to_string()));
+        }
+        let digest = match self.input {
+            Input::Text(text) => {
+                let text = match self.compatibility {
+                    Compatibility::Robosats => text,
+                    Compatibility::Upstream => upstream::remove_extension(text),
+                };
+                hash::digest(text.as_bytes(), self.digest)?
+            }
+            Input::Bytes(bytes) => hash::digest(bytes, self.digest)?,
+            Input::Digest(digest) => digest.to_vec(),
+            Input::Hex(hex) => hash::from_hex(hex)?,
+            Input::TraitCode(code) => return self.build_from_trait_code(code),
+        };
+        hash::validate_digest(&digest)?;
+        let hash_array = hash::split_hash(&HEXLOWER.encode(&digest), HASH_ARRAY_CHUNKS)?;
+        let use_hue_rotation = self.use_hue_rotation.to_owned();
+        let sets = self.parts_provider.sets();
+        let picker = Picker::new(self.algorithm, &hash_array, &digest);
+        let set = match self.compatibility {
+            Compatibility::Robosats => select_set(&self.set, &picker, sets)?,
+            Compatibility::Upstream => upstream::select_set(&self.set, &hash_array, sets)?,
+        };
+        let parts_provider = self.parts_provider.clone();
 
-        Ok(RoboHash {
+        let robo_hash = RoboHash {
             image_size: self.image_size,
+            size_mode: self.size_mode,
+            scale: self.scale,
             hash_array,
-            use_background,
-        })
+            digest,
+            compatibility: self.compatibility,
+            algorithm: self.algorithm,
+            background: self.background.clone(),
+            use_hue_rotation,
+            hue: self.hue,
+            color: None,
+            palette_color: None,
+            part_overrides: self.part_overrides.clone(),
+            recoloring: self.recoloring.clone(),
+            effects: self.effects.clone(),
+            mask: self.mask,
+            set,
+            parts_provider,
+        };
+        // Fails on overrides out of the choices of the robot
+        robo_hash.select(catalog::find(sets, set)?)?;
+        Ok(robo_hash)
+    }
+
+    /// Every trait comes from the code, the hash array is left blank.
+    fn build_from_trait_code(&self, code: &str) -> Result<RoboHash, Error> {
+        let code = code.parse::<TraitCode>()?;
+        let set_catalog = catalog::find(self.parts_provider.sets(), code.set)?;
+        code.validate_layers(set_catalog)?;
+        let mut part_overrides = set_catalog
+            .layers
+            .iter()
+            .zip(&code.parts)
+            .map(|(layer, (_, index))| (Layer::Named(layer.name.clone()), *index))
+            .collect::<Vec<_>>();
+        for (layer, index) in &self.part_overrides {
+            part_overrides.retain(|(other, _)| !other.name().eq_ignore_ascii_case(layer.name()));
+            part_overrides.push((layer.clone(), *index));
+        }
+        let background = match (&self.background, code.background, code.background_color) {
+            (background, ..) if *background != Background::default() => background.clone(),
+            (_, Some(index), _) => Background::Embedded(EmbeddedBackground::Id(index)),
+            (_, None, Some(color)) => Background::Solid(color),
+            (_, None, None) => Background::Transparent,
+        };
+
+        let robo_hash = RoboHash {
+            image_size: self.image_size,
+            size_mode: self.size_mode,
+            scale: self.scale,
+            hash_array: vec![0; HASH_ARRAY_CHUNKS * 2],
+            digest: Vec::new(),
+            compatibility: self.compatibility,
+            algorithm: self.algorithm,
+            background,
+            use_hue_rotation: false,
+            hue: self.hue.or(code.hue),
+            color: Some(code.color),
+            palette_color: code.palette_color,
+            part_overrides,
+            recoloring: self.recoloring.clone(),
+            effects: self.effects.clone(),
+            mask: self.mask,
+            set: code.set,
+            parts_provider: self.parts_provider.clone(),
+        };
+        robo_hash.select(set_catalog)?;
+        Ok(robo_hash)
     }
 }
 
+const HASH_ARRAY_CHUNKS: usize = 11;
+// Slots 6 to 10 pick the background, hue, color, set and blinking eyes and the last
+// one the palette color, the layers after the sixth take the slots in between
+const FIRST_RESERVED_SLOT: usize = 6;
+const RESERVED_SLOTS: usize = 5;
+const PALETTE_SLOT: usize = HASH_ARRAY_CHUNKS * 2 - 1;
+/// Most layers a set can have, `V1` picks each one from a slot of the hash array.
+pub(crate) const MAX_LAYERS: usize = PALETTE_SLOT - RESERVED_SLOTS;
+
 #[derive(Debug)]
 pub struct RoboHash {
     image_size: ImageSize,
+    size_mode: SizeMode,
+    scale: f32,
     hash_array: Vec<i64>,
-    use_background: bool,
+    digest: Vec<u8>,
+    compatibility: Compatibility,
+    algorithm: AlgorithmVersion,
+    background: Background,
+    use_hue_rotation: bool,
+    hue: Option<i32>,
+    color: Option<usize>,
+    palette_color: Option<usize>,
+    part_overrides: Vec<(Layer, usize)>,
+    recoloring: Recoloring,
+    effects: Vec<Arc<dyn Effect>>,
+    mask: Option<Mask>,
+    set: RoboSet,
+    parts_provider: Arc<dyn PartsProvider>,
+}
+
+/// Indices picked from the hash or overridden.
+#[derive(Debug, PartialEq, Eq)]
+struct Selection {
+    color: usize,
+    parts: Vec<usize>,
+    background: Option<usize>,
+    background_color: Option<[u8; 4]>,
+    hue_rotation: Option<i32>,
+    palette_color: Option<usize>,
+}
+
+/// Images selected from the hash and resized to their placement, parts in stacking order.
+struct Layers {
+    layout: Layout,
+    color: usize,
+    indices: Vec<usize>,
+    names: Vec<String>,
+    parts: Vec<Arc<RgbaImage>>,
+    background: Option<Arc<RgbaImage>>,
+    hue_rotation: Option<i32>,
+    /// Hue the parts were recolored to with a palette, instead of being rotated.
+    palette_hue: Option<i32>,
+    /// Color of the palette colors the parts were recolored to instead of the hue.
+    palette_color: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -70,30 +416,480 @@ impl ImageSize {
 }
 
 impl RoboHash {
+    /// Base64 of the robot encoded as PNG.
     pub fn assemble_base64(&self) -> Result<String, Error> {
-        if self.is_missing_required_data() {
-            return Err(Error::RoboHashMissingRequiredData);
+        let image = self.render()?;
+        let base64 = image::to_base_64(&image)?;
+        Ok(base64)
+    }
+
+    pub fn assemble(&self, format: OutputFormat) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes, format)?;
+        Ok(bytes)
+    }
+
+    pub fn write_to<W: Write>(&self, writer: W, format: OutputFormat) -> Result<(), Error> {
+        let image = self.render()?;
+        format::encode(&image, &format, writer)
+    }
+
+    /// `data:<mime type>;base64,...`, ready for an `<img src>`.
+    pub fn assemble_data_uri(&self, format: OutputFormat) -> Result<String, Error> {
+        let bytes = self.assemble(format)?;
+        Ok(format!(
+            "data:{};base64,{}",
+            format.mime_type(),
+            general_purpose::STANDARD.encode(bytes)
+        ))
+    }
+
+    /// Composes the robot without encoding it.
+    pub fn render(&self) -> Result<RgbaImage, Error> {
+        let layers = self.load_layers()?;
+        let mut image = image::build_robo_hash_image(
+            &layers.parts,
+            &layers.background,
+            &layers.layout,
+            &layers.hue_rotation,
+            &self.effects,
+        )?;
+        if let Some(mask) = &self.mask {
+            mask.apply(&mut image);
         }
+        Ok(image)
+    }
 
-        let set = select_robot_parts(&self.hash_array);
+    /// SVG document with one `<image>` per layer. The hue rotation is applied by a
+    /// `feColorMatrix` filter and the mask by a `clipPath` instead of being baked
+    /// into the parts. With effects, the parts are baked into one image with them.
+    pub fn assemble_svg(&self) -> Result<String, Error> {
+        let layers = self.load_layers()?;
+        svg::build_robo_hash_svg(
+            &layers.names,
+            &layers.parts,
+            &layers.background,
+            &layers.layout,
+            &layers.hue_rotation,
+            &self.effects,
+            &self.mask,
+        )
+    }
 
-        let background = match &self.use_background {
-            true => select_background(&self.hash_array),
+    /// Frames of an animated robot. The first frame is the still robot.
+    pub fn render_frames(&self, animation: &Animation) -> Result<Vec<RgbaImage>, Error> {
+        let layers = self.load_layers()?;
+        let blink_eyes = match animation.motions.contains(&Motion::Blink) {
+            true => self.load_blink_eyes(&layers)?,
             false => None,
         };
+        let mut frames = animation::build_robo_hash_frames(
+            &layers.parts,
+            &layers.background,
+            &layers.layout,
+            &layers.hue_rotation,
+            &blink_eyes,
+            &self.effects,
+            animation,
+        )?;
+        if let Some(mask) = &self.mask {
+            frames.iter_mut().for_each(|frame| mask.apply(frame));
+        }
+        Ok(frames)
+    }
+
+    pub fn assemble_animation(
+        &self,
+        animation: &Animation,
+        format: AnimationFormat,
+    ) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        self.write_animation_to(&mut bytes, animation, format)?;
+        Ok(bytes)
+    }
 
-        let hue_rotation = select_hue_rotation(&self.hash_array);
+    pub fn write_animation_to<W: Write>(
+        &self,
+        writer: W,
+        animation: &Animation,
+        format: AnimationFormat,
+    ) -> Result<(), Error> {
+        let frames = self.render_frames(animation)?;
+        animation::encode(&frames, animation.frame_duration, &format, writer)
+    }
+
+    /// Composes the robot over `canvas` with its top left corner at `x`, `y`.
+    /// Parts falling outside of the canvas are clipped.
+    pub fn render_onto(&self, canvas: &mut RgbaImage, x: i64, y: i64) -> Result<(), Error> {
+        let image = self.render()?;
+        imageops::overlay(canvas, &image, x, y);
+        Ok(())
+    }
+
+    /// Set, color, parts, background and hue of the robot, without loading any image.
+    pub fn traits(&self) -> Result<RoboTraits, Error> {
+        if self.is_missing_required_data() {
+            return Err(Error::RoboHashMissingRequiredData);
+        }
+
+        let set_catalog = catalog::find(self.parts_provider.sets(), self.set)?;
+        let selection = self.select(set_catalog)?;
+        let parts = set_catalog
+            .layers
+            .iter()
+            .zip(selection.parts)
+            .map(|(layer, index)| PartTrait {
+                layer: layer.name.clone(),
+                index,
+            })
+            .collect();
+
+        Ok(RoboTraits {
+            compatibility: self.compatibility,
+            version: self.algorithm,
+            set: self.set,
+            color: ColorTrait {
+                index: selection.color,
+                name: set_catalog.colors[selection.color].clone(),
+            },
+            parts,
+            background: selection.background,
+            background_color: selection.background_color,
+            hue: selection.hue_rotation,
+            palette_color: selection.palette_color,
+        })
+    }
+
+    /// Compact code of the traits, e.g. `RH1-set1-c7-b3f7m2e9a4-bg12-h250`, that
+    /// `RoboHashBuilder::from_trait_code` renders back without the hashed input.
+    pub fn trait_code(&self) -> Result<String, Error> {
+        Ok(TraitCode::from_traits(&self.traits()?).to_string())
+    }
+
+    /// How far the traits of both robots are, from 0 for the same traits to 1, see
+    /// `similarity::trait_distance`.
+    pub fn distance(&self, other: &RoboHash) -> Result<f32, Error> {
+        Ok(similarity::trait_distance(
+            &self.traits()?,
+            &other.traits()?,
+        ))
+    }
 
-        let image = image::build_robo_hash_image(
-            &set,
-            &background,
-            self.image_size.width,
-            self.image_size.height,
-            &hue_rotation,
+    /// Fingerprint of the rendered robot, close for robots that look alike. Only the
+    /// parts are hashed, a shared background would make any two robots look close.
+    pub fn perceptual_hash(&self) -> Result<PerceptualHash, Error> {
+        let layers = self.load_layers()?;
+        let robot = image::build_robo_hash_image(
+            &layers.parts,
+            &None,
+            &layers.layout,
+            &layers.hue_rotation,
+            &[],
         )?;
+        Ok(PerceptualHash::from_image(&robot))
+    }
 
-        let base64 = image::to_base_64(&image)?;
-        Ok(base64)
+    /// Alt text describing the robot, e.g. "a blue robot with a bulky body, laser
+    /// eyes and a bolt, on a starry night sky". The color is left out when the hue
+    /// is rotated, as are the parts the provider has no description for.
+    pub fn describe(&self, locale: Locale) -> Result<String, Error> {
+        let traits = self.traits()?;
+        let provider = &self.parts_provider;
+        let color = match traits.hue {
+            Some(_) => None,
+            None => provider.color_description(traits.set, traits.color.index),
+        };
+        let parts = traits
+            .parts
+            .iter()
+            .enumerate()
+            .filter_map(|(layer, part)| {
+                provider.part_description(traits.set, traits.color.index, layer, part.index)
+            })
+            .collect::<Vec<_>>();
+        let background = match (traits.background, traits.background_color, &self.background) {
+            (Some(index), ..) => Some(
+                provider
+                    .background_description(index)
+                    .map(|description| description.get(locale).to_string())
+                    .unwrap_or_else(|| describe::background(locale).to_string()),
+            ),
+            (None, Some(_), _) => Some(describe::plain_background(locale).to_string()),
+            (None, None, Background::Custom(_)) => Some(describe::background(locale).to_string()),
+            (None, None, _) => None,
+        };
+
+        Ok(describe::describe(
+            locale,
+            color.as_ref().map(|color| color.get(locale)),
+            &parts
+                .iter()
+                .map(|part| part.get(locale))
+                .collect::<Vec<_>>(),
+            background.as_deref(),
+        ))
+    }
+
+    /// Parts of each layer in the color of the robot and backgrounds, the values
+    /// `with_part` and `with_background` accept.
+    pub fn choices(&self) -> Result<Choices, Error> {
+        if self.is_missing_required_data() {
+            return Err(Error::RoboHashMissingRequiredData);
+        }
+
+        let set_catalog = catalog::find(self.parts_provider.sets(), self.set)?;
+        let color = self.select(set_catalog)?.color;
+        let layers = set_catalog
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(layer, layer_catalog)| LayerChoices {
+                layer: layer_catalog.name.clone(),
+                parts: set_catalog.layer_count(layer, color),
+            })
+            .collect();
+        Ok(Choices {
+            layers,
+            backgrounds: self.parts_provider.backgrounds(),
+        })
+    }
+
+    fn load_layers(&self) -> Result<Layers, Error> {
+        if self.is_missing_required_data() {
+            return Err(Error::RoboHashMissingRequiredData);
+        }
+
+        let provider = &self.parts_provider;
+        let mut layout = self.layout();
+        let set_catalog = catalog::find(provider.sets(), self.set)?;
+        let Selection {
+            color,
+            parts: indices,
+            background,
+            background_color,
+            hue_rotation,
+            palette_color,
+        } = self.select(set_catalog)?;
+        let parts = indices
+            .iter()
+            .enumerate()
+            .map(|(layer, index)| {
+                let placement = &layout.parts;
+                provider.resized_part(
+                    self.set,
+                    color,
+                    layer,
+                    *index,
+                    placement.width,
+                    placement.height,
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let names = set_catalog
+            .layers
+            .iter()
+            .map(|layer| layer.name.clone())
+            .collect::<Vec<_>>();
+        let (parts, hue_rotation, palette_hue) = match (&self.recoloring, hue_rotation) {
+            (Recoloring::Palette(palette), Some(hue)) => {
+                let parts = parts
+                    .iter()
+                    .zip(&names)
+                    .map(|(part, name)| palette.recolor(part, name, hue, palette_color))
+                    .collect();
+                (parts, None, Some(hue))
+            }
+            _ => (parts, hue_rotation, None),
+        };
+
+        if let Background::Custom(image) = &self.background {
+            let (width, height) = image.dimensions();
+            layout.background = layout.fit(self.size_mode, width, height);
+        }
+        let placement = &layout.background;
+        let background = match (&self.background, background, background_color) {
+            (_, Some(index), _) => {
+                Some(provider.resized_background(index, placement.width, placement.height)?)
+            }
+            (_, None, Some(color)) => Some(Arc::new(RgbaImage::from_pixel(
+                placement.width,
+                placement.height,
+                Rgba(color),
+            ))),
+            (Background::Custom(image), None, None) => Some(Arc::new(image::resize(
+                image.as_ref(),
+                placement.width,
+                placement.height,
+            ))),
+            _ => None,
+        };
+
+        Ok(Layers {
+            layout,
+            color,
+            indices,
+            names,
+            parts,
+            background,
+            hue_rotation,
+            palette_hue,
+            palette_color,
+        })
+    }
+
+    fn select(&self, set_catalog: &SetCatalog) -> Result<Selection, Error> {
+        set_catalog.validate()?;
+        let hash_array = &self.hash_array;
+        let picker = self.picker();
+        let colors = set_catalog.colors.len();
+        if let Some(color) = self.color.filter(|color| *color >= colors) {
+            return Err(Error::InvalidOverride(format!(
+                "{} has {} colors, got color {}",
+                set_catalog.set, colors, color
+            )));
+        }
+        let (color, mut parts) = match self.compatibility {
+            Compatibility::Robosats => {
+                let color = self
+                    .color
+                    .unwrap_or_else(|| select_color(&picker, set_catalog));
+                (color, select_robot_parts(&picker, set_catalog, color))
+            }
+            Compatibility::Upstream => {
+                let color = self
+                    .color
+                    .unwrap_or_else(|| upstream::select_color(hash_array, set_catalog));
+                let parts = upstream::select_robot_parts(hash_array, set_catalog, color);
+                (color, parts)
+            }
+        };
+        for (layer, index) in &self.part_overrides {
+            let position = set_catalog
+                .layers
+                .iter()
+                .position(|other| other.name.eq_ignore_ascii_case(layer.name()))
+                .ok_or_else(|| {
+                    Error::InvalidOverride(format!("{} has no {} layer", set_catalog.set, layer))
+                })?;
+            let count = set_catalog.layer_count(position, color);
+            if *index >= count {
+                return Err(Error::InvalidOverride(format!(
+                    "{} {} has {} parts, got part {}",
+                    set_catalog.colors[color], layer, count, index
+                )));
+            }
+            parts[position] = *index;
+        }
+        let (background, background_color) = match &self.background {
+            Background::Embedded(embedded) => (self.select_embedded_background(embedded)?, None),
+            Background::Solid(color) => (None, Some(*color)),
+            Background::Derived => (None, Some(select_background_color(&picker))),
+            Background::Custom(_) | Background::Transparent => (None, None),
+        };
+        let hue_rotation = match (self.hue, self.use_hue_rotation) {
+            (Some(hue), _) => Some(hue),
+            (None, true) => select_hue_rotation(&picker),
+            (None, false) => None,
+        };
+        let palette_color = match (&self.recoloring, hue_rotation) {
+            (Recoloring::Palette(palette), Some(_)) if !palette.colors.is_empty() => {
+                let colors = palette.colors.len();
+                if let Some(index) = self.palette_color.filter(|index| *index >= colors) {
+                    return Err(Error::InvalidOverride(format!(
+                        "the palette has {} colors, got palette color {}",
+                        colors, index
+                    )));
+                }
+                Some(
+                    self.palette_color
+                        .unwrap_or_else(|| select_palette_color(&picker, colors)),
+                )
+            }
+            _ => None,
+        };
+        Ok(Selection {
+            color,
+            parts,
+            background,
+            background_color,
+            hue_rotation,
+            palette_color,
+        })
+    }
+
+    fn select_embedded_background(
+        &self,
+        embedded: &EmbeddedBackground,
+    ) -> Result<Option<usize>, Error> {
+        let picker = self.picker();
+        let provider = &self.parts_provider;
+        let backgrounds = provider.backgrounds();
+        let check = |index: &usize| match *index < backgrounds {
+            true => Ok(()),
+            false => Err(Error::InvalidOverride(format!(
+                "{} backgrounds, got background {}",
+                backgrounds, index
+            ))),
+        };
+        match (embedded, self.compatibility) {
+            (EmbeddedBackground::Auto, Compatibility::Robosats) => {
+                Ok(select_background(&picker, backgrounds))
+            }
+            (EmbeddedBackground::Auto, Compatibility::Upstream) => Ok(upstream::select_background(
+                &self.hash_array,
+                &provider.background_sets(),
+            )),
+            (EmbeddedBackground::Id(index), _) => {
+                check(index)?;
+                Ok(Some(*index))
+            }
+            (EmbeddedBackground::Subset(indices), _) => {
+                indices.iter().try_for_each(check)?;
+                Ok(select_background(&picker, indices.len()).map(|i| indices[i]))
+            }
+        }
+    }
+
+    fn load_blink_eyes(&self, layers: &Layers) -> Result<Option<BlinkEyes>, Error> {
+        let provider = &self.parts_provider;
+        let set_catalog = catalog::find(provider.sets(), self.set)?;
+        // Sets without an eyes layer do not blink
+        let Some(layer) = layers
+            .names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case("eyes"))
+        else {
+            return Ok(None);
+        };
+        let count = set_catalog.layer_count(layer, layers.color);
+        select_blink_eyes(&self.picker(), count, layers.indices[layer])
+            .map(|index| {
+                let placement = &layers.layout.parts;
+                let mut part = provider.resized_part(
+                    self.set,
+                    layers.color,
+                    layer,
+                    index,
+                    placement.width,
+                    placement.height,
+                )?;
+                if let (Recoloring::Palette(palette), Some(hue)) =
+                    (&self.recoloring, layers.palette_hue)
+                {
+                    part = palette.recolor(&part, &layers.names[layer], hue, layers.palette_color);
+                }
+                Ok(BlinkEyes { layer, part })
+            })
+            .transpose()
+    }
+
+    fn picker(&self) -> Picker<'_> {
+        Picker::new(self.algorithm, &self.hash_array, &self.digest)
+    }
+
+    fn layout(&self) -> Layout {
+        let ImageSize { width, height } = self.image_size;
+        Layout::new(width, height, self.size_mode, self.scale)
     }
 
     fn is_missing_required_data(&self) -> bool {
@@ -101,37 +897,91 @@ impl RoboHash {
     }
 }
 
-fn select_robot_parts(hash_array: &[i64]) -> Vec<String> {
-    use robot_parts::{PARTS, PARTS_LENGTH};
-    let mut selected_strings = Vec::new();
+fn select_set(set: &RoboSet, picker: &Picker, sets: &[SetCatalog]) -> Result<RoboSet, Error> {
+    match set {
+        RoboSet::Any => {
+            if sets.is_empty() {
+                return Err(Error::RoboSetUnavailable(set.to_string()));
+            }
+            let index = 9;
+            let i = picker.pick(index, sets.len());
+            Ok(sets[i].set)
+        }
+        _ => Ok(catalog::find(sets, *set)?.set),
+    }
+}
+
+fn select_color(picker: &Picker, set_catalog: &SetCatalog) -> usize {
+    let index = 8;
+    picker.pick(index, set_catalog.colors.len())
+}
+
+fn select_robot_parts(picker: &Picker, set_catalog: &SetCatalog, color: usize) -> Vec<usize> {
+    let mut selected_indices = Vec::new();
+
+    for i in 0..set_catalog.layers.len() {
+        let length = set_catalog.layer_count(i, color);
+        selected_indices.push(picker.pick(layer_slot(i), length))
+    }
+
+    selected_indices
+}
 
-    for i in 0..PARTS.len() {
-        let index = (hash_array[i] % PARTS_LENGTH[i] as i64) as usize;
-        selected_strings.push(PARTS[i][index].to_string())
+fn layer_slot(layer: usize) -> usize {
+    match layer {
+        layer if layer < FIRST_RESERVED_SLOT => layer,
+        layer => layer + RESERVED_SLOTS,
     }
+}
 
-    selected_strings
+fn select_background(picker: &Picker, backgrounds: usize) -> Option<usize> {
+    if backgrounds == 0 {
+        return None;
+    }
+    let index = 6;
+    let i = picker.pick(index, backgrounds);
+    Some(i)
 }
 
-fn select_background(hash_array: &[i64]) -> Option<String> {
-    use backgrounds::BACKGROUNDS;
+/// Pastel color, light enough for any robot to stand out.
+fn select_background_color(picker: &Picker) -> [u8; 4] {
     let index = 6;
-    let i = (hash_array[index] % BACKGROUNDS.len() as i64) as usize;
-    Some(BACKGROUNDS[i].to_string())
+    let hue = picker.pick(index, 360) as f32;
+    let lch = recolor::Lch {
+        l: 0.88,
+        c: 0.06,
+        h: hue,
+    };
+    let [r, g, b] = recolor::from_lch(recolor::ColorSpace::Oklch, lch);
+    [r, g, b, 255]
+}
+
+/// Any other eyes of the same color, never the selected ones.
+fn select_blink_eyes(picker: &Picker, count: usize, selected: usize) -> Option<usize> {
+    if count < 2 {
+        return None;
+    }
+    let index = 10;
+    let shift = picker.pick(index, count - 1);
+    Some((selected + 1 + shift) % count)
+}
+
+fn select_palette_color(picker: &Picker, colors: usize) -> usize {
+    picker.pick(PALETTE_SLOT, colors)
 }
 
-fn select_hue_rotation(hash_array: &[i64]) -> Option<i32> {
+fn select_hue_rotation(picker: &Picker) -> Option<i32> {
     let index = 7;
-    let hue = (hash_array[index] % 360) as i32;
+    let hue = picker.pick(index, 360) as i32;
     Some(hue)
 }
 
 pub mod android {
-    use jni::objects::{JClass, JString};
-    use jni::sys::jstring;
+    use jni::objects::{JByteArray, JClass, JString};
+    use jni::sys::{jint, jstring};
     use jni::JNIEnv;
 
-    use crate::RoboHashBuilder;
+    use crate::{AlgorithmVersion, RoboHashBuilder};
 
     #[no_mangle]
     pub extern "system" fn Java_com_robosats_RoboIdentities_nativeGenerateRobohash<'local>(
@@ -149,9 +999,11 @@ pub mod android {
             Some((hash, size_str)) => {
                 match size_str.parse::<u32>() {
                     Ok(size) => {
+                        // Only the trait selection is pinned, the art and defaults may still change
                         let robohash = RoboHashBuilder::new(hash)
+                            .with_algorithm(AlgorithmVersion::V1)
                             .with_background(&true)
-                            .with_size(size as u32, size as u32)
+                            .with_size(size, size)
                             .build();
 
                         match robohash {
@@ -173,14 +1025,70 @@ pub mod android {
             None =>  todo!(),
         }
     }
+
+    /// Robot of a digest the caller already holds, null when it cannot be built.
+    #[no_mangle]
+    pub extern "system" fn Java_com_robosats_RoboIdentities_nativeGenerateRobohashFromDigest<
+        'local,
+    >(
+        env: JNIEnv<'local>,
+        _class: JClass<'local>,
+        digest: JByteArray<'local>,
+        size: jint,
+    ) -> jstring {
+        let digest = env
+            .convert_byte_array(&digest)
+            .expect("Couldn't get java byte array!");
+        let base64_string = RoboHashBuilder::from_digest(&digest)
+            .with_algorithm(AlgorithmVersion::V1)
+            .with_background(&true)
+            .with_size(size as u32, size as u32)
+            .build()
+            .and_then(|robo| robo.assemble_base64());
+        match base64_string {
+            Ok(base64_string) => env
+                .new_string(base64_string)
+                .expect("Couldn't create java string!")
+                .into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        }
+    }
+
+    /// Robot of a trait code (`RH1-...`), null when it cannot be built.
+    #[no_mangle]
+    pub extern "system" fn Java_com_robosats_RoboIdentities_nativeGenerateRobohashFromTraitCode<
+        'local,
+    >(
+        mut env: JNIEnv<'local>,
+        _class: JClass<'local>,
+        trait_code: JString<'local>,
+        size: jint,
+    ) -> jstring {
+        let trait_code: String = env
+            .get_string(&trait_code)
+            .expect("Couldn't get java string!")
+            .into();
+        let base64_string = RoboHashBuilder::from_trait_code(&trait_code)
+            .with_size(size as u32, size as u32)
+            .build()
+            .and_then(|robo| robo.assemble_base64());
+        match base64_string {
+            Ok(base64_string) => env
+                .new_string(base64_string)
+                .expect("Couldn't create java string!")
+                .into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs::File;
-    use std::io::Write;
 
-    use crate::image::tests::load_base64_string_image_resources;
+    use crate::effects::Outline;
+    use crate::mask::Shape;
+    use crate::pack::{Manifest, PackParts, MANIFEST, PACK_VERSION};
+    use crate::recolor::Palette;
 
     use super::*;
 
@@ -191,7 +1099,7 @@ mod tests {
         // act
         let robo_hash_builder = RoboHashBuilder::new(text);
         // assert
-        assert_eq!(robo_hash_builder.text, text)
+        assert!(matches!(robo_hash_builder.input, Input::Text(input) if input == text))
     }
 
     #[test]
@@ -239,8 +1147,23 @@ mod tests {
         };
         let robo_hash = RoboHash {
             image_size,
+            size_mode: SizeMode::Stretch,
+            scale: 1.0,
             hash_array: vec![],
-            use_background: false,
+            digest: vec![],
+            compatibility: Compatibility::Robosats,
+            algorithm: AlgorithmVersion::V1,
+            background: Background::Transparent,
+            use_hue_rotation: false,
+            hue: None,
+            color: None,
+            palette_color: None,
+            part_overrides: Vec::new(),
+            recoloring: Recoloring::HueRotation,
+            effects: Vec::new(),
+            mask: None,
+            set: RoboSet::Set1,
+            parts_provider: Arc::new(EmbeddedParts::new()),
         };
         // act
         let image = robo_hash.assemble_base64();
@@ -255,24 +1178,1140 @@ mod tests {
     #[test]
     fn test_that_robo_hash_image_is_generated() {
         // arrange
-        let initial_string = "test";
+        let corpus = golden::Corpus::load(golden::CORPUS_DIRECTORY).unwrap();
+        let case = corpus
+            .cases
+            .iter()
+            .find(|case| case.name == "test")
+            .unwrap();
+        let expected = corpus.golden(case).unwrap();
+
+        // act
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .build()
+            .unwrap();
+        let png = general_purpose::STANDARD
+            .decode(robo_hash.assemble_base64().unwrap())
+            .unwrap();
+        let image = ::image::load_from_memory(&png).unwrap().to_rgba8();
 
-        let test_resource = initial_string;
-        let expected_robo_hash = load_base64_string_image_resources(test_resource);
+        // assert
+        let comparison = golden::compare(&expected, &image, 0).unwrap();
+        assert_eq!(comparison.differing, 0);
+    }
 
+    #[test]
+    fn test_that_select_color_picks_one_of_the_embedded_colors() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test").build().unwrap();
+        let sets = catalog::embedded();
         // act
-        let robo_hash = RoboHashBuilder::new(initial_string).build().unwrap();
-        let constructed_robo_hash = robo_hash.assemble_base64().unwrap();
+        let color = select_color(&robo_hash.picker(), &sets[0]);
+        // assert
+        assert!(color < sets[0].colors.len());
+    }
 
-        // _write_to_test_resources(&test_resource, &constructed_robo_hash);
+    #[test]
+    fn test_that_select_robot_parts_stays_within_the_layer_counts() {
+        // arrange
+        let hash_array = vec![11; 22];
+        let picker = Picker::new(AlgorithmVersion::V1, &hash_array, &[]);
+        let sets = catalog::embedded();
+        // act
+        let parts = (0..sets[0].colors.len())
+            .map(|color| select_robot_parts(&picker, &sets[0], color))
+            .collect::<Vec<Vec<usize>>>();
+        // assert
+        assert!(parts.iter().enumerate().all(|(color, indices)| indices
+            .iter()
+            .enumerate()
+            .all(|(layer, index)| *index < sets[0].layer_count(layer, color))));
+    }
 
-        assert_eq!(constructed_robo_hash, expected_robo_hash);
+    #[test]
+    fn test_that_layers_after_the_sixth_skip_the_reserved_slots() {
+        // arrange
+        let hash_array = (0..22).collect::<Vec<i64>>();
+        let picker = Picker::new(AlgorithmVersion::V1, &hash_array, &[]);
+        let set_catalog = SetCatalog {
+            set: RoboSet::Set4,
+            colors: vec![String::from("default")],
+            layers: (0..MAX_LAYERS)
+                .map(|layer| catalog::LayerCatalog {
+                    name: format!("Layer{}", layer),
+                    counts: vec![100],
+                    sort_order: None,
+                })
+                .collect(),
+        };
+        // act
+        let parts = select_robot_parts(&picker, &set_catalog, 0);
+        // assert
+        assert_eq!(
+            parts,
+            [0, 1, 2, 3, 4, 5, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+        );
     }
 
-    fn _write_to_test_resources(location: &str, content: &str) -> std::io::Result<()> {
-        let file_location = format!("./test_resources/{}.txt", location);
-        let mut file = File::create(file_location)?;
-        file.write_all(content.as_bytes())?;
-        Ok(())
+    #[derive(Debug)]
+    struct CatalogOnly(Vec<SetCatalog>);
+
+    impl PartsProvider for CatalogOnly {
+        fn sets(&self) -> &[SetCatalog] {
+            &self.0
+        }
+
+        fn backgrounds(&self) -> usize {
+            0
+        }
+
+        fn part(
+            &self,
+            _: RoboSet,
+            _: usize,
+            _: usize,
+            _: usize,
+        ) -> Result<::image::DynamicImage, Error> {
+            Err(Error::Unknown)
+        }
+
+        fn background(&self, _: usize) -> Result<::image::DynamicImage, Error> {
+            Err(Error::Unknown)
+        }
+    }
+
+    #[test]
+    fn test_that_catalogs_without_parts_to_pick_return_an_error() {
+        // arrange
+        let set_catalog = |colors: Vec<&str>, counts: Vec<usize>| SetCatalog {
+            set: RoboSet::Set4,
+            colors: colors.into_iter().map(String::from).collect(),
+            layers: vec![catalog::LayerCatalog {
+                name: String::from("Body"),
+                counts,
+                sort_order: None,
+            }],
+        };
+        let catalogs = [
+            (set_catalog(vec![], vec![]), "set4 has no colors"),
+            (
+                set_catalog(vec!["red", "blue"], vec![2, 0]),
+                "set4/Body has no parts in blue",
+            ),
+            (
+                set_catalog(vec!["red", "blue"], vec![2]),
+                "set4/Body has no parts in blue",
+            ),
+        ];
+        for (set_catalog, reason) in catalogs {
+            let provider: Arc<dyn PartsProvider> = Arc::new(CatalogOnly(vec![set_catalog]));
+            for (algorithm, compatibility) in [
+                (AlgorithmVersion::V1, Compatibility::Robosats),
+                (AlgorithmVersion::V2, Compatibility::Robosats),
+                (AlgorithmVersion::V2, Compatibility::Upstream),
+            ] {
+                // act
+                let robo_hash = RoboHashBuilder::new("test")
+                    .with_parts_provider(provider.clone())
+                    .with_set(RoboSet::Set4)
+                    .with_algorithm(algorithm)
+                    .with_compatibility(compatibility)
+                    .build();
+                // assert
+                assert_eq!(
+                    robo_hash.err().unwrap().to_string(),
+                    Error::InvalidCatalog(String::from(reason)).to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_that_hue_rotation_is_optional() {
+        // arrange
+        let initial_string = "test";
+        // act
+        let robo_hash = RoboHashBuilder::new(initial_string).build().unwrap();
+        let rotated_robo_hash = RoboHashBuilder::new(initial_string)
+            .with_hue_rotation(&true)
+            .build()
+            .unwrap();
+        // assert
+        assert!(!robo_hash.use_hue_rotation);
+        assert_ne!(
+            robo_hash.assemble_base64().unwrap(),
+            rotated_robo_hash.assemble_base64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_that_any_set_is_picked_from_the_available_sets() {
+        // act
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_set(RoboSet::Any)
+            .build()
+            .unwrap();
+        // assert
+        assert_eq!(robo_hash.set, RoboSet::Set1);
+    }
+
+    #[test]
+    fn test_that_building_a_set_without_parts_returns_an_error() {
+        // act
+        let robo_hash = RoboHashBuilder::new("test").with_set(RoboSet::Set3).build();
+        // assert
+        assert_eq!(
+            robo_hash.err().unwrap().to_string(),
+            Error::RoboSetUnavailable(String::from("set3")).to_string()
+        );
+    }
+
+    #[test]
+    fn test_that_robo_hash_can_be_drawn_from_an_asset_pack() {
+        // arrange
+        let root = crate::pack::tests::write_test_pack("builder");
+        let pack = crate::pack::PackParts::from_dir(&root).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        // act
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_parts_provider(Arc::new(pack))
+            .with_set(RoboSet::Any)
+            .build()
+            .unwrap();
+        let image = robo_hash.assemble_base64();
+        // assert
+        assert_eq!(robo_hash.set, RoboSet::Set4);
+        assert!(image.is_ok());
+    }
+
+    #[test]
+    fn test_that_assemble_returns_the_png_behind_assemble_base64() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test").build().unwrap();
+        // act
+        let png = robo_hash.assemble(OutputFormat::Png).unwrap();
+        // assert
+        assert_eq!(
+            general_purpose::STANDARD.encode(png),
+            robo_hash.assemble_base64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_that_write_to_encodes_the_requested_format() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(64, 64)
+            .build()
+            .unwrap();
+        let mut bytes = Vec::new();
+        // act
+        robo_hash
+            .write_to(&mut bytes, OutputFormat::WebpLossless)
+            .unwrap();
+        // assert
+        let image = ::image::load_from_memory_with_format(&bytes, ::image::ImageFormat::WebP);
+        assert_eq!(image.unwrap().width(), 64);
+    }
+
+    #[test]
+    fn test_that_assemble_data_uri_is_prefixed_with_the_mime_type() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(32, 32)
+            .build()
+            .unwrap();
+        let format = OutputFormat::Jpeg {
+            quality: 80,
+            background: [255, 255, 255],
+        };
+        // act
+        let data_uri = robo_hash.assemble_data_uri(format).unwrap();
+        // assert
+        assert!(data_uri.starts_with("data:image/jpeg;base64,/9j/"));
+    }
+
+    #[test]
+    fn test_that_render_returns_the_image_behind_assemble() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test").build().unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        // assert
+        let png = robo_hash.assemble(OutputFormat::Png).unwrap();
+        assert_eq!(image, ::image::load_from_memory(&png).unwrap().to_rgba8());
+    }
+
+    #[test]
+    fn test_that_render_onto_draws_the_robot_at_the_offset() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(16, 16)
+            .build()
+            .unwrap();
+        let background = ::image::Rgba([1, 2, 3, 255]);
+        let mut canvas = RgbaImage::from_pixel(40, 20, background);
+        // act
+        robo_hash.render_onto(&mut canvas, 30, 10).unwrap();
+        // assert
+        let robot = robo_hash.render().unwrap();
+        assert_eq!(canvas.get_pixel(29, 10), &background);
+        assert_eq!(canvas.get_pixel(30, 10), robot.get_pixel(0, 0));
+        assert_eq!(canvas.get_pixel(39, 19), robot.get_pixel(9, 9));
+    }
+
+    #[test]
+    fn test_that_assemble_svg_has_an_image_for_the_background_and_each_layer() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(32, 32)
+            .with_hue_rotation(&true)
+            .build()
+            .unwrap();
+        // act
+        let svg = robo_hash.assemble_svg().unwrap();
+        // assert
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<image ").count(), 6);
+        assert!(svg.contains(r#"<image class="background""#));
+        assert!(svg.contains(r#"<image class="accessory""#));
+        assert_eq!(svg.matches("<feColorMatrix").count(), 1);
+    }
+
+    #[test]
+    fn test_that_the_first_animation_frame_is_the_still_robot() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(32, 32)
+            .with_hue_rotation(&true)
+            .build()
+            .unwrap();
+        let animation = Animation {
+            motions: vec![
+                Motion::HueCycle,
+                Motion::Blink,
+                Motion::Bob { amplitude: 2 },
+            ],
+            frames: 4,
+            frame_duration: 100,
+        };
+        // act
+        let frames = robo_hash.render_frames(&animation).unwrap();
+        // assert
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], robo_hash.render().unwrap());
+    }
+
+    #[test]
+    fn test_that_select_blink_eyes_never_picks_the_selected_eyes() {
+        // arrange
+        let hash_array = vec![13; 22];
+        let picker = Picker::new(AlgorithmVersion::V1, &hash_array, &[]);
+        // act
+        let blink_eyes = (0..12)
+            .map(|selected| select_blink_eyes(&picker, 12, selected))
+            .collect::<Vec<Option<usize>>>();
+        // assert
+        assert!(blink_eyes
+            .iter()
+            .enumerate()
+            .all(|(selected, eyes)| eyes.is_some_and(|eyes| eyes != selected && eyes < 12)));
+        assert_eq!(select_blink_eyes(&picker, 1, 0), None);
+    }
+
+    #[test]
+    fn test_that_assemble_animation_returns_an_apng() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(16, 16)
+            .build()
+            .unwrap();
+        // act
+        let apng = robo_hash
+            .assemble_animation(&Animation::default(), AnimationFormat::Apng)
+            .unwrap();
+        // assert
+        assert_eq!(&apng[..8], b"\x89PNG\r\n\x1a\n");
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+    }
+
+    #[test]
+    fn test_that_contain_letterboxes_non_square_sizes() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(90, 30)
+            .with_size_mode(SizeMode::Contain)
+            .build()
+            .unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        // assert
+        assert_eq!(image.dimensions(), (90, 30));
+        assert_eq!(image.get_pixel(0, 15)[3], 0);
+        assert_eq!(image.get_pixel(89, 15)[3], 0);
+        assert_eq!(image.get_pixel(45, 15)[3], 255);
+    }
+
+    #[test]
+    fn test_that_cover_crops_the_square_art() {
+        // arrange
+        let square = RoboHashBuilder::new("test")
+            .with_size(40, 40)
+            .build()
+            .unwrap();
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(40, 20)
+            .with_size_mode(SizeMode::Cover)
+            .build()
+            .unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        // assert
+        let square = square.render().unwrap();
+        assert_eq!(image.get_pixel(7, 0), square.get_pixel(7, 10));
+        assert_eq!(image.get_pixel(33, 19), square.get_pixel(33, 29));
+    }
+
+    #[test]
+    fn test_that_scale_leaves_the_background_around_the_robot() {
+        // arrange
+        let background = RoboHashBuilder::new("test")
+            .with_size(40, 40)
+            .with_scale(0.5)
+            .build()
+            .unwrap();
+        let transparent = RoboHashBuilder::new("test")
+            .with_size(40, 40)
+            .with_scale(0.5)
+            .with_background(&false)
+            .build()
+            .unwrap();
+        // act
+        let background = background.render().unwrap();
+        let transparent = transparent.render().unwrap();
+        // assert
+        assert_eq!(background.get_pixel(2, 2)[3], 255);
+        assert!(transparent.enumerate_pixels().all(|(x, y, pixel)| {
+            (10..30).contains(&x) && (10..30).contains(&y) || pixel[3] == 0
+        }));
+    }
+
+    #[test]
+    fn test_that_building_with_an_invalid_scale_returns_an_error() {
+        // act
+        let robo_hash = RoboHashBuilder::new("test").with_scale(0.0).build();
+        // assert
+        assert_eq!(
+            robo_hash.err().unwrap().to_string(),
+            Error::InvalidScale(String::from("0")).to_string()
+        );
+    }
+
+    #[test]
+    fn test_that_a_sha512_digest_gives_the_robot_of_its_text() {
+        // arrange
+        let digest = hash::digest(b"test", Digest::Sha512).unwrap();
+        // act
+        let from_digest = RoboHashBuilder::from_digest(&digest).build().unwrap();
+        let from_hex = RoboHashBuilder::from_hex(&HEXLOWER.encode(&digest))
+            .build()
+            .unwrap();
+        let from_bytes = RoboHashBuilder::from_bytes(b"test").build().unwrap();
+        // assert
+        let expected = RoboHashBuilder::new("test").build().unwrap().hash_array;
+        assert_eq!(from_digest.hash_array, expected);
+        assert_eq!(from_hex.hash_array, expected);
+        assert_eq!(from_bytes.hash_array, expected);
+    }
+
+    #[test]
+    fn test_that_a_sha256_digest_gives_the_robot_of_its_text_hashed_with_sha256() {
+        // arrange
+        let hex = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        // act
+        let from_hex = RoboHashBuilder::from_hex(hex).build().unwrap();
+        // assert
+        let expected = RoboHashBuilder::new("test")
+            .with_digest(Digest::Sha256)
+            .build()
+            .unwrap();
+        assert_eq!(from_hex.hash_array, expected.hash_array);
+        assert_ne!(
+            from_hex.hash_array,
+            RoboHashBuilder::new("test").build().unwrap().hash_array
+        );
+    }
+
+    #[test]
+    fn test_that_building_from_an_invalid_digest_returns_an_error() {
+        // act
+        let short = RoboHashBuilder::from_digest(&[1, 2, 3]).build();
+        let not_hex = RoboHashBuilder::from_hex("robosats").build();
+        // assert
+        assert_eq!(
+            short.err().unwrap().to_string(),
+            "invalid digest: expected 17 to 64 bytes, got 3"
+        );
+        assert!(matches!(not_hex, Err(Error::InvalidDigest(_))));
+    }
+
+    #[test]
+    fn test_that_traits_describe_the_rendered_layers() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .with_hue_rotation(&true)
+            .build()
+            .unwrap();
+        // act
+        let traits = robo_hash.traits().unwrap();
+        // assert
+        let layers = robo_hash.load_layers().unwrap();
+        assert_eq!(traits.compatibility, Compatibility::Robosats);
+        assert_eq!(traits.set, RoboSet::Set1);
+        assert_eq!(traits.color.index, layers.color);
+        assert_eq!(traits.color.name, "red");
+        for (layer, part) in traits.parts.iter().enumerate() {
+            assert_eq!(part.index, layers.indices[layer]);
+            assert_eq!(part.layer, layers.names[layer]);
+        }
+        assert_eq!(traits.hue, layers.hue_rotation);
+        assert!(traits.hue.is_some() && traits.background.is_some());
+    }
+
+    #[test]
+    fn test_that_describe_names_the_color_the_parts_and_the_background() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .build()
+            .unwrap();
+        // act
+        let english = robo_hash.describe(Locale::En).unwrap();
+        let spanish = robo_hash.describe(Locale::Es).unwrap();
+        // assert
+        assert_eq!(
+            english,
+            "a red robot with a funnel-shaped body, a tall dome head, a striped panel mouth, \
+             small dot eyes and a small hat, on a beach"
+        );
+        assert_eq!(
+            spanish,
+            "un robot rojo con un cuerpo en forma de embudo, una cabeza alta en cúpula, \
+             una boca de panel a rayas, ojos pequeños como puntos y un sombrerito, sobre una playa"
+        );
+    }
+
+    #[test]
+    fn test_that_describe_leaves_out_the_color_of_hue_rotated_robots() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .with_background(&false)
+            .with_hue_rotation(&true)
+            .build()
+            .unwrap();
+        // act
+        let description = robo_hash.describe(Locale::En).unwrap();
+        // assert
+        assert_eq!(
+            description,
+            "a robot with a funnel-shaped body, a tall dome head, a striped panel mouth, \
+             small dot eyes and a small hat"
+        );
+    }
+
+    #[test]
+    fn test_that_with_part_overrides_only_the_given_layer() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test").build().unwrap();
+        let traits = robo_hash.traits().unwrap();
+        let eyes = (traits.part("Eyes").unwrap() + 1) % 10;
+        // act
+        let tweaked = RoboHashBuilder::new("test")
+            .with_part(Layer::Eyes, eyes)
+            .build()
+            .unwrap()
+            .traits()
+            .unwrap();
+        // assert
+        for (part, tweaked_part) in traits.parts.iter().zip(&tweaked.parts) {
+            match part.layer.as_str() {
+                "Eyes" => assert_eq!(tweaked_part.index, eyes),
+                _ => assert_eq!(tweaked_part, part),
+            }
+        }
+        assert_eq!(tweaked.color, traits.color);
+        assert_eq!(tweaked.background, traits.background);
+    }
+
+    #[test]
+    fn test_that_with_part_fails_out_of_the_choices() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .build()
+            .unwrap();
+        let choices = robo_hash.choices().unwrap();
+        let eyes = choices.layer(&Layer::Eyes).unwrap().parts;
+        // act
+        let out_of_range = RoboHashBuilder::new("test")
+            .with_algorithm(AlgorithmVersion::V1)
+            .with_part(Layer::Eyes, eyes)
+            .build();
+        let unknown_layer = RoboHashBuilder::new("test")
+            .with_part(Layer::Named(String::from("Hat")), 0)
+            .build();
+        // assert
+        assert_eq!(
+            out_of_range.unwrap_err().to_string(),
+            format!(
+                "invalid override: red Eyes has {} parts, got part {}",
+                eyes, eyes
+            )
+        );
+        assert_eq!(
+            unknown_layer.unwrap_err().to_string(),
+            "invalid override: set1 has no Hat layer"
+        );
+    }
+
+    #[test]
+    fn test_that_with_background_and_with_hue_override_the_hash() {
+        // act
+        let traits = RoboHashBuilder::new("test")
+            .with_background(Background::Embedded(EmbeddedBackground::Id(3)))
+            .with_hue(-90)
+            .build()
+            .unwrap()
+            .traits()
+            .unwrap();
+        let out_of_range = RoboHashBuilder::new("test")
+            .with_background(Background::Embedded(EmbeddedBackground::Id(21)))
+            .build();
+        // assert
+        assert_eq!(traits.background, Some(3));
+        assert_eq!(traits.hue, Some(270));
+        assert_eq!(
+            out_of_range.unwrap_err().to_string(),
+            "invalid override: 21 backgrounds, got background 21"
+        );
+    }
+
+    #[test]
+    fn test_that_choices_list_the_parts_in_the_robot_color() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test").build().unwrap();
+        let sets = catalog::embedded();
+        let set_catalog = &sets[0];
+        // act
+        let choices = robo_hash.choices().unwrap();
+        // assert
+        let color = robo_hash.traits().unwrap().color.index;
+        let expected = set_catalog
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(layer, layer_catalog)| LayerChoices {
+                layer: layer_catalog.name.clone(),
+                parts: set_catalog.layer_count(layer, color),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(choices.layers, expected);
+        assert_eq!(choices.backgrounds, 21);
+    }
+
+    #[test]
+    fn test_that_from_trait_code_renders_the_same_robot() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_hue_rotation(&true)
+            .with_part(Layer::Mouth, 0)
+            .build()
+            .unwrap();
+        let code = robo_hash.trait_code().unwrap();
+        // act
+        let decoded = RoboHashBuilder::from_trait_code(&code).build().unwrap();
+        // assert
+        assert_eq!(decoded.traits().unwrap(), robo_hash.traits().unwrap());
+        assert_eq!(decoded.trait_code().unwrap(), code);
+        assert_eq!(
+            decoded.assemble_base64().unwrap(),
+            robo_hash.assemble_base64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_that_from_trait_code_applies_the_overrides() {
+        // act
+        let traits = RoboHashBuilder::from_trait_code("RH1-set1-c7-b3f7m2e9a4-bg12-h250")
+            .with_part(Layer::Eyes, 1)
+            .with_background(&false)
+            .build()
+            .unwrap()
+            .traits()
+            .unwrap();
+        // assert
+        assert_eq!(traits.color.index, 7);
+        assert_eq!(traits.part("Body"), Some(3));
+        assert_eq!(traits.part("Eyes"), Some(1));
+        assert_eq!(traits.background, None);
+        assert_eq!(traits.hue, Some(250));
+    }
+
+    #[test]
+    fn test_that_from_trait_code_validates_the_code_against_the_set() {
+        let error = |code: &str| {
+            RoboHashBuilder::from_trait_code(code)
+                .build()
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("RH1-set1-c7-b3f7m2e9"),
+            "invalid trait code: expected the layers \"bfmea\" of set1, got \"bfme\""
+        );
+        assert_eq!(
+            error("RH1-set1-c10-b3f7m2e9a4"),
+            "invalid override: set1 has 10 colors, got color 10"
+        );
+        assert_eq!(
+            error("RH1-set1-c7-b30f7m2e9a4"),
+            "invalid override: red Body has 11 parts, got part 30"
+        );
+    }
+
+    #[test]
+    fn test_that_palette_colors_are_picked_from_their_slot_and_kept_in_the_trait_code() {
+        // arrange
+        let mut palette = Palette::hsl();
+        palette.colors = vec![[247, 147, 26], [0, 128, 255], [0, 160, 80]];
+        let recoloring = Recoloring::Palette(palette);
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_hue_rotation(&true)
+            .with_recoloring(recoloring.clone())
+            .build()
+            .unwrap();
+        // act
+        let traits = robo_hash.traits().unwrap();
+        let code = robo_hash.trait_code().unwrap();
+        // assert
+        let expected = select_palette_color(&robo_hash.picker(), 3);
+        assert_eq!(traits.palette_color, Some(expected));
+        assert!(code.ends_with(&format!("-p{}", expected)));
+        let decoded = RoboHashBuilder::from_trait_code(&code)
+            .with_recoloring(recoloring)
+            .build()
+            .unwrap();
+        assert_eq!(decoded.traits().unwrap(), traits);
+    }
+
+    #[test]
+    fn test_that_palette_recoloring_keeps_the_traits_and_the_eyes() {
+        // arrange
+        let builder = || RoboHashBuilder::new("test").with_hue_rotation(&true);
+        let rotated = builder().build().unwrap();
+        let palette = builder()
+            .with_recoloring(Recoloring::Palette(Palette::default()))
+            .build()
+            .unwrap();
+        // act
+        let layers = palette.load_layers().unwrap();
+        // assert
+        let unrecolored = rotated.load_layers().unwrap();
+        assert_eq!(palette.traits().unwrap(), rotated.traits().unwrap());
+        assert_eq!(layers.hue_rotation, None);
+        assert_eq!(layers.palette_hue, rotated.traits().unwrap().hue);
+        for (layer, name) in layers.names.iter().enumerate() {
+            let same = layers.parts[layer] == unrecolored.parts[layer];
+            assert_eq!(same, name == "Eyes", "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_that_solid_backgrounds_fill_the_background_placement() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(300, 100)
+            .with_size_mode(SizeMode::Contain)
+            .with_background(Background::Solid([10, 20, 30, 255]))
+            .build()
+            .unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        // assert
+        assert_eq!(image.get_pixel(101, 1), &Rgba([10, 20, 30, 255]));
+        assert_eq!(image.get_pixel(99, 1)[3], 0);
+        let traits = robo_hash.traits().unwrap();
+        assert_eq!(traits.background, None);
+        assert_eq!(traits.background_color, Some([10, 20, 30, 255]));
+    }
+
+    #[test]
+    fn test_that_masks_cut_every_output_to_the_shape() {
+        // arrange
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(64, 64)
+            .with_mask(Mask::new(Shape::Circle).with_border([255, 0, 0, 255], 3.0))
+            .build()
+            .unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        let frames = robo_hash.render_frames(&Animation::default()).unwrap();
+        let svg = robo_hash.assemble_svg().unwrap();
+        // assert
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(image.get_pixel(1, 32), &Rgba([255, 0, 0, 255]));
+        assert!(frames.iter().all(|frame| frame.get_pixel(63, 63)[3] == 0));
+        assert!(svg.contains(r#"clip-path="url(#robohash-mask)""#));
+    }
+
+    #[test]
+    fn test_that_effects_run_on_the_robot_before_the_background() {
+        // arrange
+        let outlined = |background: bool| {
+            RoboHashBuilder::new("test")
+                .with_size(64, 64)
+                .with_background(&background)
+                .with_effect(Outline {
+                    color: [255, 0, 255, 255],
+                    width: 2.0,
+                })
+                .build()
+                .unwrap()
+        };
+        let plain = RoboHashBuilder::new("test")
+            .with_size(64, 64)
+            .with_background(&false)
+            .build()
+            .unwrap();
+        // act
+        let image = outlined(false).render().unwrap();
+        let frames = outlined(false)
+            .render_frames(&Animation::default())
+            .unwrap();
+        let with_background = outlined(true).render().unwrap();
+        // assert
+        let plain = plain.render().unwrap();
+        let magenta = Rgba([255, 0, 255, 255]);
+        assert!(image.pixels().filter(|pixel| **pixel == magenta).count() > 50);
+        assert!(!plain.pixels().any(|pixel| *pixel == magenta));
+        assert_eq!(frames[0], image);
+        assert!(with_background.pixels().any(|pixel| *pixel == magenta));
+    }
+
+    #[test]
+    fn test_that_look_alikes_are_closer_than_other_robots() {
+        // arrange
+        let robot = |text: &str| {
+            RoboHashBuilder::new(text)
+                .with_size(64, 64)
+                .build()
+                .unwrap()
+        };
+        let original = robot("test");
+        let look_alike = RoboHashBuilder::from_trait_code(&original.trait_code().unwrap())
+            .with_size(64, 64)
+            .with_part(Layer::Accessory, 0)
+            .build()
+            .unwrap();
+        let other = robot("other");
+        // act
+        let close = original.distance(&look_alike).unwrap();
+        let far = original.distance(&other).unwrap();
+        let pixels_close = original
+            .perceptual_hash()
+            .unwrap()
+            .distance(&look_alike.perceptual_hash().unwrap());
+        let pixels_far = original
+            .perceptual_hash()
+            .unwrap()
+            .distance(&other.perceptual_hash().unwrap());
+        // assert
+        assert!((close - 0.08).abs() < 1e-6 && close < far);
+        assert!(pixels_close < pixels_far);
+        assert_eq!(original.distance(&robot("test")).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_that_algorithm_versions_pick_other_robots_from_the_same_input() {
+        // arrange
+        let traits = |text: &str, algorithm: AlgorithmVersion| {
+            RoboHashBuilder::new(text)
+                .with_hue_rotation(&true)
+                .with_algorithm(algorithm)
+                .build()
+                .unwrap()
+                .traits()
+                .unwrap()
+        };
+        // act
+        let v1 = traits("test", AlgorithmVersion::V1);
+        let v2 = traits("test", AlgorithmVersion::V2);
+        // assert
+        assert_eq!(v2, traits("test", AlgorithmVersion::default()));
+        assert_eq!(v2, traits("test", AlgorithmVersion::V2));
+        assert_eq!(v2.version, AlgorithmVersion::V2);
+        assert_ne!((&v1.parts, v1.hue), (&v2.parts, v2.hue));
+        let upstream = |algorithm| {
+            RoboHashBuilder::new("test")
+                .with_compatibility(Compatibility::Upstream)
+                .with_algorithm(algorithm)
+                .build()
+                .unwrap()
+                .traits()
+                .unwrap()
+                .parts
+        };
+        assert_eq!(
+            upstream(AlgorithmVersion::V1),
+            upstream(AlgorithmVersion::V2)
+        );
+    }
+
+    /// Robot of a golden vector: an input with the options RoboSats uses.
+    fn golden_trait_code(text: &str, algorithm: AlgorithmVersion) -> String {
+        RoboHashBuilder::new(text)
+            .with_hue_rotation(&true)
+            .with_algorithm(algorithm)
+            .build()
+            .unwrap()
+            .trait_code()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_that_every_algorithm_version_keeps_its_golden_vectors() {
+        for algorithm in AlgorithmVersion::ALL {
+            // arrange
+            let location = format!("./test_resources/golden/{}.txt", algorithm);
+            let vectors = std::fs::read_to_string(&location)
+                .unwrap_or_else(|_| panic!("{} is missing, see write_golden_vectors", location));
+            for line in vectors.lines() {
+                let (text, expected) = line.split_once('\t').unwrap();
+                // act
+                let trait_code = golden_trait_code(text, algorithm);
+                // assert
+                assert_eq!(trait_code, expected, "{} {}", algorithm, text);
+            }
+        }
+    }
+
+    /// Writes the golden vectors of new algorithm versions, never the ones already
+    /// frozen: `cargo test -- --ignored write_golden_vectors`.
+    #[test]
+    #[ignore]
+    fn write_golden_vectors() {
+        let texts = ["test", "reckless", "Bear.png", "ñandú 🤖"]
+            .iter()
+            .map(|text| text.to_string())
+            .chain((0..96).map(|i| format!("robot {}", i)))
+            .collect::<Vec<String>>();
+        for algorithm in AlgorithmVersion::ALL {
+            let location = format!("./test_resources/golden/{}.txt", algorithm);
+            if std::path::Path::new(&location).exists() {
+                continue;
+            }
+            let vectors = texts
+                .iter()
+                .map(|text| format!("{}\t{}\n", text, golden_trait_code(text, algorithm)))
+                .collect::<String>();
+            std::fs::write(location, vectors).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_that_derived_backgrounds_are_picked_from_the_hash() {
+        // act
+        let color = |text: &str| {
+            let robo_hash = RoboHashBuilder::new(text)
+                .with_background(Background::Derived)
+                .build()
+                .unwrap();
+            robo_hash.traits().unwrap().background_color.unwrap()
+        };
+        // assert
+        assert_eq!(color("test"), color("test"));
+        assert_ne!(color("test"), color("other"));
+        assert_eq!(color("test")[3], 255);
+    }
+
+    #[test]
+    fn test_that_custom_backgrounds_are_fitted_like_the_art() {
+        // arrange
+        let custom = Arc::new(RgbaImage::from_pixel(40, 20, Rgba([200, 0, 0, 255])));
+        let robo_hash = RoboHashBuilder::new("test")
+            .with_size(100, 100)
+            .with_size_mode(SizeMode::Contain)
+            .with_background(Background::Custom(custom))
+            .build()
+            .unwrap();
+        // act
+        let image = robo_hash.render().unwrap();
+        // assert
+        assert_eq!(image.get_pixel(0, 26), &Rgba([200, 0, 0, 255]));
+        assert_eq!(image.get_pixel(0, 24)[3], 0);
+        assert_eq!(robo_hash.traits().unwrap().background, None);
+    }
+
+    #[test]
+    fn test_that_embedded_subsets_pick_among_the_given_backgrounds() {
+        // act
+        let backgrounds = ["alice", "bob", "carol", "dave"]
+            .iter()
+            .map(|text| {
+                let robo_hash = RoboHashBuilder::new(text)
+                    .with_background(Background::Embedded(EmbeddedBackground::Subset(vec![2, 5])))
+                    .build()
+                    .unwrap();
+                robo_hash.traits().unwrap().background.unwrap()
+            })
+            .collect::<Vec<_>>();
+        let empty = RoboHashBuilder::new("test")
+            .with_background(Background::Embedded(EmbeddedBackground::Subset(vec![])))
+            .build()
+            .unwrap();
+        // assert
+        assert!(backgrounds
+            .iter()
+            .all(|background| [2, 5].contains(background)));
+        assert_eq!(empty.traits().unwrap().background, None);
+    }
+
+    #[derive(serde::Deserialize)]
+    struct UpstreamRobot {
+        text: String,
+        set: RoboSet,
+        color: String,
+        parts: Vec<String>,
+        background: String,
+    }
+
+    /// Art under `directory` in the order upstream lists it, `_` files included.
+    fn listed_names(directory: &str) -> Vec<String> {
+        let mut names = std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| !name.starts_with('.'))
+            .filter(|name| !name.ends_with(".json"))
+            .collect::<Vec<String>>();
+        names.sort();
+        names
+    }
+
+    /// The art of the crate as upstream sees it, as an asset pack: the embedded art
+    /// leaves the `_` backgrounds out.
+    fn upstream_pack() -> PackParts {
+        let set_catalog = catalog::find(&catalog::embedded(), RoboSet::Set1)
+            .unwrap()
+            .clone();
+        let mut files = std::collections::BTreeMap::new();
+        for color in &set_catalog.colors {
+            let directories = listed_names(&format!("./sets/set1/{}", color));
+            for layer in &set_catalog.layers {
+                let directory = directories
+                    .iter()
+                    .find(|directory| directory.ends_with(&layer.name))
+                    .unwrap();
+                let directory = format!("./sets/set1/{}/{}", color, directory);
+                for (i, name) in listed_names(&directory).iter().enumerate() {
+                    files.insert(
+                        format!("sets/set1/{}/{}/{:03}.png", color, layer.name, i),
+                        std::fs::read(format!("{}/{}", directory, name)).unwrap(),
+                    );
+                }
+            }
+        }
+        let mut background_sets = Vec::new();
+        let mut backgrounds = 0;
+        for set in listed_names("./backgrounds") {
+            let names = listed_names(&format!("./backgrounds/{}", set));
+            for name in &names {
+                backgrounds += 1;
+                files.insert(
+                    format!("backgrounds/{:03}.png", backgrounds),
+                    std::fs::read(format!("./backgrounds/{}/{}", set, name)).unwrap(),
+                );
+            }
+            background_sets.push(names.len());
+        }
+        let manifest = Manifest {
+            version: PACK_VERSION,
+            backgrounds,
+            background_sets,
+            sets: vec![set_catalog],
+            descriptions: Vec::new(),
+            background_descriptions: Vec::new(),
+        };
+        files.insert(
+            String::from(MANIFEST),
+            serde_json::to_vec(&manifest).unwrap(),
+        );
+        PackParts::from_files(files).unwrap()
+    }
+
+    #[test]
+    fn test_that_upstream_compatibility_picks_the_parts_of_the_upstream_corpus() {
+        // arrange
+        let corpus = std::fs::read_to_string("./test_resources/upstream/corpus.json").unwrap();
+        let corpus: Vec<UpstreamRobot> = serde_json::from_str(&corpus).unwrap();
+        let pack: Arc<dyn PartsProvider> = Arc::new(upstream_pack());
+        let backgrounds = listed_names("./backgrounds")
+            .iter()
+            .flat_map(|set| {
+                listed_names(&format!("./backgrounds/{}", set))
+                    .into_iter()
+                    .map(move |name| format!("{}/{}", set, name))
+            })
+            .collect::<Vec<String>>();
+        for robot in corpus {
+            // act
+            let robo_hash = RoboHashBuilder::new(&robot.text)
+                .with_compatibility(Compatibility::Upstream)
+                .with_parts_provider(pack.clone())
+                .build()
+                .unwrap();
+            let set_catalog =
+                catalog::find(robo_hash.parts_provider.sets(), robo_hash.set).unwrap();
+            let selection = robo_hash.select(set_catalog).unwrap();
+            // assert
+            let color = set_catalog.colors.iter().position(|c| *c == robot.color);
+            let parts = robot
+                .parts
+                .iter()
+                .map(|part| {
+                    let (layer, name) = part.split_once('/').unwrap();
+                    let directory = format!("./sets/{}/{}/{}", robot.set, robot.color, layer);
+                    listed_names(&directory)
+                        .iter()
+                        .position(|n| n == name)
+                        .unwrap()
+                })
+                .collect();
+            let background = backgrounds.iter().position(|b| *b == robot.background);
+            let expected = Selection {
+                color: color.unwrap(),
+                parts,
+                background,
+                background_color: None,
+                hue_rotation: None,
+                palette_color: None,
+            };
+            assert_eq!(robo_hash.set, robot.set, "{}", robot.text);
+            assert_eq!(selection, expected, "{}", robot.text);
+        }
+    }
+
+    #[test]
+    fn test_that_upstream_compatibility_ignores_image_extensions() {
+        // act
+        let robo_hash = RoboHashBuilder::new("Bear.png")
+            .with_compatibility(Compatibility::Upstream)
+            .build()
+            .unwrap();
+        // assert
+        let expected = RoboHashBuilder::new("Bear").build().unwrap();
+        assert_eq!(robo_hash.hash_array, expected.hash_array);
     }
 }
@@ -1,9 +1,17 @@
+use robohash::cache::CachedParts;
+use robohash::provider::EmbeddedParts;
 use robohash::*;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
+use std::sync::Arc;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    if args.first().map(String::as_str) == Some("audit") {
+        return audit(&args[1..]);
+    }
+
     let initial_string = "reckless";
     let size = 256;
 
@@ -22,3 +30,38 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// `robohash-cli audit <inputs file> [threshold]` prints the inputs, one per line
+/// of the file, whose robots look alike, with their trait and pixel distances. The
+/// threshold defaults to 0.08, robots with a single other part.
+fn audit(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let path = args
+        .first()
+        .ok_or("usage: robohash-cli audit <inputs file> [threshold]")?;
+    let threshold = args.get(1).map(|t| t.parse::<f32>()).transpose()?;
+    let inputs = std::fs::read_to_string(path)?;
+    let inputs = inputs
+        .lines()
+        .map(str::trim)
+        .filter(|input| !input.is_empty())
+        .collect::<Vec<&str>>();
+
+    // Small robots are enough for perceptual hashes
+    let parts = Arc::new(CachedParts::new(EmbeddedParts::new()));
+    let robots = inputs
+        .iter()
+        .map(|input| {
+            RoboHashBuilder::new(input)
+                .with_size(64, 64)
+                .with_parts_provider(parts.clone())
+                .build()
+        })
+        .collect::<Result<Vec<RoboHash>, _>>()?;
+    for pair in similarity::audit(&robots, threshold.unwrap_or(0.08))? {
+        println!(
+            "{}\t{}\t{:.3}\t{:.3}",
+            inputs[pair.first], inputs[pair.second], pair.trait_distance, pair.pixel_distance
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use std::f32::consts::PI;
+
+use image::{Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+/// Outline avatars are cut to, centered in the image.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Shape {
+    /// Circle of the shortest side of the image.
+    Circle,
+    /// The whole image with corners rounded by `radius` pixels.
+    RoundedRectangle { radius: f32 },
+    /// Superellipse of the shortest side of the image, between the circle and the square.
+    Squircle,
+    /// Hexagon with flat top and bottom sides, as wide as the shortest side of the image.
+    Hexagon,
+}
+
+/// Stroke drawn inside the edge of the mask.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Border {
+    pub color: [u8; 4],
+    /// Width in pixels.
+    pub width: f32,
+}
+
+/// Cuts the rendered avatar to a shape with anti-aliased edges, so it looks the
+/// same on every platform and once saved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mask {
+    pub shape: Shape,
+    pub border: Option<Border>,
+}
+
+impl Mask {
+    pub fn new(shape: Shape) -> Self {
+        Self {
+            shape,
+            border: None,
+        }
+    }
+
+    pub fn with_border(mut self, color: [u8; 4], width: f32) -> Self {
+        self.border = Some(Border { color, width });
+        self
+    }
+
+    /// Makes the pixels outside of the shape transparent and draws the border.
+    pub(crate) fn apply(&self, image: &mut RgbaImage) {
+        let (width, height) = image.dimensions();
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            // Distance from the center of the pixel
+            let distance = self.distance(x as f32 + 0.5, y as f32 + 0.5, width, height);
+            if let Some(border) = &self.border {
+                let stroke = coverage(distance) - coverage(distance + border.width);
+                if stroke > 0.0 {
+                    *pixel = over(border.color, stroke, *pixel);
+                }
+            }
+            pixel[3] = (pixel[3] as f32 * coverage(distance)).round() as u8;
+        }
+    }
+
+    /// Signed distance in pixels from `x`, `y` to the edge, negative inside.
+    fn distance(&self, x: f32, y: f32, width: u32, height: u32) -> f32 {
+        let (half_width, half_height) = (width as f32 / 2.0, height as f32 / 2.0);
+        let (x, y) = ((x - half_width).abs(), (y - half_height).abs());
+        let radius = half_width.min(half_height);
+        match self.shape {
+            Shape::Circle => x.hypot(y) - radius,
+            Shape::RoundedRectangle { radius } => {
+                let radius = radius.clamp(0.0, half_width.min(half_height));
+                let (qx, qy) = (x - half_width + radius, y - half_height + radius);
+                qx.max(0.0).hypot(qy.max(0.0)) + qx.max(qy).min(0.0) - radius
+            }
+            Shape::Squircle => {
+                // |x|^4 + |y|^4 = r^4, divided by the gradient for a distance
+                let norm = (x.powi(4) + y.powi(4)).powf(0.25);
+                if norm == 0.0 {
+                    return -radius;
+                }
+                let gradient = (x.powi(6) + y.powi(6)).sqrt() / norm.powi(3);
+                (norm - radius) / gradient
+            }
+            Shape::Hexagon => {
+                // https://iquilezles.org/articles/distfunctions2d/
+                let apothem = radius * HEXAGON_APOTHEM;
+                let (kx, ky, kz) = (-HEXAGON_APOTHEM, 0.5, 0.577_350_3);
+                let dot = 2.0 * (kx * x + ky * y).min(0.0);
+                let (px, py) = (x - dot * kx, y - dot * ky);
+                let px = px - px.clamp(-kz * apothem, kz * apothem);
+                let py = py - apothem;
+                px.hypot(py) * py.signum()
+            }
+        }
+    }
+
+    /// SVG element drawing the shape moved `inset` pixels inside its edge.
+    pub(crate) fn svg_element(
+        &self,
+        width: u32,
+        height: u32,
+        inset: f32,
+        attributes: &str,
+    ) -> String {
+        let (half_width, half_height) = (width as f32 / 2.0, height as f32 / 2.0);
+        let radius = half_width.min(half_height) - inset;
+        match self.shape {
+            Shape::Circle => format!(
+                r#"<circle cx="{}" cy="{}" r="{}"{}/>"#,
+                half_width, half_height, radius, attributes
+            ),
+            Shape::RoundedRectangle { radius } => {
+                let corner = (radius.clamp(0.0, half_width.min(half_height)) - inset).max(0.0);
+                format!(
+                    r#"<rect x="{0}" y="{0}" width="{1}" height="{2}" rx="{3}" ry="{3}"{4}/>"#,
+                    inset,
+                    width as f32 - 2.0 * inset,
+                    height as f32 - 2.0 * inset,
+                    corner,
+                    attributes
+                )
+            }
+            Shape::Squircle => {
+                let points = (0..64).map(|i| {
+                    let angle = i as f32 * 2.0 * PI / 64.0;
+                    let (sin, cos) = angle.sin_cos();
+                    (
+                        radius * cos.signum() * cos.abs().sqrt(),
+                        radius * sin.signum() * sin.abs().sqrt(),
+                    )
+                });
+                polygon(points, half_width, half_height, attributes)
+            }
+            Shape::Hexagon => {
+                let radius =
+                    (half_width.min(half_height) * HEXAGON_APOTHEM - inset) / HEXAGON_APOTHEM;
+                let points = (0..6).map(|i| {
+                    let (sin, cos) = (i as f32 * PI / 3.0).sin_cos();
+                    (radius * cos, radius * sin)
+                });
+                polygon(points, half_width, half_height, attributes)
+            }
+        }
+    }
+}
+
+/// cos(30°), apothem of a hexagon of radius 1.
+const HEXAGON_APOTHEM: f32 = 0.866_025_4;
+
+fn polygon(
+    points: impl Iterator<Item = (f32, f32)>,
+    center_x: f32,
+    center_y: f32,
+    attributes: &str,
+) -> String {
+    let points = points
+        .map(|(x, y)| format!("{:.2},{:.2}", center_x + x, center_y + y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(r#"<polygon points="{}"{}/>"#, points, attributes)
+}
+
+/// Part of a pixel inside an edge `distance` pixels away.
+fn coverage(distance: f32) -> f32 {
+    (0.5 - distance).clamp(0.0, 1.0)
+}
+
+/// `color` drawn over `pixel` with the opacity `coverage`.
+fn over(color: [u8; 4], coverage: f32, pixel: Rgba<u8>) -> Rgba<u8> {
+    let alpha = color[3] as f32 / 255.0 * coverage;
+    let below = pixel[3] as f32 / 255.0 * (1.0 - alpha);
+    let out = alpha + below;
+    if out == 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+    let channel =
+        |i: usize| ((color[i] as f32 * alpha + pixel[i] as f32 * below) / out).round() as u8;
+    Rgba([
+        channel(0),
+        channel(1),
+        channel(2),
+        (out * 255.0).round() as u8,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn masked(mask: Mask, width: u32, height: u32) -> RgbaImage {
+        let mut image = RgbaImage::from_pixel(width, height, Rgba([0, 0, 255, 255]));
+        mask.apply(&mut image);
+        image
+    }
+
+    #[test]
+    fn circle_keeps_the_center_and_clears_the_corners() {
+        // act
+        let image = masked(Mask::new(Shape::Circle), 100, 100);
+        // assert
+        assert_eq!(image.get_pixel(50, 50)[3], 255);
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(image.get_pixel(99, 99)[3], 0);
+        assert_eq!(image.get_pixel(1, 50)[3], 255);
+    }
+
+    #[test]
+    fn edges_are_anti_aliased() {
+        // act
+        let image = masked(Mask::new(Shape::Circle), 100, 100);
+        // assert
+        let partial = image
+            .pixels()
+            .filter(|pixel| pixel[3] > 0 && pixel[3] < 255);
+        assert!(partial.count() > 100);
+    }
+
+    #[test]
+    fn squircle_lies_between_the_circle_and_the_square() {
+        // act
+        let circle = masked(Mask::new(Shape::Circle), 100, 100);
+        let squircle = masked(Mask::new(Shape::Squircle), 100, 100);
+        // assert
+        assert_eq!(circle.get_pixel(10, 10)[3], 0);
+        assert_eq!(squircle.get_pixel(10, 10)[3], 255);
+        assert_eq!(squircle.get_pixel(2, 2)[3], 0);
+    }
+
+    #[test]
+    fn hexagon_has_flat_top_and_pointy_sides() {
+        // act
+        let image = masked(Mask::new(Shape::Hexagon), 100, 100);
+        // assert
+        assert_eq!(image.get_pixel(1, 50)[3], 255);
+        assert_eq!(image.get_pixel(50, 3)[3], 0);
+        assert_eq!(image.get_pixel(50, 10)[3], 255);
+        assert_eq!(image.get_pixel(10, 10)[3], 0);
+    }
+
+    #[test]
+    fn rounded_rectangle_only_rounds_the_corners() {
+        // act
+        let image = masked(
+            Mask::new(Shape::RoundedRectangle { radius: 20.0 }),
+            200,
+            100,
+        );
+        // assert
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(image.get_pixel(100, 0)[3], 255);
+        assert_eq!(image.get_pixel(1, 50)[3], 255);
+    }
+
+    #[test]
+    fn border_is_drawn_inside_the_edge() {
+        // act
+        let image = masked(
+            Mask::new(Shape::Circle).with_border([255, 0, 0, 255], 4.0),
+            100,
+            100,
+        );
+        // assert
+        assert_eq!(*image.get_pixel(1, 50), Rgba([255, 0, 0, 255]));
+        assert_eq!(*image.get_pixel(10, 50), Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn border_over_transparent_pixels_keeps_its_color() {
+        // act
+        let pixel = over([255, 0, 0, 255], 0.5, Rgba([0, 0, 0, 0]));
+        // assert
+        assert_eq!(pixel, Rgba([255, 0, 0, 128]));
+    }
+
+    #[test]
+    fn svg_elements_follow_the_shape() {
+        assert_eq!(
+            Mask::new(Shape::Circle).svg_element(100, 60, 2.0, r#" fill="none""#),
+            r#"<circle cx="50" cy="30" r="28" fill="none"/>"#
+        );
+        assert!(Mask::new(Shape::Hexagon)
+            .svg_element(100, 100, 0.0, "")
+            .starts_with(r#"<polygon points="100.00,50.00 75.00,93.30"#));
+    }
+}
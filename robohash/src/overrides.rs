@@ -0,0 +1,159 @@
+use std::fmt;
+use std::sync::Arc;
+
+use image::RgbaImage;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Layer of a set, named like its directory. Layers of other sets than set1 are
+/// given by name with `Named`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Layer {
+    Body,
+    Face,
+    Mouth,
+    Eyes,
+    Accessory,
+    Named(String),
+}
+
+impl Layer {
+    pub fn name(&self) -> &str {
+        match self {
+            Layer::Body => "Body",
+            Layer::Face => "Face",
+            Layer::Mouth => "Mouth",
+            Layer::Eyes => "Eyes",
+            Layer::Accessory => "Accessory",
+            Layer::Named(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What is drawn behind the robot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background {
+    /// A background of the provider.
+    Embedded(EmbeddedBackground),
+    /// A flat RGBA color.
+    Solid([u8; 4]),
+    /// A flat pastel color picked from the hash.
+    Derived,
+    /// An image of the caller, fitted to the image size like the art, see
+    /// `Background::custom`.
+    Custom(Arc<RgbaImage>),
+    /// No background.
+    Transparent,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum EmbeddedBackground {
+    /// Picked from the hash among every background.
+    #[default]
+    Auto,
+    /// The background at this index, see `Choices::backgrounds`.
+    Id(usize),
+    /// Picked from the hash among the backgrounds at these indices.
+    Subset(Vec<usize>),
+}
+
+impl Background {
+    /// Custom background decoded from PNG, JPEG, WebP... bytes.
+    pub fn custom(bytes: &[u8]) -> Result<Self, Error> {
+        let image = image::load_from_memory(bytes)?;
+        Ok(Background::Custom(Arc::new(image.to_rgba8())))
+    }
+}
+
+impl Default for Background {
+    fn default() -> Self {
+        Background::Embedded(EmbeddedBackground::Auto)
+    }
+}
+
+/// `with_background(&true)` picks a background from the hash, `&false` none.
+impl From<&bool> for Background {
+    fn from(use_background: &bool) -> Self {
+        match use_background {
+            true => Background::default(),
+            false => Background::Transparent,
+        }
+    }
+}
+
+/// Values the overrides of a robot accept, for pickers letting users tweak it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Choices {
+    /// Parts of each layer in the color of the robot, in stacking order.
+    pub layers: Vec<LayerChoices>,
+    /// Number of backgrounds, `EmbeddedBackground::Id` takes `0..backgrounds`.
+    pub backgrounds: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerChoices {
+    pub layer: String,
+    /// Number of parts, `with_part` takes `0..parts`.
+    pub parts: usize,
+}
+
+impl Choices {
+    /// Choices of the layer named `layer`, ignoring case.
+    pub fn layer(&self, layer: &Layer) -> Option<&LayerChoices> {
+        self.layers
+            .iter()
+            .find(|choices| choices.layer.eq_ignore_ascii_case(layer.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn background_from_bool_keeps_the_meaning_of_with_background() {
+        assert_eq!(
+            Background::from(&true),
+            Background::Embedded(EmbeddedBackground::Auto)
+        );
+        assert_eq!(Background::from(&false), Background::Transparent);
+    }
+
+    #[test]
+    fn custom_backgrounds_are_decoded_from_any_format() {
+        // arrange
+        let mut png = Vec::new();
+        RgbaImage::from_pixel(3, 2, image::Rgba([1, 2, 3, 255]))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        // act
+        let background = Background::custom(&png).unwrap();
+        // assert
+        assert!(matches!(background, Background::Custom(image) if image.dimensions() == (3, 2)));
+        assert!(Background::custom(b"not an image").is_err());
+    }
+
+    #[test]
+    fn choices_find_the_layer_ignoring_case() {
+        // arrange
+        let choices = Choices {
+            layers: vec![LayerChoices {
+                layer: String::from("EYES"),
+                parts: 12,
+            }],
+            backgrounds: 21,
+        };
+        // act
+        let eyes = choices.layer(&Layer::Eyes);
+        // assert
+        assert_eq!(eyes.map(|eyes| eyes.parts), Some(12));
+        assert!(choices.layer(&Layer::Named(String::from("Hat"))).is_none());
+    }
+}
@@ -0,0 +1,782 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Seek};
+use std::path::Path;
+
+use image::DynamicImage;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::catalog::{RoboSet, SetCatalog};
+use crate::describe::Description;
+use crate::error::Error;
+use crate::provider::PartsProvider;
+use crate::MAX_LAYERS;
+
+pub const MANIFEST: &str = "pack.json";
+pub const PACK_VERSION: u32 = 1;
+
+/// `pack.json`, the manifest at the root of an asset pack.
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "backgrounds": 2,
+///   "sets": [
+///     {
+///       "set": "set2",
+///       "colors": ["default"],
+///       "layers": [
+///         { "name": "Body", "counts": [3] },
+///         { "name": "Eyes", "counts": [5] }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Layers are listed in stacking order and `counts` holds the number of parts of
+/// the layer for each color. `background_sets` optionally splits the backgrounds
+/// in the directories upstream Robohash picks from, e.g. `[14, 7]` for `bg1/` and
+/// `bg2/`, and a layer `sort_order` gives its upstream `NNN#` prefix. Both only
+/// matter to `Compatibility::Upstream`.
+///
+/// `descriptions` optionally describes the colors and parts of a set for
+/// `RoboHash::describe`, by color, then layer, then part index, and
+/// `background_descriptions` the backgrounds in order. Like the embedded ones,
+/// colors are adjectives that `describe` puts in "a {color} robot" and
+/// "un robot {color}", whatever the set:
+///
+/// ```json
+/// "descriptions": [
+///   {
+///     "set": "set2",
+///     "colors": { "default": { "en": "furry", "es": "peludo" } },
+///     "parts": { "default": { "Eyes": [{ "en": "one eye", "es": "un ojo" }, ...] } }
+///   }
+/// ]
+/// ```
+///
+/// The images are stored next to the manifest:
+///
+/// ```text
+/// pack.json
+/// sets/<set>/<color>/<layer name>/<parts>
+/// backgrounds/<backgrounds>
+/// ```
+///
+/// Parts and backgrounds are indexed by file name order, so prefixing them with
+/// their index (`000#body.png`) keeps them stable. Any format `image` can guess
+/// from the content (PNG, WebP...) is accepted. Files elsewhere fail the pack,
+/// but for files and directories starting with `.` or `_`, like `__MACOSX/`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u32,
+    #[serde(default)]
+    pub backgrounds: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub background_sets: Vec<usize>,
+    pub sets: Vec<SetCatalog>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub descriptions: Vec<SetDescriptions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub background_descriptions: Vec<Description>,
+}
+
+/// Descriptions of the colors and parts of a set of a pack, every layer listed
+/// describes all the parts of its color.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetDescriptions {
+    pub set: RoboSet,
+    /// color -> description
+    #[serde(default)]
+    pub colors: BTreeMap<String, Description>,
+    /// color -> layer -> description of each part
+    #[serde(default)]
+    pub parts: BTreeMap<String, BTreeMap<String, Vec<Description>>>,
+}
+
+/// Parts loaded at runtime from an asset pack directory or zip archive.
+#[derive(Debug, Clone)]
+pub struct PackParts {
+    sets: Vec<SetCatalog>,
+    // set -> color -> layer -> encoded images
+    parts: Vec<Vec<Vec<Vec<Vec<u8>>>>>,
+    backgrounds: Vec<Vec<u8>>,
+    background_sets: Vec<usize>,
+    descriptions: Vec<SetDescriptions>,
+    background_descriptions: Vec<Description>,
+}
+
+impl PackParts {
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let root = path.as_ref();
+        let mut files = BTreeMap::new();
+        for entry in WalkDir::new(root).sort_by_file_name() {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative_path = entry
+                .path()
+                .strip_prefix(root)
+                .map_err(|_| Error::InvalidPack(entry.path().display().to_string()))?;
+            let name = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(name, fs::read(entry.path())?);
+        }
+        Self::from_files(files)
+    }
+
+    pub fn from_zip<R: Read + Seek>(reader: R) -> Result<Self, Error> {
+        let mut archive = zip::ZipArchive::new(reader)?;
+        let mut files = BTreeMap::new();
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if !file.is_file() {
+                continue;
+            }
+            let mut bytes = Vec::with_capacity(file.size() as usize);
+            file.read_to_end(&mut bytes)?;
+            files.insert(file.name().to_string(), bytes);
+        }
+        Self::from_files(files)
+    }
+
+    pub fn manifest(&self) -> Manifest {
+        Manifest {
+            version: PACK_VERSION,
+            backgrounds: self.backgrounds.len(),
+            background_sets: self.background_sets.clone(),
+            sets: self.sets.clone(),
+            descriptions: self.descriptions.clone(),
+            background_descriptions: self.background_descriptions.clone(),
+        }
+    }
+
+    fn set_descriptions(&self, set: RoboSet) -> Option<(&SetCatalog, &SetDescriptions)> {
+        let catalog = self.sets.iter().find(|catalog| catalog.set == set)?;
+        let descriptions = self
+            .descriptions
+            .iter()
+            .find(|descriptions| descriptions.set == set)?;
+        Some((catalog, descriptions))
+    }
+
+    pub(crate) fn from_files(mut files: BTreeMap<String, Vec<u8>>) -> Result<Self, Error> {
+        files.retain(|name, _| {
+            !name
+                .split('/')
+                .any(|component| component.starts_with(['.', '_']))
+        });
+        let manifest = files
+            .remove(MANIFEST)
+            .ok_or_else(|| Error::InvalidPack(format!("missing {}", MANIFEST)))?;
+        let manifest: Manifest = serde_json::from_slice(&manifest)?;
+        if manifest.version != PACK_VERSION {
+            return Err(Error::InvalidPack(format!(
+                "unsupported version {}",
+                manifest.version
+            )));
+        }
+
+        if let Some(descriptions) = manifest
+            .descriptions
+            .iter()
+            .find(|descriptions| !manifest.sets.iter().any(|set| set.set == descriptions.set))
+        {
+            return Err(Error::InvalidPack(format!(
+                "descriptions of {}, which the pack does not list",
+                descriptions.set
+            )));
+        }
+
+        for (i, set) in manifest.sets.iter().enumerate() {
+            if manifest.sets[..i].iter().any(|other| other.set == set.set) {
+                return Err(Error::InvalidPack(format!("{} is listed twice", set.set)));
+            }
+        }
+
+        let mut parts = Vec::with_capacity(manifest.sets.len());
+        for set in &manifest.sets {
+            let descriptions = manifest
+                .descriptions
+                .iter()
+                .find(|descriptions| descriptions.set == set.set);
+            validate_set(set, descriptions)?;
+            let mut colors = Vec::with_capacity(set.colors.len());
+            for (c, color) in set.colors.iter().enumerate() {
+                let mut layers = Vec::with_capacity(set.layers.len());
+                for layer in &set.layers {
+                    let directory = format!("sets/{}/{}/{}/", set.set, color, layer.name);
+                    layers.push(take_files(&mut files, &directory, layer.counts[c])?);
+                }
+                colors.push(layers);
+            }
+            parts.push(colors);
+        }
+        let backgrounds = take_files(&mut files, "backgrounds/", manifest.backgrounds)?;
+        if let Some(name) = files.keys().next() {
+            return Err(Error::InvalidPack(format!(
+                "{} is not in the directory of a listed layer or in backgrounds/",
+                name
+            )));
+        }
+        let background_sets = manifest.background_sets;
+        if !background_sets.is_empty() && background_sets.iter().sum::<usize>() != backgrounds.len()
+        {
+            return Err(Error::InvalidPack(format!(
+                "background sets {:?} do not add up to {} backgrounds",
+                background_sets,
+                backgrounds.len()
+            )));
+        }
+        let background_descriptions = manifest.background_descriptions;
+        if !background_descriptions.is_empty() && background_descriptions.len() != backgrounds.len()
+        {
+            return Err(Error::InvalidPack(format!(
+                "{} background descriptions for {} backgrounds",
+                background_descriptions.len(),
+                backgrounds.len()
+            )));
+        }
+
+        Ok(Self {
+            sets: manifest.sets,
+            parts,
+            backgrounds,
+            background_sets,
+            descriptions: manifest.descriptions,
+            background_descriptions,
+        })
+    }
+}
+
+fn validate_set(set: &SetCatalog, descriptions: Option<&SetDescriptions>) -> Result<(), Error> {
+    if set.set == RoboSet::Any {
+        return Err(Error::InvalidPack(String::from(
+            "\"any\" is not a robot set",
+        )));
+    }
+    if set.colors.is_empty() || set.layers.is_empty() {
+        return Err(Error::InvalidPack(format!(
+            "{} needs at least one color and one layer",
+            set.set
+        )));
+    }
+    if set.layers.len() > MAX_LAYERS {
+        return Err(Error::InvalidPack(format!(
+            "{} has {} layers, at most {} are supported",
+            set.set,
+            set.layers.len(),
+            MAX_LAYERS
+        )));
+    }
+    for (i, color) in set.colors.iter().enumerate() {
+        if set.colors[..i].contains(color) {
+            return Err(Error::InvalidPack(format!(
+                "{} lists the color {} twice",
+                set.set, color
+            )));
+        }
+    }
+    for (i, layer) in set.layers.iter().enumerate() {
+        if set.layers[..i]
+            .iter()
+            .any(|other| other.name.eq_ignore_ascii_case(&layer.name))
+        {
+            return Err(Error::InvalidPack(format!(
+                "{} lists the layer {} twice",
+                set.set, layer.name
+            )));
+        }
+    }
+    for layer in &set.layers {
+        if layer.counts.len() != set.colors.len() || layer.counts.contains(&0) {
+            return Err(Error::InvalidPack(format!(
+                "{}/{} needs a part count for each color",
+                set.set, layer.name
+            )));
+        }
+    }
+    if let Some(descriptions) = descriptions {
+        validate_descriptions(set, descriptions)?;
+    }
+    Ok(())
+}
+
+fn validate_descriptions(set: &SetCatalog, descriptions: &SetDescriptions) -> Result<(), Error> {
+    let color_index = |color: &String| {
+        set.colors.iter().position(|c| c == color).ok_or_else(|| {
+            Error::InvalidPack(format!(
+                "descriptions of {}/{}, not a color",
+                set.set, color
+            ))
+        })
+    };
+    for color in descriptions.colors.keys() {
+        color_index(color)?;
+    }
+    for (color, layers) in &descriptions.parts {
+        let c = color_index(color)?;
+        for (layer, parts) in layers {
+            let layer_catalog = set
+                .layers
+                .iter()
+                .find(|layer_catalog| layer_catalog.name == *layer)
+                .ok_or_else(|| {
+                    Error::InvalidPack(format!(
+                        "descriptions of {}/{}/{}, not a layer",
+                        set.set, color, layer
+                    ))
+                })?;
+            if parts.len() != layer_catalog.counts[c] {
+                return Err(Error::InvalidPack(format!(
+                    "{} descriptions for the {} parts of {}/{}/{}",
+                    parts.len(),
+                    layer_catalog.counts[c],
+                    set.set,
+                    color,
+                    layer
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes the images right in `directory`, in file name order. Files of its
+/// subdirectories are left to fail as unclaimed.
+fn take_files(
+    files: &mut BTreeMap<String, Vec<u8>>,
+    directory: &str,
+    count: usize,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let names = files
+        .keys()
+        .filter(|name| {
+            name.strip_prefix(directory)
+                .is_some_and(|file| !file.contains('/'))
+        })
+        .cloned()
+        .collect::<Vec<String>>();
+    if names.len() != count {
+        return Err(Error::InvalidPack(format!(
+            "expected {} images in {}, found {}",
+            count,
+            directory,
+            names.len()
+        )));
+    }
+    Ok(names.iter().filter_map(|name| files.remove(name)).collect())
+}
+
+impl PartsProvider for PackParts {
+    fn sets(&self) -> &[SetCatalog] {
+        &self.sets
+    }
+
+    fn backgrounds(&self) -> usize {
+        self.backgrounds.len()
+    }
+
+    fn background_sets(&self) -> Vec<usize> {
+        match self.background_sets.is_empty() {
+            true => vec![self.backgrounds.len()],
+            false => self.background_sets.clone(),
+        }
+    }
+
+    fn part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Result<DynamicImage, Error> {
+        let s = self
+            .sets
+            .iter()
+            .position(|catalog| catalog.set == set)
+            .ok_or_else(|| Error::RoboSetUnavailable(set.to_string()))?;
+        let bytes = self.parts[s]
+            .get(color)
+            .and_then(|layers| layers.get(layer))
+            .and_then(|parts| parts.get(index))
+            .ok_or_else(|| {
+                Error::InvalidArrayIndex(format!("{}/{}/{}", set, color, layer), index.to_string())
+            })?;
+        Ok(image::load_from_memory(bytes)?)
+    }
+
+    fn background(&self, index: usize) -> Result<DynamicImage, Error> {
+        let bytes = self.backgrounds.get(index).ok_or_else(|| {
+            Error::InvalidArrayIndex(String::from("backgrounds"), index.to_string())
+        })?;
+        Ok(image::load_from_memory(bytes)?)
+    }
+
+    fn color_description(&self, set: RoboSet, color: usize) -> Option<Description> {
+        let (catalog, descriptions) = self.set_descriptions(set)?;
+        descriptions.colors.get(catalog.colors.get(color)?).cloned()
+    }
+
+    fn part_description(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Option<Description> {
+        let (catalog, descriptions) = self.set_descriptions(set)?;
+        let layers = descriptions.parts.get(catalog.colors.get(color)?)?;
+        let parts = layers.get(&catalog.layers.get(layer)?.name)?;
+        parts.get(index).cloned()
+    }
+
+    fn background_description(&self, index: usize) -> Option<Description> {
+        self.background_descriptions.get(index).cloned()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{Cursor, Write};
+    use std::path::PathBuf;
+
+    use image::{ImageOutputFormat, Rgba, RgbaImage};
+    use zip::write::FileOptions;
+
+    use crate::catalog::LayerCatalog;
+
+    use super::*;
+
+    pub(crate) fn test_pack_files() -> Vec<(String, Vec<u8>)> {
+        let manifest = Manifest {
+            version: PACK_VERSION,
+            backgrounds: 1,
+            background_sets: Vec::new(),
+            descriptions: Vec::new(),
+            background_descriptions: Vec::new(),
+            sets: vec![SetCatalog {
+                set: RoboSet::Set4,
+                colors: vec![String::from("default")],
+                layers: vec![
+                    LayerCatalog {
+                        name: String::from("Body"),
+                        counts: vec![2],
+                        sort_order: None,
+                    },
+                    LayerCatalog {
+                        name: String::from("Eyes"),
+                        counts: vec![3],
+                        sort_order: None,
+                    },
+                ],
+            }],
+        };
+        let mut files = vec![(
+            String::from(MANIFEST),
+            serde_json::to_vec(&manifest).unwrap(),
+        )];
+        let images = [
+            ("sets/set4/default/Body/000#body.png", [255, 0, 0, 255]),
+            ("sets/set4/default/Body/001#body.png", [0, 255, 0, 255]),
+            ("sets/set4/default/Eyes/000#eyes.png", [0, 0, 255, 128]),
+            ("sets/set4/default/Eyes/001#eyes.png", [0, 0, 0, 128]),
+            ("sets/set4/default/Eyes/002#eyes.png", [255, 255, 255, 128]),
+            ("backgrounds/000#background.png", [9, 9, 9, 255]),
+        ];
+        for (name, color) in images {
+            let image = RgbaImage::from_pixel(8, 8, Rgba(color));
+            let mut bytes = Vec::new();
+            image
+                .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)
+                .unwrap();
+            files.push((String::from(name), bytes));
+        }
+        files
+    }
+
+    pub(crate) fn write_test_pack(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("robohash-{}-{}", name, std::process::id()));
+        for (name, bytes) in test_pack_files() {
+            let path = root.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, bytes).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn from_dir_loads_the_pack_catalog_and_images() {
+        // arrange
+        let root = write_test_pack("from-dir");
+        // act
+        let pack = PackParts::from_dir(&root);
+        fs::remove_dir_all(&root).unwrap();
+        // assert
+        let pack = pack.unwrap();
+        assert_eq!(pack.sets()[0].set, RoboSet::Set4);
+        assert_eq!(pack.backgrounds(), 1);
+        let eyes = pack.part(RoboSet::Set4, 0, 1, 2).unwrap().to_rgba8();
+        assert_eq!(eyes.get_pixel(0, 0), &Rgba([255, 255, 255, 128]));
+    }
+
+    #[test]
+    fn from_zip_loads_the_pack_catalog_and_images() {
+        // arrange
+        let mut bytes = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(Cursor::new(&mut bytes));
+            for (name, content) in test_pack_files() {
+                writer.start_file(name, FileOptions::default()).unwrap();
+                writer.write_all(&content).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        // act
+        let pack = PackParts::from_zip(Cursor::new(bytes)).unwrap();
+        // assert
+        assert_eq!(pack.manifest().sets[0].layers[1].counts, vec![3]);
+        let body = pack.part(RoboSet::Set4, 0, 0, 1).unwrap().to_rgba8();
+        assert_eq!(body.get_pixel(0, 0), &Rgba([0, 255, 0, 255]));
+    }
+
+    #[test]
+    fn from_files_rejects_counts_that_do_not_match_the_images() {
+        // arrange
+        let mut files = test_pack_files()
+            .into_iter()
+            .collect::<BTreeMap<String, Vec<u8>>>();
+        files.remove("sets/set4/default/Eyes/001#eyes.png");
+        // act
+        let pack = PackParts::from_files(files);
+        // assert
+        assert_eq!(
+            pack.err().unwrap().to_string(),
+            Error::InvalidPack(String::from(
+                "expected 3 images in sets/set4/default/Eyes/, found 2"
+            ))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn from_files_rejects_malformed_packs() {
+        // arrange
+        let pack_files = |edit: &dyn Fn(&mut Manifest), extra_images: &[&str]| {
+            let mut files = test_pack_files()
+                .into_iter()
+                .collect::<BTreeMap<String, Vec<u8>>>();
+            let mut manifest: Manifest = serde_json::from_slice(&files[MANIFEST]).unwrap();
+            edit(&mut manifest);
+            files.insert(
+                String::from(MANIFEST),
+                serde_json::to_vec(&manifest).unwrap(),
+            );
+            let body = files["sets/set4/default/Body/000#body.png"].clone();
+            for name in extra_images {
+                files.insert(String::from(*name), body.clone());
+            }
+            files
+        };
+        let duplicate_set = pack_files(
+            &|manifest| {
+                let set = manifest.sets[0].clone();
+                manifest.sets.push(set);
+            },
+            &[],
+        );
+        let duplicate_layer = pack_files(
+            &|manifest| {
+                let mut eyes = manifest.sets[0].layers[1].clone();
+                eyes.name = String::from("eyes");
+                manifest.sets[0].layers.push(eyes);
+            },
+            &[],
+        );
+        let unlisted_layer = pack_files(&|_| {}, &["sets/set4/default/Tail/000#tail.png"]);
+        let nested = pack_files(&|_| {}, &["sets/set4/default/Eyes/old/000#eyes.png"]);
+        let hidden = pack_files(
+            &|_| {},
+            &[
+                "sets/set4/default/Eyes/.DS_Store",
+                "__MACOSX/sets/set4/default/Eyes/._000#eyes.png",
+            ],
+        );
+        // act
+        let error = |files| PackParts::from_files(files).err().unwrap().to_string();
+        // assert
+        assert_eq!(
+            error(duplicate_set),
+            Error::InvalidPack(String::from("set4 is listed twice")).to_string()
+        );
+        assert_eq!(
+            error(duplicate_layer),
+            Error::InvalidPack(String::from("set4 lists the layer eyes twice")).to_string()
+        );
+        assert_eq!(
+            error(unlisted_layer),
+            Error::InvalidPack(String::from(
+                "sets/set4/default/Tail/000#tail.png is not in the directory of a listed layer or in backgrounds/"
+            ))
+            .to_string()
+        );
+        assert_eq!(
+            error(nested),
+            Error::InvalidPack(String::from(
+                "sets/set4/default/Eyes/old/000#eyes.png is not in the directory of a listed layer or in backgrounds/"
+            ))
+            .to_string()
+        );
+        assert!(PackParts::from_files(hidden).is_ok());
+    }
+
+    /// Test pack files with `descriptions` in the manifest.
+    fn described_pack_files(descriptions: serde_json::Value) -> BTreeMap<String, Vec<u8>> {
+        let mut files = test_pack_files()
+            .into_iter()
+            .collect::<BTreeMap<String, Vec<u8>>>();
+        let mut manifest: serde_json::Value = serde_json::from_slice(&files[MANIFEST]).unwrap();
+        manifest["descriptions"] = descriptions;
+        files.insert(
+            String::from(MANIFEST),
+            serde_json::to_vec(&manifest).unwrap(),
+        );
+        files
+    }
+
+    #[test]
+    fn pack_parts_describe_the_colors_and_parts_of_the_manifest() {
+        // arrange
+        let text = |en: &str, es: &str| serde_json::json!({ "en": en, "es": es });
+        let files = described_pack_files(serde_json::json!([{
+            "set": "set4",
+            "colors": { "default": text("furry", "peludo") },
+            "parts": { "default": { "Eyes": [
+                text("round eyes", "ojos redondos"),
+                text("sleepy eyes", "ojos dormidos"),
+                text("big eyes", "ojos grandes")
+            ] } }
+        }]));
+        // act
+        let pack = PackParts::from_files(files).unwrap();
+        // assert
+        let en = |description: Option<Description>| description.map(|d| d.en);
+        assert_eq!(
+            en(pack.color_description(RoboSet::Set4, 0)).unwrap(),
+            "furry"
+        );
+        assert_eq!(
+            en(pack.part_description(RoboSet::Set4, 0, 1, 2)).unwrap(),
+            "big eyes"
+        );
+        assert_eq!(pack.part_description(RoboSet::Set4, 0, 0, 0), None);
+        assert_eq!(pack.manifest().descriptions.len(), 1);
+        let robo_hash = crate::RoboHashBuilder::new("test")
+            .with_parts_provider(std::sync::Arc::new(pack))
+            .with_set(RoboSet::Set4)
+            .with_part(crate::Layer::Named(String::from("Eyes")), 2)
+            .with_background(crate::Background::Transparent)
+            .build()
+            .unwrap();
+        assert_eq!(
+            robo_hash.describe(crate::Locale::En).unwrap(),
+            "a furry robot with big eyes"
+        );
+        assert_eq!(
+            robo_hash.describe(crate::Locale::Es).unwrap(),
+            "un robot peludo con ojos grandes"
+        );
+    }
+
+    #[test]
+    fn from_files_rejects_descriptions_that_do_not_match_the_catalog() {
+        // arrange
+        let text = serde_json::json!({ "en": "eyes", "es": "ojos" });
+        let short = described_pack_files(serde_json::json!([{
+            "set": "set4",
+            "parts": { "default": { "Eyes": [text] } }
+        }]));
+        let unknown_layer = described_pack_files(serde_json::json!([{
+            "set": "set4",
+            "parts": { "default": { "Tail": [] } }
+        }]));
+        let unknown_set = described_pack_files(serde_json::json!([{ "set": "set2" }]));
+        // act
+        let error = |files| PackParts::from_files(files).err().unwrap().to_string();
+        // assert
+        assert_eq!(
+            error(short),
+            Error::InvalidPack(String::from(
+                "1 descriptions for the 3 parts of set4/default/Eyes"
+            ))
+            .to_string()
+        );
+        assert_eq!(
+            error(unknown_layer),
+            Error::InvalidPack(String::from(
+                "descriptions of set4/default/Tail, not a layer"
+            ))
+            .to_string()
+        );
+        assert_eq!(
+            error(unknown_set),
+            Error::InvalidPack(String::from(
+                "descriptions of set2, which the pack does not list"
+            ))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn from_files_rejects_sets_with_more_layers_than_slots() {
+        // arrange
+        let mut files = test_pack_files()
+            .into_iter()
+            .collect::<BTreeMap<String, Vec<u8>>>();
+        let mut manifest: Manifest = serde_json::from_slice(&files[MANIFEST]).unwrap();
+        let eyes = manifest.sets[0].layers[1].clone();
+        manifest.sets[0].layers.resize(MAX_LAYERS + 1, eyes);
+        files.insert(
+            String::from(MANIFEST),
+            serde_json::to_vec(&manifest).unwrap(),
+        );
+        // act
+        let pack = PackParts::from_files(files);
+        // assert
+        assert_eq!(
+            pack.err().unwrap().to_string(),
+            Error::InvalidPack(String::from("set4 has 17 layers, at most 16 are supported"))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn from_files_rejects_background_sets_that_do_not_add_up() {
+        // arrange
+        let mut files = test_pack_files()
+            .into_iter()
+            .collect::<BTreeMap<String, Vec<u8>>>();
+        let mut manifest: Manifest = serde_json::from_slice(&files[MANIFEST]).unwrap();
+        manifest.background_sets = vec![1, 1];
+        files.insert(
+            String::from(MANIFEST),
+            serde_json::to_vec(&manifest).unwrap(),
+        );
+        // act
+        let pack = PackParts::from_files(files);
+        // assert
+        assert_eq!(
+            pack.err().unwrap().to_string(),
+            Error::InvalidPack(String::from(
+                "background sets [1, 1] do not add up to 1 backgrounds"
+            ))
+            .to_string()
+        );
+    }
+}
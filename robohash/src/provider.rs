@@ -0,0 +1,210 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use image::{DynamicImage, RgbaImage};
+
+use crate::backgrounds::{BACKGROUNDS, BACKGROUND_SETS};
+use crate::catalog::{self, RoboSet, SetCatalog};
+use crate::describe::{Description, EmbeddedSetDescriptions};
+use crate::descriptions;
+use crate::error::Error;
+use crate::image::{from_base64, resize};
+use crate::robot_parts::SETS;
+
+/// Source of the images a robot is composed of.
+///
+/// The renderer only picks indices from the catalog returned by `sets`, then asks
+/// the provider for the decoded images.
+pub trait PartsProvider: Debug + Send + Sync {
+    /// Robot sets this provider can draw.
+    fn sets(&self) -> &[SetCatalog];
+
+    /// Number of backgrounds this provider can draw.
+    fn backgrounds(&self) -> usize;
+
+    fn part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Result<DynamicImage, Error>;
+
+    fn background(&self, index: usize) -> Result<DynamicImage, Error>;
+
+    /// Number of backgrounds in each background set, the backgrounds being listed
+    /// set after set. Upstream Robohash picks a set before a background, only
+    /// `Compatibility::Upstream` uses it.
+    fn background_sets(&self) -> Vec<usize> {
+        vec![self.backgrounds()]
+    }
+
+    /// Description of a color for `RoboHash::describe`, `None` if undescribed.
+    fn color_description(&self, _set: RoboSet, _color: usize) -> Option<Description> {
+        None
+    }
+
+    fn part_description(
+        &self,
+        _set: RoboSet,
+        _color: usize,
+        _layer: usize,
+        _index: usize,
+    ) -> Option<Description> {
+        None
+    }
+
+    fn background_description(&self, _index: usize) -> Option<Description> {
+        None
+    }
+
+    /// `part` resized to `width`x`height`, what the renderer draws. Caching
+    /// providers such as `CachedParts` override it.
+    fn resized_part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+        width: u32,
+        height: u32,
+    ) -> Result<Arc<RgbaImage>, Error> {
+        let part = self.part(set, color, layer, index)?;
+        Ok(Arc::new(resize(&part, width, height)))
+    }
+
+    fn resized_background(
+        &self,
+        index: usize,
+        width: u32,
+        height: u32,
+    ) -> Result<Arc<RgbaImage>, Error> {
+        let background = self.background(index)?;
+        Ok(Arc::new(resize(&background, width, height)))
+    }
+}
+
+/// Parts compiled into the binary by `cargo xtask assets`.
+#[derive(Debug, Clone)]
+pub struct EmbeddedParts {
+    sets: Vec<SetCatalog>,
+}
+
+impl EmbeddedParts {
+    pub fn new() -> Self {
+        Self {
+            sets: catalog::embedded(),
+        }
+    }
+}
+
+impl Default for EmbeddedParts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartsProvider for EmbeddedParts {
+    fn sets(&self) -> &[SetCatalog] {
+        &self.sets
+    }
+
+    fn backgrounds(&self) -> usize {
+        BACKGROUNDS.len()
+    }
+
+    fn background_sets(&self) -> Vec<usize> {
+        BACKGROUND_SETS.to_vec()
+    }
+
+    fn part(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Result<DynamicImage, Error> {
+        let embedded_set = SETS
+            .iter()
+            .find(|embedded_set| embedded_set.set() == set)
+            .ok_or_else(|| Error::RoboSetUnavailable(set.to_string()))?;
+        let part = embedded_set
+            .parts
+            .get(color)
+            .and_then(|layers| layers.get(layer))
+            .and_then(|parts| parts.get(index))
+            .ok_or_else(|| {
+                Error::InvalidArrayIndex(format!("{}/{}/{}", set, color, layer), index.to_string())
+            })?;
+        from_base64(part)
+    }
+
+    fn background(&self, index: usize) -> Result<DynamicImage, Error> {
+        let background = BACKGROUNDS.get(index).ok_or_else(|| {
+            Error::InvalidArrayIndex(String::from("backgrounds"), index.to_string())
+        })?;
+        from_base64(background)
+    }
+
+    fn color_description(&self, set: RoboSet, color: usize) -> Option<Description> {
+        let descriptions = embedded_descriptions(set)?;
+        Some(descriptions.colors.get(color)?.description())
+    }
+
+    fn part_description(
+        &self,
+        set: RoboSet,
+        color: usize,
+        layer: usize,
+        index: usize,
+    ) -> Option<Description> {
+        let descriptions = embedded_descriptions(set)?;
+        let part = descriptions.parts.get(color)?.get(layer)?.get(index)?;
+        Some(part.description())
+    }
+
+    fn background_description(&self, index: usize) -> Option<Description> {
+        Some(descriptions::BACKGROUNDS.get(index)?.description())
+    }
+}
+
+fn embedded_descriptions(set: RoboSet) -> Option<&'static EmbeddedSetDescriptions> {
+    descriptions::SETS
+        .iter()
+        .find(|descriptions| descriptions.name == set.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_parts_decode_every_catalog_entry() {
+        // arrange
+        let provider = EmbeddedParts::new();
+        let set1 = &provider.sets()[0];
+        // act
+        let last_parts = (0..set1.colors.len())
+            .flat_map(|color| {
+                (0..set1.layers.len())
+                    .map(move |layer| (color, layer, set1.layer_count(layer, color) - 1))
+            })
+            .map(|(color, layer, index)| provider.part(RoboSet::Set1, color, layer, index))
+            .collect::<Vec<_>>();
+        // assert
+        assert!(last_parts.iter().all(|part| part.is_ok()));
+    }
+
+    #[test]
+    fn embedded_parts_reject_indices_past_the_layer_count() {
+        // arrange
+        let provider = EmbeddedParts::new();
+        let set1 = &provider.sets()[0];
+        let eyes = set1.layers.iter().position(|layer| layer.name == "Eyes");
+        let purple = set1.colors.iter().position(|color| color == "purple");
+        // act
+        let part = provider.part(RoboSet::Set1, purple.unwrap(), eyes.unwrap(), 11);
+        // assert
+        assert!(part.is_err());
+    }
+}
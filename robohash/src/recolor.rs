@@ -0,0 +1,386 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use image::{Rgba, RgbaImage};
+
+use crate::overrides::Layer;
+
+/// How the parts of a robot with a hue are recolored.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Recoloring {
+    /// Rotates the hue of every pixel by the hue of the robot, greys and shading
+    /// included. The robots rendered so far.
+    #[default]
+    HueRotation,
+    /// Moves the colored pixels of each part to a palette color picked from the
+    /// hue of the robot, or from the hash among `Palette::colors`, keeping their
+    /// shading. Greys are left alone.
+    Palette(Palette),
+}
+
+/// Space the palette colors are picked and the pixels shifted in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    #[default]
+    Oklch,
+    Hsl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerPolicy {
+    /// Shifts the part to the palette color.
+    Recolor,
+    /// Keeps the original colors of the part.
+    Keep,
+    /// Shifts the part to the opposite hue of the palette color.
+    Complement,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub space: ColorSpace,
+    /// Lightness of the palette color, from 0 to 1.
+    pub lightness: RangeInclusive<f32>,
+    /// Chroma of the palette color, from 0 to about 0.37 in OKLCH, or its
+    /// saturation, from 0 to 1 in HSL.
+    pub chroma: RangeInclusive<f32>,
+    /// RGB colors the palette color is picked among instead, e.g. brand colors.
+    /// The index of the picked one is part of the traits.
+    pub colors: Vec<[u8; 3]>,
+    /// Policy of each layer, layers not listed are recolored.
+    pub policies: Vec<(Layer, LayerPolicy)>,
+}
+
+impl Palette {
+    /// Mid lightness and moderate chroma, the eyes keep their colors.
+    pub fn oklch() -> Self {
+        Self {
+            space: ColorSpace::Oklch,
+            lightness: 0.55..=0.8,
+            chroma: 0.08..=0.16,
+            colors: Vec::new(),
+            policies: vec![(Layer::Eyes, LayerPolicy::Keep)],
+        }
+    }
+
+    pub fn hsl() -> Self {
+        Self {
+            space: ColorSpace::Hsl,
+            lightness: 0.4..=0.65,
+            chroma: 0.4..=0.75,
+            ..Self::oklch()
+        }
+    }
+
+    pub fn policy(&self, layer: &str) -> LayerPolicy {
+        self.policies
+            .iter()
+            .find(|(other, _)| other.name().eq_ignore_ascii_case(layer))
+            .map_or(LayerPolicy::Recolor, |(_, policy)| *policy)
+    }
+
+    /// Palette color of the robots with this hue, or the color at `index` of
+    /// `colors`, every layer is shifted to it.
+    pub(crate) fn color(&self, hue: i32, index: Option<usize>) -> Lch {
+        if let Some(rgb) = index.and_then(|index| self.colors.get(index)) {
+            return to_lch(self.space, *rgb);
+        }
+        Lch {
+            l: lerp(&self.lightness, fraction(hue, 1)),
+            c: lerp(&self.chroma, fraction(hue, 2)),
+            h: hue.rem_euclid(360) as f32,
+        }
+    }
+
+    pub(crate) fn recolor(
+        &self,
+        part: &Arc<RgbaImage>,
+        layer: &str,
+        hue: i32,
+        index: Option<usize>,
+    ) -> Arc<RgbaImage> {
+        let color = self.color(hue, index);
+        let color = match self.policy(layer) {
+            LayerPolicy::Keep => return part.clone(),
+            LayerPolicy::Recolor => color,
+            LayerPolicy::Complement => Lch {
+                h: color.h + 180.0,
+                ..color
+            },
+        };
+        let mut part = part.as_ref().clone();
+        shift(&mut part, self.space, color);
+        Arc::new(part)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::oklch()
+    }
+}
+
+/// Lightness, chroma (or saturation) and hue in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Lch {
+    pub(crate) l: f32,
+    pub(crate) c: f32,
+    pub(crate) h: f32,
+}
+
+/// Pixels under this OKLCH chroma are greys, whites and blacks.
+const GREY_CHROMA: f32 = 0.03;
+
+/// Moves the mean color of the colored pixels to `color`, each pixel keeping its
+/// distance to the mean so the shading survives.
+fn shift(part: &mut RgbaImage, space: ColorSpace, color: Lch) {
+    let colored = |pixel: &Rgba<u8>| pixel[3] > 0 && !is_grey(rgb(pixel));
+    let (mut l, mut c, mut sin, mut cos, mut weight) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for pixel in part.pixels().filter(|pixel| colored(pixel)) {
+        let lch = to_lch(space, rgb(pixel));
+        let alpha = pixel[3] as f32 / 255.0;
+        l += lch.l * alpha;
+        c += lch.c * alpha;
+        sin += lch.h.to_radians().sin() * alpha;
+        cos += lch.h.to_radians().cos() * alpha;
+        weight += alpha;
+    }
+    if weight == 0.0 {
+        return;
+    }
+    let mean = Lch {
+        l: l / weight,
+        c: c / weight,
+        h: sin.atan2(cos).to_degrees(),
+    };
+
+    for pixel in part.pixels_mut().filter(|pixel| colored(pixel)) {
+        let lch = to_lch(space, rgb(pixel));
+        let shifted = Lch {
+            l: (lch.l + color.l - mean.l).clamp(0.0, 1.0),
+            c: match mean.c > 0.0 {
+                true => lch.c * color.c / mean.c,
+                false => color.c,
+            },
+            h: lch.h + color.h - mean.h,
+        };
+        let [r, g, b] = from_lch(space, shifted);
+        *pixel = Rgba([r, g, b, pixel[3]]);
+    }
+}
+
+fn rgb(pixel: &Rgba<u8>) -> [u8; 3] {
+    [pixel[0], pixel[1], pixel[2]]
+}
+
+fn is_grey(rgb: [u8; 3]) -> bool {
+    to_lch(ColorSpace::Oklch, rgb).c < GREY_CHROMA
+}
+
+fn lerp(range: &RangeInclusive<f32>, t: f32) -> f32 {
+    range.start() + (range.end() - range.start()) * t
+}
+
+/// Deterministic value from 0 to 1 spreading the lightness and chroma of the
+/// palette colors over the hues.
+fn fraction(hue: i32, salt: u32) -> f32 {
+    let mixed = (hue as u32)
+        .wrapping_add(salt.wrapping_mul(0x85eb_ca6b))
+        .wrapping_mul(0x9e37_79b9);
+    (mixed >> 16) as f32 / u16::MAX as f32
+}
+
+pub(crate) fn to_lch(space: ColorSpace, rgb: [u8; 3]) -> Lch {
+    match space {
+        ColorSpace::Oklch => {
+            let [l, a, b] = oklab(rgb);
+            Lch {
+                l,
+                c: (a * a + b * b).sqrt(),
+                h: b.atan2(a).to_degrees().rem_euclid(360.0),
+            }
+        }
+        ColorSpace::Hsl => hsl(rgb),
+    }
+}
+
+pub(crate) fn from_lch(space: ColorSpace, lch: Lch) -> [u8; 3] {
+    match space {
+        ColorSpace::Oklch => {
+            let h = lch.h.to_radians();
+            from_oklab([lch.l, lch.c * h.cos(), lch.c * h.sin()])
+        }
+        ColorSpace::Hsl => from_hsl(lch),
+    }
+}
+
+fn to_linear(channel: u8) -> f32 {
+    let channel = channel as f32 / 255.0;
+    match channel <= 0.04045 {
+        true => channel / 12.92,
+        false => ((channel + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+fn from_linear(channel: f32) -> u8 {
+    let channel = channel.clamp(0.0, 1.0);
+    let channel = match channel <= 0.003_130_8 {
+        true => channel * 12.92,
+        false => 1.055 * channel.powf(1.0 / 2.4) - 0.055,
+    };
+    (channel * 255.0).round() as u8
+}
+
+/// https://bottosson.github.io/posts/oklab/
+fn oklab(rgb: [u8; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(to_linear);
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn from_oklab(lab: [f32; 3]) -> [u8; 3] {
+    let [l, a, b] = lab;
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    [
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    ]
+    .map(from_linear)
+}
+
+fn hsl(rgb: [u8; 3]) -> Lch {
+    let [r, g, b] = rgb.map(|channel| channel as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return Lch { l, c: 0.0, h: 0.0 };
+    }
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = match max {
+        max if max == r => 60.0 * ((g - b) / delta).rem_euclid(6.0),
+        max if max == g => 60.0 * ((b - r) / delta + 2.0),
+        _ => 60.0 * ((r - g) / delta + 4.0),
+    };
+    Lch { l, c: s, h }
+}
+
+fn from_hsl(lch: Lch) -> [u8; 3] {
+    let Lch { l, c: s, h } = lch;
+    let (l, s, h) = (l.clamp(0.0, 1.0), s.clamp(0.0, 1.0), h.rem_euclid(360.0));
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match (h / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    [r, g, b].map(|channel| ((channel + m) * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(pixels: &[[u8; 4]]) -> Arc<RgbaImage> {
+        let mut image = RgbaImage::new(pixels.len() as u32, 1);
+        for (x, pixel) in pixels.iter().enumerate() {
+            image.put_pixel(x as u32, 0, Rgba(*pixel));
+        }
+        Arc::new(image)
+    }
+
+    fn hue(space: ColorSpace, pixel: &Rgba<u8>) -> f32 {
+        to_lch(space, rgb(pixel)).h
+    }
+
+    #[test]
+    fn colors_survive_a_round_trip_through_both_spaces() {
+        for rgb in [[255, 0, 0], [12, 200, 99], [250, 250, 250], [30, 60, 200]] {
+            assert_eq!(
+                from_lch(ColorSpace::Oklch, to_lch(ColorSpace::Oklch, rgb)),
+                rgb
+            );
+            assert_eq!(from_lch(ColorSpace::Hsl, to_lch(ColorSpace::Hsl, rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn palette_colors_stay_within_the_bounds() {
+        // arrange
+        let palette = Palette::oklch();
+        // act
+        let colors = (0..360)
+            .map(|hue| palette.color(hue, None))
+            .collect::<Vec<_>>();
+        // assert
+        assert!(colors
+            .iter()
+            .all(|color| palette.lightness.contains(&color.l)));
+        assert!(colors.iter().all(|color| palette.chroma.contains(&color.c)));
+        assert_eq!(colors[250].h, 250.0);
+    }
+
+    #[test]
+    fn recolor_shifts_the_colored_pixels_and_keeps_the_greys() {
+        // arrange
+        let palette = Palette::oklch();
+        let part = part(&[[200, 40, 40, 255], [120, 20, 20, 255], [128, 128, 128, 255]]);
+        // act
+        let recolored = palette.recolor(&part, "Body", 140, None);
+        // assert
+        let pixels = recolored.pixels().collect::<Vec<_>>();
+        for pixel in &pixels[..2] {
+            assert!((hue(ColorSpace::Oklch, pixel) - 140.0).abs() < 5.0);
+        }
+        assert!(
+            to_lch(ColorSpace::Oklch, rgb(pixels[0])).l
+                > to_lch(ColorSpace::Oklch, rgb(pixels[1])).l
+        );
+        assert_eq!(*pixels[2], Rgba([128, 128, 128, 255]));
+    }
+
+    #[test]
+    fn recolor_follows_the_layer_policies() {
+        // arrange
+        let mut palette = Palette::hsl();
+        palette
+            .policies
+            .push((Layer::Accessory, LayerPolicy::Complement));
+        let part = part(&[[200, 40, 40, 255]]);
+        // act
+        let eyes = palette.recolor(&part, "eyes", 90, None);
+        let accessory = palette.recolor(&part, "Accessory", 90, None);
+        // assert
+        assert_eq!(eyes, part);
+        assert!((hue(ColorSpace::Hsl, accessory.get_pixel(0, 0)) - 270.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn brand_colors_replace_the_picked_colors() {
+        // arrange
+        let palette = Palette {
+            colors: vec![[247, 147, 26], [0, 128, 255]],
+            ..Palette::hsl()
+        };
+        let part = part(&[[200, 40, 40, 255]]);
+        // act
+        let recolored = palette.recolor(&part, "Body", 3, Some(1));
+        // assert
+        assert_eq!(*recolored.get_pixel(0, 0), Rgba([0, 128, 255, 255]));
+    }
+}
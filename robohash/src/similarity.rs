@@ -0,0 +1,281 @@
+//! How alike two robots look, to find identities that could be mistaken for one
+//! another. Distances go from 0 for the same robot to 1.
+
+use image::imageops::{self, FilterType};
+use image::{Rgb, RgbImage, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::traits::RoboTraits;
+use crate::RoboHash;
+
+/// Weights of the traits in `trait_distance`, adding up to 1.
+const PARTS_WEIGHT: f32 = 0.4;
+const COLOR_WEIGHT: f32 = 0.3;
+const HUE_WEIGHT: f32 = 0.2;
+const BACKGROUND_WEIGHT: f32 = 0.1;
+
+/// Weight of the structure against the colors in `PerceptualHash::distance`.
+const STRUCTURE_WEIGHT: f32 = 0.6;
+
+/// Distance between the traits of two robots: the share of layers with another
+/// part, then the color, the hue, or the palette color when both have one, and the
+/// background. Robots of different sets are 1 apart.
+pub fn trait_distance(first: &RoboTraits, second: &RoboTraits) -> f32 {
+    if first.set != second.set {
+        return 1.0;
+    }
+    let layers = first.parts.len().max(second.parts.len()).max(1);
+    let different_parts = first
+        .parts
+        .iter()
+        .filter(|part| second.part(&part.layer) != Some(part.index))
+        .count()
+        + second.parts.len().saturating_sub(first.parts.len());
+    let parts = different_parts as f32 / layers as f32;
+    let color = (first.color.index != second.color.index) as u8 as f32;
+    let hue = match (first.hue, second.hue) {
+        _ if first.palette_color.is_some() && second.palette_color.is_some() => {
+            (first.palette_color != second.palette_color) as u8 as f32
+        }
+        (Some(first), Some(second)) => {
+            let degrees = (first - second).rem_euclid(360);
+            degrees.min(360 - degrees) as f32 / 180.0
+        }
+        (None, None) => 0.0,
+        _ => 1.0,
+    };
+    let background = (first.background != second.background
+        || first.background_color != second.background_color) as u8 as f32;
+    PARTS_WEIGHT * parts + COLOR_WEIGHT * color + HUE_WEIGHT * hue + BACKGROUND_WEIGHT * background
+}
+
+/// Fingerprint of a rendered robot: a difference hash of its shapes and the mean
+/// color of each quarter. Images looking alike have close hashes whatever their size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerceptualHash {
+    /// Whether each pixel of a 9x8 grayscale thumbnail is brighter than the next one.
+    pub structure: u64,
+    /// Mean RGB color of the top left, top right, bottom left and bottom right quarters,
+    /// mid grey for transparent quarters.
+    pub colors: [[u8; 3]; 4],
+}
+
+impl PerceptualHash {
+    /// Transparent pixels are seen over a mid grey.
+    pub fn from_image(image: &RgbaImage) -> Self {
+        let flat = RgbImage::from_fn(image.width(), image.height(), |x, y| {
+            let pixel = image.get_pixel(x, y);
+            let alpha = pixel[3] as f32 / 255.0;
+            let channel = |i: usize| (pixel[i] as f32 * alpha + 128.0 * (1.0 - alpha)) as u8;
+            Rgb([channel(0), channel(1), channel(2)])
+        });
+
+        let thumbnail = imageops::resize(&flat, 9, 8, FilterType::Triangle);
+        let luma = |x: u32, y: u32| {
+            let [r, g, b] = thumbnail.get_pixel(x, y).0;
+            0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32
+        };
+        let mut structure = 0;
+        for y in 0..8 {
+            for x in 0..8 {
+                structure = structure << 1 | (luma(x, y) > luma(x + 1, y)) as u64;
+            }
+        }
+
+        // Weighted by the alpha, so a robot over a transparent canvas gives its own colors
+        let (half_width, half_height) = (image.width() / 2, image.height() / 2);
+        let colors = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(column, row)| {
+            let mut sums = [0.0; 4];
+            for y in row * half_height..(row + 1) * half_height {
+                for x in column * half_width..(column + 1) * half_width {
+                    let pixel = image.get_pixel(x, y);
+                    let alpha = pixel[3] as f32;
+                    (0..3).for_each(|i| sums[i] += pixel[i] as f32 * alpha);
+                    sums[3] += alpha;
+                }
+            }
+            match sums[3] > 0.0 {
+                true => [0, 1, 2].map(|i| (sums[i] / sums[3]).round() as u8),
+                false => [128; 3],
+            }
+        });
+        Self { structure, colors }
+    }
+
+    pub fn distance(&self, other: &PerceptualHash) -> f32 {
+        let structure = (self.structure ^ other.structure).count_ones() as f32 / 64.0;
+        let colors = self
+            .colors
+            .iter()
+            .zip(&other.colors)
+            .map(|(first, second)| {
+                let squares = first
+                    .iter()
+                    .zip(second)
+                    .map(|(a, b)| (*a as f32 - *b as f32).powi(2))
+                    .sum::<f32>();
+                squares.sqrt() / (255.0 * 3f32.sqrt())
+            })
+            .sum::<f32>()
+            / 4.0;
+        STRUCTURE_WEIGHT * structure + (1.0 - STRUCTURE_WEIGHT) * colors
+    }
+}
+
+/// Two robots of an audit looking alike.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarPair {
+    /// Indices of the robots in the audited list, `first < second`.
+    pub first: usize,
+    pub second: usize,
+    pub trait_distance: f32,
+    pub pixel_distance: f32,
+}
+
+impl SimilarPair {
+    /// The closest of both distances.
+    pub fn distance(&self) -> f32 {
+        self.trait_distance.min(self.pixel_distance)
+    }
+}
+
+/// Pairs of `robots` whose traits or rendered images are at most `threshold`
+/// apart, closest first. Robots are rendered at their size, build them small to
+/// audit long lists faster.
+pub fn audit(robots: &[RoboHash], threshold: f32) -> Result<Vec<SimilarPair>, Error> {
+    let fingerprints = robots
+        .iter()
+        .map(|robo_hash| Ok((robo_hash.traits()?, robo_hash.perceptual_hash()?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut pairs = Vec::new();
+    for (first, (first_traits, first_hash)) in fingerprints.iter().enumerate() {
+        for (second, (second_traits, second_hash)) in
+            fingerprints.iter().enumerate().skip(first + 1)
+        {
+            let pair = SimilarPair {
+                first,
+                second,
+                trait_distance: trait_distance(first_traits, second_traits),
+                pixel_distance: first_hash.distance(second_hash),
+            };
+            if pair.distance() <= threshold {
+                pairs.push(pair);
+            }
+        }
+    }
+    pairs.sort_by(|a, b| a.distance().total_cmp(&b.distance()));
+    Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgba;
+
+    use crate::algorithm::AlgorithmVersion;
+    use crate::catalog::RoboSet;
+    use crate::traits::{ColorTrait, PartTrait};
+    use crate::upstream::Compatibility;
+    use crate::RoboHashBuilder;
+
+    use super::*;
+
+    fn traits() -> RoboTraits {
+        RoboTraits {
+            compatibility: Compatibility::Robosats,
+            version: AlgorithmVersion::V1,
+            set: RoboSet::Set1,
+            color: ColorTrait {
+                index: 2,
+                name: String::from("green"),
+            },
+            parts: ["Body", "Face", "Mouth", "Eyes"]
+                .iter()
+                .map(|layer| PartTrait {
+                    layer: String::from(*layer),
+                    index: 1,
+                })
+                .collect(),
+            background: Some(3),
+            background_color: None,
+            hue: Some(350),
+            palette_color: None,
+        }
+    }
+
+    #[test]
+    fn trait_distance_weighs_each_trait() {
+        // arrange
+        let mut one_part = traits();
+        one_part.parts[3].index = 2;
+        let mut color = traits();
+        color.color.index = 4;
+        let mut hue = traits();
+        hue.hue = Some(10);
+        let mut palette_color = traits();
+        palette_color.palette_color = Some(1);
+        let mut set = traits();
+        set.set = RoboSet::Set2;
+        // act
+        let distance = |other: &RoboTraits| trait_distance(&traits(), other);
+        // assert
+        assert_eq!(distance(&traits()), 0.0);
+        assert_eq!(distance(&one_part), 0.1);
+        assert_eq!(distance(&color), 0.3);
+        assert!((distance(&hue) - 0.2 * 20.0 / 180.0).abs() < 1e-6);
+        assert_eq!(
+            trait_distance(
+                &RoboTraits {
+                    palette_color: Some(0),
+                    ..hue
+                },
+                &palette_color
+            ),
+            0.2
+        );
+        assert_eq!(distance(&set), 1.0);
+    }
+
+    #[test]
+    fn perceptual_hash_ignores_the_size_and_sees_the_colors() {
+        // arrange
+        let robot = |size: u32, color: [u8; 4]| {
+            RgbaImage::from_fn(size, size, |x, _| match x < size / 2 {
+                true => Rgba(color),
+                false => Rgba([255, 255, 255, 255]),
+            })
+        };
+        // act
+        let red = PerceptualHash::from_image(&robot(64, [255, 0, 0, 255]));
+        let big_red = PerceptualHash::from_image(&robot(256, [255, 0, 0, 255]));
+        let blue = PerceptualHash::from_image(&robot(64, [0, 0, 255, 255]));
+        // assert
+        assert_eq!(red.distance(&big_red), 0.0);
+        assert_eq!(red.structure, blue.structure);
+        assert!(red.distance(&blue) > 0.1);
+    }
+
+    #[test]
+    fn audit_reports_look_alikes_closest_first() {
+        // arrange
+        let robots = ["alice", "bob", "alice", "carol"]
+            .iter()
+            .map(|text| RoboHashBuilder::new(text).with_size(32, 32).build())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        // act
+        let pairs = audit(&robots, 0.05).unwrap();
+        // assert
+        assert_eq!(
+            pairs,
+            vec![SimilarPair {
+                first: 0,
+                second: 2,
+                trait_distance: 0.0,
+                pixel_distance: 0.0,
+            }]
+        );
+        assert_eq!(audit(&robots, 1.0).unwrap().len(), 6);
+    }
+}
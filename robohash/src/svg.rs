@@ -0,0 +1,226 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use image::RgbaImage;
+
+use crate::effects::Effect;
+use crate::error::Error;
+use crate::image::{build_robo_hash_image, to_base_64};
+use crate::layout::{Layout, Placement};
+use crate::mask::Mask;
+
+pub(crate) fn build_robo_hash_svg(
+    layer_names: &[String],
+    robo_parts: &[Arc<RgbaImage>],
+    background: &Option<Arc<RgbaImage>>,
+    layout: &Layout,
+    hue_rotation: &Option<i32>,
+    effects: &[Arc<dyn Effect>],
+    mask: &Option<Mask>,
+) -> Result<String, Error> {
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        layout.width, layout.height
+    )?;
+
+    // Effects work on pixels, the parts are baked into one image with them and the hue
+    let robot = match effects.is_empty() {
+        true => None,
+        false => Some(build_robo_hash_image(
+            robo_parts,
+            &None,
+            layout,
+            hue_rotation,
+            effects,
+        )?),
+    };
+
+    // Same matrix as `huerotate_in_place`, applied to the sRGB values like the raster output
+    let filter = match hue_rotation.filter(|_| robot.is_none()) {
+        Some(hue) => {
+            let id = format!("robohash-hue-{}", hue);
+            writeln!(
+                svg,
+                r#"<defs><filter id="{}" color-interpolation-filters="sRGB"><feColorMatrix type="hueRotate" values="{}"/></filter></defs>"#,
+                id, hue
+            )?;
+            format!(r#" filter="url(#{})""#, id)
+        }
+        None => String::new(),
+    };
+
+    if let Some(mask) = mask {
+        writeln!(
+            svg,
+            r#"<defs><clipPath id="robohash-mask">{}</clipPath></defs>"#,
+            mask.svg_element(layout.width, layout.height, 0.0, "")
+        )?;
+        writeln!(svg, r#"<g class="mask" clip-path="url(#robohash-mask)">"#)?;
+    }
+    if let Some(background) = background {
+        write_layer(&mut svg, "background", background, &layout.background)?;
+    }
+    writeln!(svg, r#"<g class="parts"{}>"#, filter)?;
+    match &robot {
+        Some(robot) => {
+            let canvas = Placement {
+                x: 0,
+                y: 0,
+                width: layout.width,
+                height: layout.height,
+            };
+            write_layer(&mut svg, "robot", robot, &canvas)?;
+        }
+        None => {
+            for (name, part) in layer_names.iter().zip(robo_parts) {
+                write_layer(&mut svg, name, part, &layout.parts)?;
+            }
+        }
+    }
+    writeln!(svg, "</g>")?;
+    if let Some(mask) = mask {
+        writeln!(svg, "</g>")?;
+        // Stroke centered half its width inside the edge, like the raster border
+        if let Some(border) = &mask.border {
+            let [r, g, b, a] = border.color;
+            let attributes = format!(
+                r#" class="border" fill="none" stroke="rgb({},{},{})" stroke-opacity="{}" stroke-width="{}""#,
+                r,
+                g,
+                b,
+                a as f32 / 255.0,
+                border.width
+            );
+            writeln!(
+                svg,
+                "{}",
+                mask.svg_element(layout.width, layout.height, border.width / 2.0, &attributes)
+            )?;
+        }
+    }
+    svg.push_str("</svg>\n");
+    Ok(svg)
+}
+
+fn write_layer(
+    svg: &mut String,
+    name: &str,
+    image: &RgbaImage,
+    placement: &Placement,
+) -> Result<(), Error> {
+    let png = to_base_64(image)?;
+    writeln!(
+        svg,
+        r#"<image class="{}" x="{}" y="{}" width="{}" height="{}" href="data:image/png;base64,{}"/>"#,
+        class_name(name),
+        placement.x,
+        placement.y,
+        placement.width,
+        placement.height,
+        png
+    )?;
+    Ok(())
+}
+
+/// Layer names come from asset packs, keep them usable as CSS classes.
+fn class_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgba;
+
+    use crate::effects::Outline;
+    use crate::layout::SizeMode;
+    use crate::mask::Shape;
+
+    use super::*;
+
+    fn layout() -> Layout {
+        Layout::new(8, 8, SizeMode::Stretch, 1.0)
+    }
+
+    fn part(color: [u8; 4]) -> Arc<RgbaImage> {
+        Arc::new(RgbaImage::from_pixel(8, 8, Rgba(color)))
+    }
+
+    #[test]
+    fn build_robo_hash_svg_has_one_image_per_layer_and_the_background_first() {
+        // arrange
+        let names = vec![String::from("Body"), String::from("Eyes")];
+        let parts = vec![part([255, 0, 0, 255]), part([0, 0, 255, 128])];
+        let background = Some(part([9, 9, 9, 255]));
+        // act
+        let svg =
+            build_robo_hash_svg(&names, &parts, &background, &layout(), &None, &[], &None).unwrap();
+        // assert
+        let classes = svg
+            .match_indices(r#"<image class=""#)
+            .map(|(i, m)| svg[i + m.len()..].split('"').next().unwrap())
+            .collect::<Vec<&str>>();
+        assert_eq!(classes, vec!["background", "body", "eyes"]);
+        assert!(!svg.contains("filter"));
+    }
+
+    #[test]
+    fn build_robo_hash_svg_expresses_the_hue_rotation_as_a_filter() {
+        // arrange
+        let names = vec![String::from("Body")];
+        let parts = vec![part([255, 0, 0, 255])];
+        // act
+        let svg =
+            build_robo_hash_svg(&names, &parts, &None, &layout(), &Some(90), &[], &None).unwrap();
+        // assert
+        assert!(svg.contains(r#"<feColorMatrix type="hueRotate" values="90"/>"#));
+        assert!(svg.contains(r#"<g class="parts" filter="url(#robohash-hue-90)">"#));
+    }
+
+    #[test]
+    fn build_robo_hash_svg_clips_to_the_mask_and_strokes_its_border() {
+        // arrange
+        let names = vec![String::from("Body")];
+        let parts = vec![part([255, 0, 0, 255])];
+        let mask = Mask::new(Shape::Circle).with_border([0, 0, 255, 255], 2.0);
+        // act
+        let svg =
+            build_robo_hash_svg(&names, &parts, &None, &layout(), &None, &[], &Some(mask)).unwrap();
+        // assert
+        assert!(svg.contains(
+            r#"<defs><clipPath id="robohash-mask"><circle cx="4" cy="4" r="4"/></clipPath></defs>"#
+        ));
+        assert!(svg.contains(r#"<g class="mask" clip-path="url(#robohash-mask)">"#));
+        assert!(svg.contains(
+            r#"<circle cx="4" cy="4" r="3" class="border" fill="none" stroke="rgb(0,0,255)" stroke-opacity="1" stroke-width="2"/>"#
+        ));
+    }
+
+    #[test]
+    fn build_robo_hash_svg_bakes_the_parts_with_the_effects() {
+        // arrange
+        let names = vec![String::from("Body"), String::from("Eyes")];
+        let parts = vec![part([255, 0, 0, 255]), part([0, 0, 255, 128])];
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Outline {
+            color: [0, 0, 0, 255],
+            width: 1.0,
+        })];
+        // act
+        let svg = build_robo_hash_svg(&names, &parts, &None, &layout(), &Some(90), &effects, &None)
+            .unwrap();
+        // assert
+        assert!(svg.contains(r#"<g class="parts">"#));
+        assert!(svg.contains(r#"<image class="robot" x="0" y="0" width="8" height="8""#));
+        assert!(!svg.contains(r#"<image class="body""#));
+        assert!(!svg.contains("filter"));
+    }
+
+    #[test]
+    fn class_name_replaces_characters_css_does_not_accept() {
+        assert_eq!(class_name("Left Arm#2"), "left-arm-2");
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt;
+use std::str::FromStr;
+
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE};
+
+use crate::catalog::{RoboSet, SetCatalog};
+use crate::error::Error;
+use crate::traits::RoboTraits;
+
+const VERSION: &str = "RH1";
+
+/// Traits of a robot written as `RH1-set1-c7-b3f7m2e9a4-bg12-h250`: the version,
+/// the set, the color, the part of each layer in stacking order after the initial
+/// of the layer, then the background (`bg<index>`, or `s<rrggbbaa>` for a solid
+/// color), the hue and the palette color (`p<index>`) when the robot has them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TraitCode {
+    pub(crate) set: RoboSet,
+    pub(crate) color: usize,
+    pub(crate) parts: Vec<(char, usize)>,
+    pub(crate) background: Option<usize>,
+    pub(crate) background_color: Option<[u8; 4]>,
+    pub(crate) hue: Option<i32>,
+    pub(crate) palette_color: Option<usize>,
+}
+
+impl TraitCode {
+    pub(crate) fn from_traits(traits: &RoboTraits) -> Self {
+        Self {
+            set: traits.set,
+            color: traits.color.index,
+            parts: traits
+                .parts
+                .iter()
+                .map(|part| (initial(&part.layer), part.index))
+                .collect(),
+            background: traits.background,
+            background_color: traits.background_color,
+            hue: traits.hue,
+            palette_color: traits.palette_color,
+        }
+    }
+
+    /// Fails unless the code has one part per layer of the set, in stacking order.
+    pub(crate) fn validate_layers(&self, set_catalog: &SetCatalog) -> Result<(), Error> {
+        let initials = set_catalog
+            .layers
+            .iter()
+            .map(|layer| initial(&layer.name))
+            .collect::<String>();
+        let code_initials = self
+            .parts
+            .iter()
+            .map(|(initial, _)| initial)
+            .collect::<String>();
+        if initials != code_initials {
+            return Err(Error::InvalidTraitCode(format!(
+                "expected the layers \"{}\" of {}, got \"{}\"",
+                initials, self.set, code_initials
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn initial(layer: &str) -> char {
+    layer
+        .chars()
+        .next()
+        .map_or('x', |initial| initial.to_ascii_lowercase())
+}
+
+impl fmt::Display for TraitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-c{}-", VERSION, self.set, self.color)?;
+        for (initial, index) in &self.parts {
+            write!(f, "{}{}", initial, index)?;
+        }
+        if let Some(background) = self.background {
+            write!(f, "-bg{}", background)?;
+        }
+        if let Some(color) = self.background_color {
+            write!(f, "-s{}", HEXLOWER.encode(&color))?;
+        }
+        if let Some(hue) = self.hue {
+            write!(f, "-h{}", hue)?;
+        }
+        if let Some(index) = self.palette_color {
+            write!(f, "-p{}", index)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TraitCode {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidTraitCode(reason);
+        let mut segments = code.trim().split('-');
+
+        let version = segments.next().unwrap_or_default();
+        if !version.eq_ignore_ascii_case(VERSION) {
+            return Err(invalid(format!(
+                "unsupported version \"{}\", expected {}",
+                version, VERSION
+            )));
+        }
+        let set = segments.next().unwrap_or_default();
+        let set = RoboSet::from_str(&set.to_ascii_lowercase())
+            .ok()
+            .filter(|set| *set != RoboSet::Any)
+            .ok_or_else(|| invalid(format!("unknown set \"{}\"", set)))?;
+        let color = segments.next().unwrap_or_default();
+        let color =
+            number(color, "c").ok_or_else(|| invalid(format!("bad color \"{}\"", color)))?;
+        let parts = segments.next().unwrap_or_default();
+        let parts =
+            parse_parts(parts).ok_or_else(|| invalid(format!("bad parts \"{}\"", parts)))?;
+
+        let mut background = None;
+        let mut background_color = None;
+        let mut hue = None;
+        let mut palette_color = None;
+        let mut segment = segments.next();
+        if let Some(index) = segment.and_then(|segment| number(segment, "bg")) {
+            background = Some(index);
+            segment = segments.next();
+        } else if let Some(color) = segment.and_then(solid_color) {
+            background_color = Some(color);
+            segment = segments.next();
+        }
+        if let Some(degrees) = segment.and_then(|segment| number(segment, "h")) {
+            if degrees >= 360 {
+                return Err(invalid(format!("hue {} out of 0 to 359", degrees)));
+            }
+            hue = Some(degrees as i32);
+            segment = segments.next();
+        }
+        if let Some(index) = segment.and_then(|segment| number(segment, "p")) {
+            palette_color = Some(index);
+            segment = segments.next();
+        }
+        if let Some(segment) = segment {
+            return Err(invalid(format!("unexpected \"{}\"", segment)));
+        }
+
+        Ok(Self {
+            set,
+            color,
+            parts,
+            background,
+            background_color,
+            hue,
+            palette_color,
+        })
+    }
+}
+
+/// `s` followed by the RGBA color in hex, e.g. `sff8800ff`.
+fn solid_color(segment: &str) -> Option<[u8; 4]> {
+    let hex = segment.strip_prefix(['s', 'S'])?;
+    HEXLOWER_PERMISSIVE
+        .decode(hex.as_bytes())
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+/// `prefix` followed by a decimal number, e.g. `bg12`.
+fn number(segment: &str, prefix: &str) -> Option<usize> {
+    let digits = segment
+        .get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &segment[prefix.len()..])?;
+    match !digits.is_empty() && digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// `b3f7m2` into `[('b', 3), ('f', 7), ('m', 2)]`.
+fn parse_parts(segment: &str) -> Option<Vec<(char, usize)>> {
+    let mut parts = Vec::new();
+    let mut rest = segment;
+    while let Some(initial) = rest.chars().next() {
+        if !initial.is_ascii_alphabetic() {
+            return None;
+        }
+        let digits = rest[1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |end| end + 1);
+        parts.push((initial.to_ascii_lowercase(), rest[1..digits].parse().ok()?));
+        rest = &rest[digits..];
+    }
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code() -> TraitCode {
+        TraitCode {
+            set: RoboSet::Set1,
+            color: 7,
+            parts: vec![('b', 3), ('f', 7), ('m', 2), ('e', 9), ('a', 10)],
+            background: Some(12),
+            background_color: None,
+            hue: Some(250),
+            palette_color: None,
+        }
+    }
+
+    #[test]
+    fn trait_codes_are_written_and_parsed_back() {
+        assert_eq!(code().to_string(), "RH1-set1-c7-b3f7m2e9a10-bg12-h250");
+        assert_eq!(
+            TraitCode::from_str("RH1-set1-c7-b3f7m2e9a10-bg12-h250").unwrap(),
+            code()
+        );
+    }
+
+    #[test]
+    fn background_and_hue_are_optional() {
+        // act
+        let code = TraitCode::from_str("rh1-SET1-c7-b3f7m2e9a10-h250").unwrap();
+        // assert
+        assert_eq!(code.background, None);
+        assert_eq!(code.hue, Some(250));
+        assert_eq!(code.to_string(), "RH1-set1-c7-b3f7m2e9a10-h250");
+    }
+
+    #[test]
+    fn solid_backgrounds_are_written_as_colors() {
+        // arrange
+        let code = TraitCode {
+            background: None,
+            background_color: Some([255, 136, 0, 255]),
+            ..code()
+        };
+        // act
+        let written = code.to_string();
+        // assert
+        assert_eq!(written, "RH1-set1-c7-b3f7m2e9a10-sff8800ff-h250");
+        assert_eq!(TraitCode::from_str(&written).unwrap(), code);
+    }
+
+    #[test]
+    fn palette_colors_follow_the_hue() {
+        // arrange
+        let code = TraitCode {
+            palette_color: Some(2),
+            ..code()
+        };
+        // act
+        let written = code.to_string();
+        // assert
+        assert_eq!(written, "RH1-set1-c7-b3f7m2e9a10-bg12-h250-p2");
+        assert_eq!(TraitCode::from_str(&written).unwrap(), code);
+    }
+
+    #[test]
+    fn malformed_trait_codes_are_rejected() {
+        let error = |code: &str| TraitCode::from_str(code).unwrap_err().to_string();
+        assert_eq!(
+            error("RH2-set1-c7-b3"),
+            "invalid trait code: unsupported version \"RH2\", expected RH1"
+        );
+        assert_eq!(
+            error("RH1-set9-c7-b3"),
+            "invalid trait code: unknown set \"set9\""
+        );
+        assert_eq!(
+            error("RH1-set1-7-b3"),
+            "invalid trait code: bad color \"7\""
+        );
+        assert_eq!(
+            error("RH1-set1-c7-b3f"),
+            "invalid trait code: bad parts \"b3f\""
+        );
+        assert_eq!(
+            error("RH1-set1-c7-b3-h360"),
+            "invalid trait code: hue 360 out of 0 to 359"
+        );
+        assert_eq!(
+            error("RH1-set1-c7-b3-h25-bg1"),
+            "invalid trait code: unexpected \"bg1\""
+        );
+    }
+}
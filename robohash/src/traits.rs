@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::algorithm::AlgorithmVersion;
+use crate::catalog::RoboSet;
+use crate::upstream::Compatibility;
+
+/// What a robot is made of. Two robots with equal traits render the same image
+/// from the same art, size and output format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoboTraits {
+    /// Implementation the traits were picked like.
+    pub compatibility: Compatibility,
+    /// Version of the RoboSats selection algorithm.
+    pub version: AlgorithmVersion,
+    pub set: RoboSet,
+    pub color: ColorTrait,
+    /// One part per layer, in stacking order.
+    pub parts: Vec<PartTrait>,
+    /// Index of the background, `None` without background or with another
+    /// background than the provider ones.
+    pub background: Option<usize>,
+    /// RGBA color of solid and derived backgrounds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<[u8; 4]>,
+    /// Hue rotation in degrees, `None` without hue rotation.
+    pub hue: Option<i32>,
+    /// Index of the color picked among the `Palette::colors` of a palette
+    /// recoloring, `None` without them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub palette_color: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColorTrait {
+    pub index: usize,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTrait {
+    /// Name of the layer, `Body`, `Face`, `Mouth`, `Eyes` or `Accessory` for set1.
+    pub layer: String,
+    /// Index of the part among the parts of the layer in the robot color.
+    pub index: usize,
+}
+
+impl RoboTraits {
+    /// Part of the layer named `layer`, ignoring case.
+    pub fn part(&self, layer: &str) -> Option<usize> {
+        self.parts
+            .iter()
+            .find(|part| part.layer.eq_ignore_ascii_case(layer))
+            .map(|part| part.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn traits() -> RoboTraits {
+        RoboTraits {
+            compatibility: Compatibility::Robosats,
+            version: AlgorithmVersion::V1,
+            set: RoboSet::Set1,
+            color: ColorTrait {
+                index: 2,
+                name: String::from("green"),
+            },
+            parts: vec![
+                PartTrait {
+                    layer: String::from("Body"),
+                    index: 4,
+                },
+                PartTrait {
+                    layer: String::from("Eyes"),
+                    index: 7,
+                },
+            ],
+            background: Some(3),
+            background_color: None,
+            hue: None,
+            palette_color: None,
+        }
+    }
+
+    #[test]
+    fn part_finds_the_layer_ignoring_case() {
+        assert_eq!(traits().part("eyes"), Some(7));
+        assert_eq!(traits().part("Mouth"), None);
+    }
+
+    #[test]
+    fn robo_traits_are_serialized_with_lowercase_names() {
+        // act
+        let json = serde_json::to_value(traits()).unwrap();
+        // assert
+        assert_eq!(json["compatibility"], "robosats");
+        assert_eq!(json["version"], "v1");
+        assert_eq!(json["set"], "set1");
+        assert_eq!(json["parts"][1]["layer"], "Eyes");
+        assert_eq!(
+            serde_json::from_value::<RoboTraits>(json).unwrap(),
+            traits()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::{RoboSet, SetCatalog};
+use crate::error::Error;
+
+/// Implementation the set, color, parts and background are picked like.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    /// RoboSats robots, the selection this crate always used.
+    #[default]
+    Robosats,
+    /// e1ven's Python Robohash, the robohash.org robots: given the same art, picks
+    /// the same set, color, parts and background. Like upstream, an image extension
+    /// ending the text (`bear.png`) is not hashed.
+    Upstream,
+}
+
+// Slots of the hash array, upstream reserves the first 4 and picks the parts after them
+const COLOR: usize = 0;
+const SET: usize = 1;
+const BACKGROUND_SET: usize = 2;
+const BACKGROUND: usize = 3;
+const FIRST_PART: usize = 4;
+
+const EXTENSIONS: [&str; 7] = [".png", ".gif", ".jpg", ".bmp", ".jpeg", ".ppm", ".datauri"];
+
+pub(crate) fn remove_extension(text: &str) -> &str {
+    let lowercase = text.to_lowercase();
+    match EXTENSIONS.iter().any(|ext| lowercase.ends_with(ext)) {
+        true => text.rfind('.').map_or(text, |dot| &text[..dot]),
+        false => text,
+    }
+}
+
+/// Upstream lists the sets by directory name.
+pub(crate) fn select_set(
+    set: &RoboSet,
+    hash_array: &[i64],
+    sets: &[SetCatalog],
+) -> Result<RoboSet, Error> {
+    match set {
+        RoboSet::Any => {
+            let mut available = sets.iter().map(|catalog| catalog.set).collect::<Vec<_>>();
+            if available.is_empty() {
+                return Err(Error::RoboSetUnavailable(set.to_string()));
+            }
+            available.sort_by_key(|set| set.to_string());
+            let i = (hash_array[SET] % available.len() as i64) as usize;
+            Ok(available[i])
+        }
+        _ => Ok(crate::catalog::find(sets, *set)?.set),
+    }
+}
+
+pub(crate) fn select_color(hash_array: &[i64], set_catalog: &SetCatalog) -> usize {
+    (hash_array[COLOR] % set_catalog.colors.len() as i64) as usize
+}
+
+/// Upstream picks the layers in directory order and stacks them afterwards, so
+/// each layer takes the slot of its sort order rather than of its stacking order.
+pub(crate) fn select_robot_parts(
+    hash_array: &[i64],
+    set_catalog: &SetCatalog,
+    color: usize,
+) -> Vec<usize> {
+    let sort_order = |layer: usize| {
+        let sort_order = set_catalog.layers[layer].sort_order;
+        (sort_order.unwrap_or(layer as u32), layer)
+    };
+    (0..set_catalog.layers.len())
+        .map(|layer| {
+            let rank = (0..set_catalog.layers.len())
+                .filter(|other| sort_order(*other) < sort_order(layer))
+                .count();
+            let length = set_catalog.layer_count(layer, color) as i64;
+            (hash_array[FIRST_PART + rank] % length) as usize
+        })
+        .collect()
+}
+
+/// Picks a background set, then a background of that set.
+pub(crate) fn select_background(hash_array: &[i64], background_sets: &[usize]) -> Option<usize> {
+    let background_sets = background_sets
+        .iter()
+        .copied()
+        .filter(|count| *count > 0)
+        .collect::<Vec<usize>>();
+    if background_sets.is_empty() {
+        return None;
+    }
+    let set = (hash_array[BACKGROUND_SET] % background_sets.len() as i64) as usize;
+    let background = (hash_array[BACKGROUND] % background_sets[set] as i64) as usize;
+    Some(background_sets[..set].iter().sum::<usize>() + background)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::LayerCatalog;
+
+    use super::*;
+
+    #[test]
+    fn remove_extension_strips_the_image_extensions_upstream_strips() {
+        assert_eq!(remove_extension("Bear.PNG"), "Bear");
+        assert_eq!(remove_extension("bear.jpeg"), "bear");
+        assert_eq!(remove_extension("bear.txt"), "bear.txt");
+        assert_eq!(remove_extension("bear"), "bear");
+    }
+
+    #[test]
+    fn select_robot_parts_uses_the_slots_of_the_sort_order() {
+        // arrange
+        let layer = |name: &str, sort_order: u32| LayerCatalog {
+            name: String::from(name),
+            counts: vec![100],
+            sort_order: Some(sort_order),
+        };
+        let set_catalog = SetCatalog {
+            set: RoboSet::Set1,
+            colors: vec![String::from("blue")],
+            layers: vec![layer("Body", 3), layer("Face", 4), layer("Mouth", 0)],
+        };
+        let hash_array = vec![0, 0, 0, 0, 10, 11, 12, 13, 14];
+        // act
+        let parts = select_robot_parts(&hash_array, &set_catalog, 0);
+        // assert
+        assert_eq!(parts, vec![11, 12, 10]);
+    }
+
+    #[test]
+    fn select_background_picks_a_background_set_first() {
+        // arrange
+        let hash_array = vec![0, 0, 3, 5];
+        // act
+        let background = select_background(&hash_array, &[14, 7]);
+        // assert
+        assert_eq!(background, Some(14 + 5));
+    }
+}
@@ -0,0 +1,77 @@
+"""Regenerates corpus.json, the parts upstream Robohash picks for the art in this crate.
+
+Runs the upstream `robohash` package (https://github.com/e1ven/Robohash, MIT license),
+at the version pinned in requirements.txt, with its art directories pointed at the
+ones of this crate, and records the images it opens while assembling each robot.
+`bgset="any"` stands for the crate's `with_background(&true)`.
+
+    python3 -m pip install -r test_resources/upstream/requirements.txt
+    python3 test_resources/upstream/generate_corpus.py
+"""
+
+import json
+import os
+
+from PIL import Image
+from robohash import Robohash
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+ROOT = os.path.normpath(os.path.join(HERE, "..", "..")) + "/"
+INPUTS = [
+    "reckless",
+    "test",
+    "bear",
+    "Bear.png",
+    "bear.JPG",
+    "robosats",
+    "satoshi",
+    "nakamoto",
+    "alice@example.com",
+    "bob",
+    "ñandú",
+    "🤖",
+    "12345",
+    "Sound of the moon",
+    "x" * 100,
+    "",
+]
+
+
+def pick(text):
+    robot = Robohash(text)
+    robot.resourcedir = ROOT
+    robot.sets = robot._listdirs(ROOT + "sets")
+    robot.bgsets = robot._listdirs(ROOT + "backgrounds")
+    robot.colors = robot._listdirs(ROOT + "sets/set1")
+
+    opened = []
+    open_image = Image.open
+
+    def record(path, *args, **kwargs):
+        opened.append(os.path.relpath(path, ROOT).replace(os.sep, "/"))
+        return open_image(path, *args, **kwargs)
+
+    Image.open = record
+    try:
+        robot.assemble(bgset="any")
+    finally:
+        Image.open = open_image
+
+    # The first part is opened twice, as the canvas and as a layer
+    parts = list(dict.fromkeys(path for path in opened if path.startswith("sets/")))
+    backgrounds = [path for path in opened if path.startswith("backgrounds/")]
+    _, roboset, color, _ = parts[0].split("/", 3)
+    return {
+        "text": text,
+        "set": roboset,
+        "color": color,
+        "parts": [part.split("/", 3)[3] for part in parts],
+        "background": backgrounds[0][len("backgrounds/") :] if backgrounds else None,
+    }
+
+
+if __name__ == "__main__":
+    corpus = [pick(text) for text in INPUTS]
+    with open(os.path.join(HERE, "corpus.json"), "w", encoding="utf-8") as file:
+        json.dump(corpus, file, ensure_ascii=False, indent=2)
+        file.write("\n")
@@ -5,6 +5,7 @@ use num::{BigInt, Num, ToPrimitive};
 
 use data_encoding::HEXLOWER;
 use ring::digest::{Context, SHA256};
+use std::fmt;
 use std::io::Error;
 use std::io::{BufReader, Read};
 
@@ -25,7 +26,47 @@ pub(crate) fn sha256_digest(string: String) -> Result<String, Error> {
     Ok(HEXLOWER.encode(digest.as_ref()))
 }
 
+/// How the nickname is picked from the hash. Versions are frozen: a version gives
+/// the same nickname for a hash in every release, held by the golden vectors in
+/// `test_resources/golden`. Other dictionaries or picks come as new versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NicknameVersion {
+    /// Adjective, noun and number scaled from the hash. RoboSats nicknames.
+    V1,
+}
+
+impl NicknameVersion {
+    /// Latest frozen version, the default.
+    pub const LATEST: NicknameVersion = NicknameVersion::V1;
+    /// Every frozen version, oldest first.
+    pub const ALL: [NicknameVersion; 1] = [NicknameVersion::V1];
+}
+
+impl Default for NicknameVersion {
+    fn default() -> Self {
+        NicknameVersion::LATEST
+    }
+}
+
+impl fmt::Display for NicknameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NicknameVersion::V1 => f.write_str("v1"),
+        }
+    }
+}
+
 pub fn generate_nickname(hex_str: &str) -> String {
+    generate_nickname_with_version(hex_str, NicknameVersion::default())
+}
+
+pub fn generate_nickname_with_version(hex_str: &str, version: NicknameVersion) -> String {
+    match version {
+        NicknameVersion::V1 => generate_nickname_v1(hex_str),
+    }
+}
+
+fn generate_nickname_v1(hex_str: &str) -> String {
     let max_num = 999;
 
     let num_adj = BigInt::from(dicts::ADJECTIVES.len());
@@ -68,18 +109,26 @@ pub fn generate_nickname(hex_str: &str) -> String {
 }
 
 pub fn generate_short_nickname(hex_str: &str) -> Result<String, Error> {
+    generate_short_nickname_with_version(hex_str, NicknameVersion::default())
+}
+
+/// Rehashes until the nickname fits in 18 characters.
+pub fn generate_short_nickname_with_version(
+    hex_str: &str,
+    version: NicknameVersion,
+) -> Result<String, Error> {
     let max_iters = 10000;
     let max_length = 18;
     let mut i = 0;
     let mut hash = hex_str.to_owned();
-    let mut nick = generate_nickname(&hash);
+    let mut nick = generate_nickname_with_version(&hash, version);
     while i < max_iters {
         if nick.len() <= max_length {
             return Ok(nick);
         } else {
             let string = hash.to_owned() + "42";
             hash = sha256_digest(string)?;
-            nick = generate_nickname(&hash);
+            nick = generate_nickname_with_version(&hash, version);
             i += 1;
         }
     }
@@ -91,7 +140,7 @@ pub mod android {
     use jni::sys::jstring;
     use jni::JNIEnv;
 
-    use crate::generate_short_nickname;
+    use crate::{generate_short_nickname_with_version, NicknameVersion};
 
     #[no_mangle]
     pub extern "system" fn Java_com_robosats_RoboIdentities_nativeGenerateRoboname<'local>(
@@ -105,7 +154,7 @@ pub mod android {
             .expect("Couldn't get java string!")
             .into();
         let string: &str = initial_string.as_str();
-        let nickname = generate_short_nickname(string);
+        let nickname = generate_short_nickname_with_version(string, NicknameVersion::V1);
         match nickname {
             Ok(nick) => {
                 let output = env.new_string(nick).expect("Couldn't create java string!");
@@ -449,4 +498,45 @@ mod tests {
             "MalleablePorch278"
         );
     }
+
+    #[test]
+    fn test_every_version_keeps_its_golden_vectors() {
+        for version in NicknameVersion::ALL {
+            let location = format!("./test_resources/golden/{}.txt", version);
+            let vectors = std::fs::read_to_string(&location)
+                .unwrap_or_else(|_| panic!("{} is missing, see write_golden_vectors", location));
+            for line in vectors.lines() {
+                let (hex_str, nickname) = line.split_once('\t').unwrap();
+                assert_eq!(
+                    generate_short_nickname_with_version(hex_str, version).unwrap(),
+                    nickname,
+                    "{} {}",
+                    version,
+                    hex_str
+                );
+            }
+        }
+    }
+
+    /// Writes the golden vectors of new versions, never the ones already frozen:
+    /// `cargo test -- --ignored write_golden_vectors`.
+    #[test]
+    #[ignore]
+    fn write_golden_vectors() {
+        for version in NicknameVersion::ALL {
+            let location = format!("./test_resources/golden/{}.txt", version);
+            if std::path::Path::new(&location).exists() {
+                continue;
+            }
+            let vectors = (0..100)
+                .map(|i| {
+                    let hex_str = sha256_digest(format!("robot {}", i)).unwrap();
+                    let nickname = generate_short_nickname_with_version(&hex_str, version);
+                    format!("{}\t{}\n", hex_str, nickname.unwrap())
+                })
+                .collect::<String>();
+            std::fs::create_dir_all("./test_resources/golden").unwrap();
+            std::fs::write(location, vectors).unwrap();
+        }
+    }
 }
@@ -0,0 +1,641 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+const QUALITY: f32 = 70.0;
+const METHOD: i32 = 6; // Slowest compression method, best compression ratio and image quality
+const DEFAULT_COLOR: &str = "default";
+const HEADER: &str = "// @generated by `cargo xtask assets`, do not edit.\n";
+const DESCRIPTIONS: &str = "descriptions.json";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    match args.first().map(String::as_str) {
+        Some("assets") => assets(args.iter().any(|arg| arg == "--check")),
+        Some("bless") => bless(),
+        _ => {
+            eprintln!("usage: cargo xtask assets [--check] | bless");
+            exit(2)
+        }
+    }
+}
+
+/// Regenerates `robot_parts.rs`, `backgrounds.rs` and `descriptions.rs` from the art
+/// in `sets/` and `backgrounds/`. With `--check`, fails if the committed tables are
+/// out of date.
+fn assets(check: bool) -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .ok_or("workspace root not found")?
+        .join("robohash");
+
+    let sets = collect_sets(&root.join("sets"))?;
+    let backgrounds = collect_files(&root.join("backgrounds"))?;
+    if backgrounds.is_empty() {
+        return Err("backgrounds/ does not contain any image".into());
+    }
+
+    let outputs = [
+        (root.join("src/robot_parts.rs"), robot_parts_rs(&sets)?),
+        (
+            root.join("src/backgrounds.rs"),
+            backgrounds_rs(&backgrounds)?,
+        ),
+        (
+            root.join("src/descriptions.rs"),
+            descriptions_rs(&root, &sets, &backgrounds)?,
+        ),
+    ];
+
+    let mut stale = Vec::new();
+    for (path, content) in outputs {
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        if current == content {
+            continue;
+        }
+        if check {
+            stale.push(path.display().to_string());
+        } else {
+            fs::write(&path, content)?;
+            println!("wrote {}", path.display());
+        }
+    }
+    if !stale.is_empty() {
+        return Err(format!("{} out of date, run `cargo xtask assets`", stale.join(", ")).into());
+    }
+    Ok(())
+}
+
+/// Renders the golden images of `robohash/test_resources/golden/images` again, to
+/// run after changes to the rendering that are expected.
+fn bless() -> Result<(), Box<dyn Error>> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let status = Command::new(cargo)
+        .args(["test", "--package", "robohash", "--lib", "golden::"])
+        .env("ROBOHASH_BLESS", "1")
+        .status()?;
+    if !status.success() {
+        return Err("blessing the golden images failed".into());
+    }
+    println!("blessed, review the changed images before committing them");
+    Ok(())
+}
+
+struct Set {
+    name: String,
+    directory: PathBuf,
+    colors: Vec<Color>,
+}
+
+struct Color {
+    name: String,
+    layers: Vec<Layer>,
+}
+
+struct Layer {
+    name: String,
+    sort_order: u32,
+    stacking_order: u32,
+    files: Vec<PathBuf>,
+}
+
+fn collect_sets(directory: &Path) -> Result<Vec<Set>, Box<dyn Error>> {
+    let mut sets = Vec::new();
+    for set_directory in subdirectories(directory)? {
+        let name = file_name(&set_directory);
+        if !name.starts_with("set") {
+            return Err(format!("{} is not named after an upstream set", name).into());
+        }
+
+        // Sets without color variants store their layers directly in the set directory
+        let directories = subdirectories(&set_directory)?;
+        let colors = if directories
+            .iter()
+            .all(|d| parse_layer_name(&file_name(d)).is_some())
+        {
+            vec![collect_color(DEFAULT_COLOR, &set_directory)?]
+        } else {
+            directories
+                .iter()
+                .map(|d| collect_color(&file_name(d), d))
+                .collect::<Result<Vec<Color>, Box<dyn Error>>>()?
+        };
+
+        validate_colors(&name, &colors)?;
+        sets.push(Set {
+            name,
+            directory: set_directory,
+            colors,
+        });
+    }
+    if sets.is_empty() {
+        return Err("sets/ does not contain any set".into());
+    }
+    Ok(sets)
+}
+
+fn collect_color(name: &str, directory: &Path) -> Result<Color, Box<dyn Error>> {
+    let mut layers = Vec::new();
+    for layer_directory in subdirectories(directory)? {
+        let directory_name = file_name(&layer_directory);
+        let (sort_order, stacking_order, name) = parse_layer_name(&directory_name)
+            .ok_or_else(|| format!("{} is not named NNN#NNName", layer_directory.display()))?;
+        let files = collect_files(&layer_directory)?;
+        if files.is_empty() {
+            return Err(format!("{} does not contain any part", layer_directory.display()).into());
+        }
+        layers.push(Layer {
+            name,
+            sort_order,
+            stacking_order,
+            files,
+        });
+    }
+    layers.sort_by_key(|layer| layer.stacking_order);
+    Ok(Color {
+        name: name.to_string(),
+        layers,
+    })
+}
+
+fn validate_colors(set: &str, colors: &[Color]) -> Result<(), Box<dyn Error>> {
+    let layers = |color: &Color| {
+        color
+            .layers
+            .iter()
+            .map(|layer| layer.name.clone())
+            .collect::<Vec<String>>()
+    };
+    let expected = colors.first().map(layers).unwrap_or_default();
+    if expected.is_empty() {
+        return Err(format!("{} does not contain any layer", set).into());
+    }
+    for color in colors {
+        if layers(color) != expected {
+            return Err(format!(
+                "{}/{} has layers {:?}, expected {:?}",
+                set,
+                color.name,
+                layers(color),
+                expected
+            )
+            .into());
+        }
+        // Allowed, but usually means some art is missing
+        for (layer, first) in color.layers.iter().zip(&colors[0].layers) {
+            if layer.files.len() != first.files.len() {
+                eprintln!(
+                    "warning: {}/{}/{} has {} parts, {}/{}/{} has {}",
+                    set,
+                    color.name,
+                    layer.name,
+                    layer.files.len(),
+                    set,
+                    colors[0].name,
+                    first.name,
+                    first.files.len()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `003#01Body` is sorted as 003, stacked as 01 and named Body.
+fn parse_layer_name(directory_name: &str) -> Option<(u32, u32, String)> {
+    let (sort, rest) = directory_name.split_once('#')?;
+    if sort.is_empty() || !sort.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    let (stacking_order, name) = rest.split_at(digits);
+    if name.is_empty() {
+        return None;
+    }
+    Some((
+        sort.parse().ok()?,
+        stacking_order.parse().ok()?,
+        name.to_string(),
+    ))
+}
+
+/// Images under `directory`, in file name order. Names starting with `_` or `.` are skipped.
+fn collect_files(directory: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(directory)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| !is_hidden(&entry.file_name().to_string_lossy()));
+    for entry in walker {
+        let entry = entry?;
+        let is_png = entry.path().extension().is_some_and(|ext| ext == "png");
+        if entry.file_type().is_file() && is_png {
+            files.push(entry.into_path());
+        }
+    }
+    Ok(files)
+}
+
+fn subdirectories(directory: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut directories = Vec::new();
+    for entry in WalkDir::new(directory)
+        .min_depth(1)
+        .max_depth(1)
+        .sort_by_file_name()
+    {
+        let entry = entry?;
+        if entry.file_type().is_dir() && !is_hidden(&entry.file_name().to_string_lossy()) {
+            directories.push(entry.into_path());
+        }
+    }
+    Ok(directories)
+}
+
+fn is_hidden(name: &str) -> bool {
+    name.starts_with('_') || name.starts_with('.')
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn convert_to_webp_base64(path: &Path) -> Result<String, Box<dyn Error>> {
+    let image = image::open(path)?.to_rgba8();
+    let mut config = webp::WebPConfig::new().map_err(|_| "failed to configure webp")?;
+    config.quality = QUALITY;
+    config.method = METHOD;
+    let webp = webp::Encoder::from_rgba(image.as_raw(), image.width(), image.height())
+        .encode_advanced(&config)
+        .map_err(|err| format!("failed to encode {}: {:?}", path.display(), err))?;
+    Ok(general_purpose::STANDARD.encode(&*webp))
+}
+
+fn const_name(names: &[&str]) -> String {
+    names
+        .join("_")
+        .to_uppercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
+
+fn quoted(names: impl Iterator<Item = String>) -> String {
+    names
+        .map(|name| format!("\"{}\"", name))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn write_images(
+    content: &mut String,
+    declaration: &str,
+    files: &[PathBuf],
+) -> Result<(), Box<dyn Error>> {
+    writeln!(content, "\n{}: &[&str] = &[", declaration)?;
+    for file in files {
+        writeln!(content, "    \"{}\",", convert_to_webp_base64(file)?)?;
+    }
+    writeln!(content, "];")?;
+    Ok(())
+}
+
+fn robot_parts_rs(sets: &[Set]) -> Result<String, Box<dyn Error>> {
+    let mut content = String::from(HEADER);
+    content.push_str("\nuse crate::catalog::EmbeddedSet;\n");
+    let set_names = sets
+        .iter()
+        .map(|set| const_name(&[&set.name]))
+        .collect::<Vec<String>>();
+    writeln!(
+        content,
+        "\npub static SETS: &[EmbeddedSet] = &[{}];",
+        set_names.join(", ")
+    )?;
+
+    for set in sets {
+        let set_name = const_name(&[&set.name]);
+        let color_names = set
+            .colors
+            .iter()
+            .map(|color| const_name(&[&set.name, &color.name]))
+            .collect::<Vec<String>>();
+        writeln!(
+            content,
+            "\nconst {}: EmbeddedSet = EmbeddedSet {{",
+            set_name
+        )?;
+        writeln!(content, "    name: \"{}\",", set.name)?;
+        writeln!(
+            content,
+            "    colors: &[{}],",
+            quoted(set.colors.iter().map(|color| color.name.clone()))
+        )?;
+        writeln!(
+            content,
+            "    layers: &[{}],",
+            quoted(set.colors[0].layers.iter().map(|layer| layer.name.clone()))
+        )?;
+        writeln!(
+            content,
+            "    sort_orders: &[{}],",
+            set.colors[0]
+                .layers
+                .iter()
+                .map(|layer| layer.sort_order.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        writeln!(content, "    parts: &[{}],", color_names.join(", "))?;
+        writeln!(content, "}};")?;
+
+        for (color, color_name) in set.colors.iter().zip(&color_names) {
+            let layer_names = color
+                .layers
+                .iter()
+                .map(|layer| const_name(&[&set.name, &color.name, &layer.name]))
+                .collect::<Vec<String>>();
+            writeln!(
+                content,
+                "\nconst {}: &[&[&str]] = &[{}];",
+                color_name,
+                layer_names.join(", ")
+            )?;
+            for (layer, layer_name) in color.layers.iter().zip(&layer_names) {
+                write_images(&mut content, &format!("const {}", layer_name), &layer.files)?;
+            }
+        }
+    }
+    Ok(content)
+}
+
+fn backgrounds_rs(backgrounds: &[PathBuf]) -> Result<String, Box<dyn Error>> {
+    let mut content = String::from(HEADER);
+    // Upstream picks a background directory (bg1, bg2...) before a background
+    let mut background_sets: Vec<(Option<&Path>, usize)> = Vec::new();
+    for background in backgrounds {
+        match background_sets.last_mut() {
+            Some((parent, count)) if *parent == background.parent() => *count += 1,
+            _ => background_sets.push((background.parent(), 1)),
+        }
+    }
+    writeln!(
+        content,
+        "\npub static BACKGROUND_SETS: &[usize] = &[{}];",
+        background_sets
+            .iter()
+            .map(|(_, count)| count.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )?;
+    write_images(&mut content, "pub static BACKGROUNDS", backgrounds)?;
+    Ok(content)
+}
+
+/// English and Spanish phrases of a `descriptions.json`.
+#[derive(Deserialize)]
+struct Description {
+    en: String,
+    es: String,
+}
+
+/// `sets/<set>/descriptions.json`, parts are keyed by `description_key`.
+#[derive(Deserialize)]
+struct SetDescriptions {
+    colors: BTreeMap<String, Description>,
+    parts: BTreeMap<String, Description>,
+}
+
+fn read_descriptions<T: DeserializeOwned>(directory: &Path) -> Result<Option<T>, Box<dyn Error>> {
+    let path = directory.join(DESCRIPTIONS);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let descriptions = serde_json::from_str(&fs::read_to_string(&path)?)
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok(Some(descriptions))
+}
+
+/// `006#blue_body-01.png` of the blue parts is described as `body-01`.
+fn description_key(file: &Path, color: &str) -> String {
+    let name = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = name.split_once('#').map_or(name.as_str(), |(_, rest)| rest);
+    name.strip_prefix(color)
+        .map_or(name, |rest| rest.trim_start_matches('_'))
+        .to_string()
+}
+
+/// Parts sharing a key, like `011#accessory-12` and `013#accessory-12`, are told
+/// apart by a key prefixed with their sorting order, `013#accessory-12`.
+fn part_description<'a>(
+    descriptions: &'a BTreeMap<String, Description>,
+    file: &Path,
+    color: &str,
+) -> Result<&'a Description, Box<dyn Error>> {
+    let key = description_key(file, color);
+    let order = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split_once('#'))
+        .map(|(order, _)| format!("{}#{}", order, key));
+    match order.and_then(|order| descriptions.get(&order)) {
+        Some(description) => Ok(description),
+        None => description(descriptions, &key, file),
+    }
+}
+
+fn description<'a>(
+    descriptions: &'a BTreeMap<String, Description>,
+    key: &str,
+    file: &Path,
+) -> Result<&'a Description, Box<dyn Error>> {
+    descriptions.get(key).ok_or_else(|| {
+        format!(
+            "{} has no description, add \"{}\" to {}",
+            file.display(),
+            key,
+            DESCRIPTIONS
+        )
+        .into()
+    })
+}
+
+fn write_description(
+    content: &mut String,
+    indent: &str,
+    description: &Description,
+) -> std::fmt::Result {
+    writeln!(
+        content,
+        "{}EmbeddedDescription {{ en: {:?}, es: {:?} }},",
+        indent, description.en, description.es
+    )
+}
+
+/// Sets and backgrounds without a `descriptions.json` are left out.
+fn descriptions_rs(
+    root: &Path,
+    sets: &[Set],
+    backgrounds: &[PathBuf],
+) -> Result<String, Box<dyn Error>> {
+    let mut content = String::from(HEADER);
+    content.push_str("\nuse crate::describe::{EmbeddedDescription, EmbeddedSetDescriptions};\n");
+
+    let mut described = Vec::new();
+    let mut tables = String::new();
+    for set in sets {
+        let Some(descriptions) = read_descriptions::<SetDescriptions>(&set.directory)? else {
+            continue;
+        };
+        let set_name = const_name(&[&set.name]);
+        described.push(set_name.clone());
+        writeln!(
+            tables,
+            "\nconst {}: EmbeddedSetDescriptions = EmbeddedSetDescriptions {{",
+            set_name
+        )?;
+        writeln!(tables, "    name: \"{}\",", set.name)?;
+        writeln!(tables, "    colors: &[")?;
+        for color in &set.colors {
+            let color_description = description(
+                &descriptions.colors,
+                &color.name,
+                &set.directory.join(&color.name),
+            )?;
+            write_description(&mut tables, "        ", color_description)?;
+        }
+        writeln!(tables, "    ],")?;
+        writeln!(tables, "    parts: &[")?;
+        for color in &set.colors {
+            writeln!(tables, "        &[")?;
+            for layer in &color.layers {
+                writeln!(tables, "            &[")?;
+                for file in &layer.files {
+                    let part_description =
+                        part_description(&descriptions.parts, file, &color.name)?;
+                    write_description(&mut tables, "                ", part_description)?;
+                }
+                writeln!(tables, "            ],")?;
+            }
+            writeln!(tables, "        ],")?;
+        }
+        writeln!(tables, "    ],")?;
+        writeln!(tables, "}};")?;
+    }
+    writeln!(
+        content,
+        "\npub static SETS: &[EmbeddedSetDescriptions] = &[{}];",
+        described.join(", ")
+    )?;
+    content.push_str(&tables);
+
+    let background_directory = root.join("backgrounds");
+    let background_descriptions =
+        read_descriptions::<BTreeMap<String, Description>>(&background_directory)?;
+    writeln!(
+        content,
+        "\npub static BACKGROUNDS: &[EmbeddedDescription] = &["
+    )?;
+    if let Some(descriptions) = background_descriptions {
+        for background in backgrounds {
+            let key = description_key(background, "");
+            write_description(
+                &mut content,
+                "    ",
+                description(&descriptions, &key, background)?,
+            )?;
+        }
+    }
+    writeln!(content, "];")?;
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_layer_name_splits_sorting_stacking_and_name() {
+        assert_eq!(
+            parse_layer_name("003#01Body"),
+            Some((3, 1, String::from("Body")))
+        );
+        assert_eq!(
+            parse_layer_name("002#05Accessory"),
+            Some((2, 5, String::from("Accessory")))
+        );
+        assert_eq!(parse_layer_name("green"), None);
+        assert_eq!(parse_layer_name("003#01"), None);
+    }
+
+    #[test]
+    fn description_key_drops_the_index_and_the_color() {
+        assert_eq!(
+            description_key(
+                Path::new("sets/set1/blue/003#01Body/006#blue_body-01.png"),
+                "blue"
+            ),
+            "body-01"
+        );
+        assert_eq!(
+            description_key(Path::new("009#yellow__accessory-08.png"), "yellow"),
+            "accessory-08"
+        );
+        assert_eq!(
+            description_key(Path::new("bg1/002#final3.png"), ""),
+            "final3"
+        );
+    }
+
+    #[test]
+    fn part_description_prefers_the_key_with_the_sorting_order() {
+        // arrange
+        let text = |en: &str| Description {
+            en: en.to_string(),
+            es: en.to_string(),
+        };
+        let descriptions = BTreeMap::from([
+            (String::from("accessory-12"), text("coin")),
+            (String::from("013#accessory-12"), text("beard")),
+        ]);
+        let part = |name: &str| part_description(&descriptions, Path::new(name), "red");
+        // act & assert
+        assert_eq!(part("011#red_accessory-12.png").unwrap().en, "coin");
+        assert_eq!(part("013#red_accessory-12.png").unwrap().en, "beard");
+        assert!(part("014#red_accessory-14.png").is_err());
+    }
+
+    #[test]
+    fn committed_tables_match_the_layer_counts_on_disk() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../robohash");
+        let sets = collect_sets(&root.join("sets")).unwrap();
+        let robot_parts = fs::read_to_string(root.join("src/robot_parts.rs")).unwrap();
+        for set in &sets {
+            for color in &set.colors {
+                for layer in &color.layers {
+                    let name = const_name(&[&set.name, &color.name, &layer.name]);
+                    let start = robot_parts
+                        .find(&format!("const {}: &[&str] = &[", name))
+                        .unwrap_or_else(|| panic!("{} is missing", name));
+                    let parts = robot_parts[start..]
+                        .lines()
+                        .skip(1)
+                        .take_while(|line| *line != "];")
+                        .count();
+                    assert_eq!(parts, layer.files.len(), "{}", name);
+                }
+            }
+        }
+    }
+}